@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use tauri::{command, AppHandle};
 
+use crate::features::models::custom_endpoints::get_custom_endpoint;
+use crate::features::models::models_registry::ModelProvider;
+use crate::features::security::get_api_key_internal;
+
 mod providers;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,19 +37,13 @@ pub async fn post_process_transcript(
         request.vibe_prompt.as_ref(),
     );
 
-    // Get API key for selected model
-    let api_key = crate::features::security::get_api_key_internal(&app, &request.model_id)
-        .await
-        .map_err(|_| {
-            "API key not found for selected model. Please add your API key in settings.".to_string()
-        })?;
-
     // Determine provider from model_id
-    let provider = get_model_provider(&request.model_id)?;
+    let provider = get_model_provider(&app, &request.model_id)?;
 
     // Route to appropriate provider
-    let processed_text = match provider.as_str() {
-        "anthropic" | "anthropic-chat" => {
+    let processed_text = match provider {
+        ModelProvider::Anthropic => {
+            let api_key = get_required_api_key(&app, &request.model_id).await?;
             providers::process_with_anthropic(
                 request.text,
                 system_prompt,
@@ -54,13 +52,35 @@ pub async fn post_process_transcript(
             )
             .await?
         }
-        "openai" | "openai-chat" => {
-            providers::process_with_openai(request.text, system_prompt, api_key, request.model_id)
-                .await?
+        ModelProvider::OpenAI => {
+            let api_key = get_required_api_key(&app, &request.model_id).await?;
+            providers::process_with_openai(
+                request.text,
+                system_prompt,
+                providers::OPENAI_API_URL,
+                Some(api_key),
+                request.model_id,
+            )
+            .await?
+        }
+        ModelProvider::OpenAICompatible | ModelProvider::Ollama | ModelProvider::LMStudio => {
+            let endpoint = get_custom_endpoint(&app, &request.model_id)?;
+
+            // Local servers usually run without a key
+            let api_key = get_api_key_internal(&app, &endpoint.id).await.ok();
+
+            providers::process_with_openai(
+                request.text,
+                system_prompt,
+                &endpoint.base_url,
+                api_key,
+                endpoint.model,
+            )
+            .await?
         }
         _ => {
             return Err(format!(
-                "Unsupported AI model provider: {}. Please select an Anthropic or OpenAI model.",
+                "Unsupported AI model provider: {}. Please select an Anthropic, OpenAI or OpenAI-compatible model.",
                 provider
             ))
         }
@@ -258,12 +278,26 @@ fn build_system_prompt(
     prompt
 }
 
-fn get_model_provider(model_id: &str) -> Result<String, String> {
+async fn get_required_api_key(app: &AppHandle, model_id: &str) -> Result<String, String> {
+    get_api_key_internal(app, model_id).await.map_err(|_| {
+        "API key not found for selected model. Please add your API key in settings.".to_string()
+    })
+}
+
+/// Resolve the provider for a post-processing model
+///
+/// User-defined endpoints (Ollama, LM Studio, OpenAI-compatible) are looked up
+/// in models.json; built-in models are recognized by their ID prefix.
+fn get_model_provider(app: &AppHandle, model_id: &str) -> Result<ModelProvider, String> {
+    if let Ok(endpoint) = get_custom_endpoint(app, model_id) {
+        return Ok(endpoint.provider);
+    }
+
     if model_id.starts_with("claude-") {
-        Ok("anthropic".to_string())
+        Ok(ModelProvider::Anthropic)
     } else if model_id.starts_with("gpt-") {
-        Ok("openai".to_string())
+        Ok(ModelProvider::OpenAI)
     } else {
-        Err(format!("Unable to determine provider from model ID: {}. Model ID should start with 'claude-' for Anthropic or 'gpt-' for OpenAI.", model_id))
+        Err(format!("Unable to determine provider from model ID: {}. Model ID should start with 'claude-' for Anthropic or 'gpt-' for OpenAI, or belong to a custom endpoint.", model_id))
     }
 }
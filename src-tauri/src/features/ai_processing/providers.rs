@@ -1,5 +1,7 @@
 use serde_json::json;
 
+pub const OPENAI_API_URL: &str = "https://api.openai.com/v1";
+
 /// Process transcript with Anthropic Claude API
 pub async fn process_with_anthropic(
     text: String,
@@ -57,11 +59,12 @@ pub async fn process_with_anthropic(
     Ok(processed_text)
 }
 
-/// Process transcript with OpenAI GPT API
+/// Process transcript with OpenAI GPT API or any OpenAI-compatible chat completions server
 pub async fn process_with_openai(
     text: String,
     system_prompt: String,
-    api_key: String,
+    base_url: &str,
+    api_key: Option<String>,
     model: String,
 ) -> Result<String, String> {
     let client = reqwest::Client::new();
@@ -82,10 +85,15 @@ pub async fn process_with_openai(
         "temperature": 0.3
     });
 
-    let response = client
-        .post("https://api.openai.com/v1/chat/completions")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .header("content-type", "application/json")
+    let mut request = client
+        .post(format!("{}/chat/completions", base_url))
+        .header("content-type", "application/json");
+
+    if let Some(key) = api_key {
+        request = request.header("Authorization", format!("Bearer {}", key));
+    }
+
+    let response = request
         .json(&body)
         .send()
         .await
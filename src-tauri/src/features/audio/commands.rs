@@ -1,7 +1,10 @@
 use super::player::{play_error_sound, play_recording_start_sound, play_recording_stop_sound};
 use super::recorder::AudioRecorder;
-use super::state::{RecordingState, RecordingStateManager};
+use super::state::{DictationMode, RecordingState, RecordingStateManager};
 use crate::features::recordings::get_recordings_dir;
+use crate::features::transcription::language::language_from_settings;
+use crate::features::transcription::retry_queue::{record_failed_transcription, retry_queued_soon};
+use crate::features::transcription::TranscriptionProviderRegistry;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
 use tauri::{command, AppHandle, Emitter, Manager, State};
@@ -18,11 +21,16 @@ pub struct RecordingResponse {
     pub file_path: Option<String>,
 }
 
+/// Start recording command
+///
+/// `mode` picks transcription or translation to English for this
+/// dictation only; it defaults to transcription.
 #[command]
 pub async fn start_recording(
     app: AppHandle,
     recorder: State<'_, Arc<Mutex<AudioRecorder>>>,
     state_manager: State<'_, Arc<RecordingStateManager>>,
+    mode: Option<DictationMode>,
 ) -> Result<RecordingResponse, String> {
     log::info!("Start recording command called");
 
@@ -87,6 +95,7 @@ pub async fn start_recording(
     state_manager.set_current_file(Some(file_path.clone()));
     state_manager.set_start_time(Some(timestamp));
     state_manager.set_recording_device(device_id.clone());
+    state_manager.set_dictation_mode(mode.unwrap_or_default());
 
     if play_sound {
         let _ = play_recording_start_sound();
@@ -107,6 +116,13 @@ pub async fn start_recording(
 
             let _ = app.emit("recording-state-changed", RecordingState::Recording);
 
+            // Partial transcripts while recording, when a local model is in use
+            crate::features::transcription::start_live_transcription(
+                app.clone(),
+                recorder_guard.live_audio(),
+                timestamp,
+            );
+
             if let Some(window) = app.get_webview_window("voice-input") {
                 let _ = window.show();
                 // Don't steal focus - keep user focused on their active application
@@ -259,6 +275,7 @@ pub async fn stop_recording(
                 // Get recording metadata
                 let recording_device = state_manager.get_recording_device();
                 let start_time = state_manager.get_start_time();
+                let translate = state_manager.get_dictation_mode() == DictationMode::Translate;
 
                 tokio::spawn(async move {
                     state_manager_clone.force_set_state(RecordingState::Transcribing);
@@ -278,6 +295,8 @@ pub async fn stop_recording(
                         ((end_time - start) as f64) / 1000.0
                     });
 
+                    let language = language_from_settings(&app_clone);
+
                     match std::fs::read(&audio_path_clone) {
                         Ok(audio_data) => {
                             let request =
@@ -285,58 +304,62 @@ pub async fn stop_recording(
                                     audio_data,
                                     timestamp,
                                     duration,
-                                    language: Some("en".to_string()),
-                                    recording_device,
+                                    language: language.clone(),
+                                    recording_device: recording_device.clone(),
+                                    background: false,
+                                    translate,
+                                    model_id: None,
                                 };
 
-                            if let Some(local_model_state) =
-                                app_clone.try_state::<Arc<
-                                    tokio::sync::Mutex<crate::features::models::LocalModelManager>,
-                                >>()
-                            {
-                                match crate::features::transcription::orchestrator::transcribe_and_process(
-                                    request,
-                                    app_clone.clone(),
-                                    local_model_state,
-                                ).await {
-                                    Ok(_) => {
-                                        log::info!("Transcription completed successfully");
-                                    }
-                                    Err(e) => {
-                                        log::error!("Transcription failed: {}", e);
-
-                                        // Clean up the recording folder since transcription failed
-                                        if let Some(parent) = audio_path_clone.parent() {
-                                            if parent.exists() {
-                                                if let Err(cleanup_err) = std::fs::remove_dir_all(parent) {
-                                                    log::warn!("Failed to cleanup recording folder after transcription error: {}", cleanup_err);
-                                                } else {
-                                                    log::info!("Cleaned up recording folder after transcription failure");
-                                                }
-                                            }
-                                        }
-
-                                        state_manager_clone.force_set_state(RecordingState::Error);
-                                        state_manager_clone.set_error(Some(format!("Transcription failed: {}", e)));
-                                        let _ = app_clone.emit("recording-state-changed", RecordingState::Error);
+                            match crate::features::transcription::orchestrator::transcribe_and_process(
+                                request,
+                                app_clone.clone(),
+                            ).await {
+                                Ok(_) => {
+                                    log::info!("Transcription completed successfully");
+
+                                    // The provider is reachable again, so retry earlier failures
+                                    retry_queued_soon(&app_clone);
+                                }
+                                Err(e) => {
+                                    log::error!("Transcription failed: {}", e);
+
+                                    // Keep the audio and queue it for retry
+                                    if let Err(record_err) = record_failed_transcription(
+                                        &app_clone,
+                                        timestamp,
+                                        duration,
+                                        language,
+                                        recording_device,
+                                        translate,
+                                        &e,
+                                    ) {
+                                        log::warn!("Failed to record transcription failure: {}", record_err);
                                     }
+
+                                    state_manager_clone.force_set_state(RecordingState::Error);
+                                    state_manager_clone.set_error(Some(format!("Transcription failed: {}", e)));
+                                    let _ = app_clone.emit("recording-state-changed", RecordingState::Error);
                                 }
                             }
                         }
                         Err(e) => {
                             log::error!("Failed to read audio file: {}", e);
 
-                            // Clean up the recording folder since we couldn't read the audio
-                            if let Some(parent) = audio_path_clone.parent() {
-                                if parent.exists() {
-                                    if let Err(cleanup_err) = std::fs::remove_dir_all(parent) {
-                                        log::warn!("Failed to cleanup recording folder after read error: {}", cleanup_err);
-                                    } else {
-                                        log::info!(
-                                            "Cleaned up recording folder after read failure"
-                                        );
-                                    }
-                                }
+                            // Keep whatever was written; the read may succeed on retry
+                            if let Err(record_err) = record_failed_transcription(
+                                &app_clone,
+                                timestamp,
+                                duration,
+                                language,
+                                recording_device,
+                                translate,
+                                &format!("Failed to read audio file: {}", e),
+                            ) {
+                                log::warn!(
+                                    "Failed to record transcription failure: {}",
+                                    record_err
+                                );
                             }
 
                             state_manager_clone.force_set_state(RecordingState::Error);
@@ -589,10 +612,12 @@ fn check_model_available(app: &AppHandle) -> Result<(), String> {
         let id = model.get("id").and_then(|v| v.as_str()).unwrap_or("");
 
         if id == selected_model_id {
-            let provider = model.get("provider").and_then(|v| v.as_str()).unwrap_or("");
+            let provider_id = model.get("provider").and_then(|v| v.as_str()).unwrap_or("");
+            let registry = app.state::<TranscriptionProviderRegistry>();
+            let provider = registry.get(provider_id)?;
 
             // For local models, check if downloaded
-            if provider == "local-whisper" {
+            if provider.requires_download() {
                 let is_downloaded = model
                     .get("isDownloaded")
                     .and_then(|v| v.as_bool())
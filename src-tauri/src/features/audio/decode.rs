@@ -0,0 +1,151 @@
+use std::fs::File;
+use std::path::Path;
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::{MediaSource, MediaSourceStream};
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use super::normalize::NormalizedAudio;
+
+/// Decode an audio file (WAV, MP3, M4A/AAC, FLAC, OGG Vorbis, ...) from disk
+///
+/// The file extension is only a hint; the container is detected from the
+/// content. The first audio track is decoded, downmixed and resampled.
+pub fn decode_audio_file(path: &Path) -> Result<NormalizedAudio, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open audio file: {}", e))?;
+    let extension = path.extension().and_then(|ext| ext.to_str());
+
+    decode_audio(Box::new(file), extension)
+}
+
+/// Decode any container/codec symphonia supports into normalized audio
+pub fn decode_audio(
+    source: Box<dyn MediaSource>,
+    extension: Option<&str>,
+) -> Result<NormalizedAudio, String> {
+    let stream = MediaSourceStream::new(source, Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(extension) = extension {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| format!("Unsupported audio format: {}", e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or("No audio track found in file")?;
+    let track_id = track.id;
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("Unsupported audio codec: {}", e))?;
+
+    let mut sample_rate = track.codec_params.sample_rate.unwrap_or(0);
+    let mut channels = track
+        .codec_params
+        .channels
+        .map(|channels| channels.count())
+        .unwrap_or(0);
+    let mut samples: Vec<f32> = Vec::new();
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            // End of stream
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(e) => return Err(format!("Failed to read audio: {}", e)),
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // A corrupt frame only loses a few milliseconds; keep going
+            Err(SymphoniaError::DecodeError(e)) => {
+                log::warn!("Skipping undecodable audio frame: {}", e);
+                continue;
+            }
+            Err(e) => return Err(format!("Failed to decode audio: {}", e)),
+        };
+
+        let spec = *decoded.spec();
+        sample_rate = spec.rate;
+        channels = spec.channels.count();
+
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        samples.extend_from_slice(buffer.samples());
+    }
+
+    if sample_rate == 0 || channels == 0 {
+        return Err("Audio file has no sample rate or channel information".to_string());
+    }
+
+    log::info!(
+        "Decoded audio: {} Hz, {} channel(s), {} samples",
+        sample_rate,
+        channels,
+        samples.len()
+    );
+
+    NormalizedAudio::from_samples(samples, sample_rate, channels as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hound::{WavSpec, WavWriter};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_decode_stereo_wav() {
+        let spec = WavSpec {
+            channels: 2,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut writer = WavWriter::new(&mut cursor, spec).unwrap();
+            for i in 0..44100 {
+                let sample = ((i as f32 * 0.05).sin() * 8000.0) as i16;
+                writer.write_sample(sample).unwrap();
+                writer.write_sample(sample).unwrap();
+            }
+            writer.finalize().unwrap();
+        }
+
+        let audio = decode_audio(Box::new(Cursor::new(cursor.into_inner())), Some("wav")).unwrap();
+
+        // One second of audio, downmixed to mono at 16kHz
+        assert!((audio.duration_secs() - 1.0).abs() < 0.01);
+        assert!(audio.samples.iter().any(|s| s.abs() > 0.1));
+    }
+
+    #[test]
+    fn test_rejects_non_audio() {
+        let error = decode_audio(Box::new(Cursor::new(b"not audio".to_vec())), None).unwrap_err();
+        assert!(error.starts_with("Unsupported audio format"));
+    }
+}
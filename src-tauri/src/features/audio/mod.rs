@@ -1,10 +1,14 @@
 pub mod commands;
+pub mod decode;
 pub mod devices;
+pub mod normalize;
 pub mod player;
 pub mod recorder;
 pub mod state;
 
 pub use commands::{cancel_recording, get_recording_state, start_recording, stop_recording};
+pub use decode::decode_audio_file;
 pub use devices::enumerate_audio_devices;
-pub use recorder::AudioRecorder;
-pub use state::{RecordingState, RecordingStateManager};
+pub use normalize::NormalizedAudio;
+pub use recorder::{AudioRecorder, LiveAudioBuffer};
+pub use state::{DictationMode, RecordingState, RecordingStateManager};
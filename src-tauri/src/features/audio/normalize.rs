@@ -0,0 +1,166 @@
+use hound::{WavReader, WavSpec, WavWriter};
+use std::io::Cursor;
+
+/// Sample rate expected by every speech-to-text engine and provider
+pub const TARGET_SAMPLE_RATE: u32 = 16000;
+
+/// Mono 16kHz audio, ready to be handed to any transcription provider
+///
+/// Recordings are written at the device's native sample rate and channel
+/// count, so everything is normalized once before transcription instead of
+/// each provider guessing the format.
+#[derive(Debug, Clone)]
+pub struct NormalizedAudio {
+    /// Mono samples normalized to -1.0 to 1.0 at `TARGET_SAMPLE_RATE`
+    pub samples: Vec<f32>,
+}
+
+impl NormalizedAudio {
+    /// Parse WAV bytes, downmix to mono and resample to 16kHz
+    pub fn from_wav(audio_data: &[u8]) -> Result<Self, String> {
+        let cursor = Cursor::new(audio_data);
+        let mut reader =
+            WavReader::new(cursor).map_err(|e| format!("Failed to parse WAV audio: {}", e))?;
+
+        let spec = reader.spec();
+
+        log::info!(
+            "Input audio: {} Hz, {} channel(s)",
+            spec.sample_rate,
+            spec.channels
+        );
+
+        // Read all samples and convert to f32
+        let samples: Vec<f32> = match spec.sample_format {
+            hound::SampleFormat::Float => reader
+                .samples::<f32>()
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Failed to read samples: {}", e))?,
+            hound::SampleFormat::Int => {
+                let max_val = (1i64 << (spec.bits_per_sample - 1)) as f32;
+                reader
+                    .samples::<i32>()
+                    .map(|s| s.map(|v| v as f32 / max_val))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| format!("Failed to read samples: {}", e))?
+            }
+        };
+
+        Self::from_samples(samples, spec.sample_rate, spec.channels)
+    }
+
+    /// Normalize interleaved samples of any rate and channel count
+    pub fn from_samples(
+        samples: Vec<f32>,
+        sample_rate: u32,
+        channels: u16,
+    ) -> Result<Self, String> {
+        if channels == 0 {
+            return Err("Invalid audio: zero channels".to_string());
+        }
+
+        // Downmix to mono by averaging channels
+        let mut samples = if channels > 1 {
+            let channels = channels as usize;
+            samples
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+                .collect()
+        } else {
+            samples
+        };
+
+        if sample_rate != TARGET_SAMPLE_RATE {
+            log::info!(
+                "Resampling from {} Hz to {} Hz",
+                sample_rate,
+                TARGET_SAMPLE_RATE
+            );
+            samples = resample_audio(samples, sample_rate, TARGET_SAMPLE_RATE)?;
+            log::info!("Resampling complete: {} samples", samples.len());
+        }
+
+        Ok(Self { samples })
+    }
+
+    /// Length of the audio in seconds
+    pub fn duration_secs(&self) -> f64 {
+        self.samples.len() as f64 / TARGET_SAMPLE_RATE as f64
+    }
+
+    /// Encode as a 16-bit PCM mono WAV at 16kHz (for cloud providers)
+    pub fn to_wav(&self) -> Result<Vec<u8>, String> {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: TARGET_SAMPLE_RATE,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut writer = WavWriter::new(&mut cursor, spec)
+                .map_err(|e| format!("Failed to create WAV writer: {}", e))?;
+
+            for &sample in &self.samples {
+                let sample_i16 = (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+                writer
+                    .write_sample(sample_i16)
+                    .map_err(|e| format!("Failed to write WAV sample: {}", e))?;
+            }
+
+            writer
+                .finalize()
+                .map_err(|e| format!("Failed to finalize WAV: {}", e))?;
+        }
+
+        Ok(cursor.into_inner())
+    }
+}
+
+/// Resample mono audio from one sample rate to another
+pub fn resample_audio(
+    input: Vec<f32>,
+    input_rate: u32,
+    output_rate: u32,
+) -> Result<Vec<f32>, String> {
+    use rubato::{
+        Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+    };
+
+    if input.is_empty() || input_rate == output_rate {
+        return Ok(input);
+    }
+
+    // Calculate resampling ratio
+    let resample_ratio = output_rate as f64 / input_rate as f64;
+
+    // Create resampler with high quality settings
+    let params = SincInterpolationParameters {
+        sinc_len: 256,
+        f_cutoff: 0.95,
+        interpolation: SincInterpolationType::Linear,
+        oversampling_factor: 256,
+        window: WindowFunction::BlackmanHarris2,
+    };
+
+    let mut resampler = SincFixedIn::<f32>::new(
+        resample_ratio,
+        2.0,
+        params,
+        input.len(),
+        1, // 1 channel (mono)
+    )
+    .map_err(|e| format!("Failed to create resampler: {}", e))?;
+
+    // Rubato expects Vec<Vec<f32>> where outer vec is channels
+    let input_frames = vec![input];
+
+    // Perform resampling
+    let mut output_frames = resampler
+        .process(&input_frames, None)
+        .map_err(|e| format!("Resampling failed: {}", e))?;
+
+    // Extract mono channel
+    Ok(output_frames.swap_remove(0))
+}
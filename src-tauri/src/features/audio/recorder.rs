@@ -1,6 +1,7 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use cpal::{Device, Host, Stream, StreamConfig};
 use hound::{WavSpec, WavWriter};
+use std::collections::VecDeque;
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
@@ -32,6 +33,65 @@ impl Default for RecorderConfig {
     }
 }
 
+/// Seconds of recent audio kept for live transcription
+const LIVE_BUFFER_SECS: u32 = 30;
+
+/// Most recent audio of the current recording, downmixed to mono
+///
+/// Read by live transcription while the recording is in progress. Only the
+/// last `LIVE_BUFFER_SECS` are kept; positions are frames counted from the
+/// start of the recording, at the device's sample rate.
+pub struct LiveAudioBuffer {
+    sample_rate: u32,
+    samples: VecDeque<f32>,
+    /// Frames dropped from the front to bound memory
+    dropped: u64,
+}
+
+impl LiveAudioBuffer {
+    pub fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            samples: VecDeque::new(),
+            dropped: 0,
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Frames received since the recording started
+    pub fn total_frames(&self) -> u64 {
+        self.dropped + self.samples.len() as u64
+    }
+
+    /// Append interleaved device samples, averaging channels
+    fn push_interleaved(&mut self, data: &[f32], channels: u16) {
+        let channels = channels.max(1) as usize;
+        self.samples.extend(
+            data.chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32),
+        );
+
+        let capacity = (self.sample_rate * LIVE_BUFFER_SECS) as usize;
+        if self.samples.len() > capacity {
+            let excess = self.samples.len() - capacity;
+            self.samples.drain(..excess);
+            self.dropped += excess as u64;
+        }
+    }
+
+    /// Audio from frame `from` to now, and the frame it actually starts at
+    ///
+    /// Starts later than `from` when those frames were already dropped.
+    pub fn since(&self, from: u64) -> (u64, Vec<f32>) {
+        let start = from.max(self.dropped);
+        let skip = ((start - self.dropped) as usize).min(self.samples.len());
+        (start, self.samples.iter().skip(skip).copied().collect())
+    }
+}
+
 /// Main audio recorder
 pub struct AudioRecorder {
     state: Arc<Mutex<RecorderState>>,
@@ -41,6 +101,7 @@ pub struct AudioRecorder {
     config: RecorderConfig,
     app_handle: Arc<Mutex<Option<AppHandle>>>,
     last_emit_time: Arc<AtomicU64>,
+    live_audio: Arc<Mutex<LiveAudioBuffer>>,
 }
 
 impl AudioRecorder {
@@ -53,6 +114,9 @@ impl AudioRecorder {
             config: RecorderConfig::default(),
             app_handle: Arc::new(Mutex::new(None)),
             last_emit_time: Arc::new(AtomicU64::new(0)),
+            live_audio: Arc::new(Mutex::new(LiveAudioBuffer::new(
+                RecorderConfig::default().sample_rate,
+            ))),
         }
     }
 
@@ -141,8 +205,13 @@ impl AudioRecorder {
         // Store writer directly in self.writer so both callback and stop_recording can access it
         *self.writer.lock().unwrap() = Some(writer);
 
+        // A fresh buffer per recording, so a reader of the previous one
+        // never sees this recording's audio
+        self.live_audio = Arc::new(Mutex::new(LiveAudioBuffer::new(device_sample_rate)));
+
         // Clone Arc references for the callback
         let writer_clone = Arc::clone(&self.writer);
+        let live_audio_clone = Arc::clone(&self.live_audio);
         let is_recording = Arc::clone(&self.is_recording);
         let app_handle_clone = Arc::clone(&self.app_handle);
         let last_emit_time_clone = Arc::clone(&self.last_emit_time);
@@ -184,6 +253,10 @@ impl AudioRecorder {
                     }
                     drop(writer_guard); // Release lock early
 
+                    if let Ok(mut live_audio) = live_audio_clone.lock() {
+                        live_audio.push_interleaved(data, device_channels);
+                    }
+
                     // Calculate RMS and emit at ~30Hz (every 33ms)
                     let rms = (sum_squares / data.len() as f32).sqrt();
                     let level = (rms * 100.0).min(100.0); // Convert to 0-100 scale
@@ -265,6 +338,11 @@ impl AudioRecorder {
         self.is_recording.load(Ordering::Acquire)
     }
 
+    /// Recent audio of the current (or last) recording
+    pub fn live_audio(&self) -> Arc<Mutex<LiveAudioBuffer>> {
+        Arc::clone(&self.live_audio)
+    }
+
     /// Get current recorder state
     pub fn get_state(&self) -> RecorderState {
         *self.state.lock().unwrap()
@@ -315,6 +393,31 @@ mod tests {
         assert!(devices.is_ok());
     }
 
+    #[test]
+    fn test_live_audio_buffer() {
+        let mut buffer = LiveAudioBuffer::new(10);
+
+        // Stereo frames are averaged to mono
+        buffer.push_interleaved(&[0.2, 0.4, 1.0, 0.0], 2);
+        let (start, samples) = buffer.since(0);
+        assert_eq!(start, 0);
+        assert_eq!(samples.len(), 2);
+        assert!((samples[0] - 0.3).abs() < 1e-6);
+        assert!((samples[1] - 0.5).abs() < 1e-6);
+
+        // Only the last LIVE_BUFFER_SECS are kept
+        buffer.push_interleaved(&vec![0.0; 10 * LIVE_BUFFER_SECS as usize], 1);
+        assert_eq!(buffer.total_frames(), 2 + 10 * LIVE_BUFFER_SECS as u64);
+
+        let (start, samples) = buffer.since(0);
+        assert_eq!(start, 2);
+        assert_eq!(samples.len(), 10 * LIVE_BUFFER_SECS as usize);
+
+        let (start, samples) = buffer.since(buffer.total_frames() - 5);
+        assert_eq!(start, buffer.total_frames() - 5);
+        assert_eq!(samples.len(), 5);
+    }
+
     #[test]
     fn test_recording_lifecycle() {
         let dir = tempdir().unwrap();
@@ -42,6 +42,18 @@ impl RecordingState {
     }
 }
 
+/// What to do with the speech once a dictation stops
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/features/voice-input/types/generated/")]
+#[serde(rename_all = "lowercase")]
+pub enum DictationMode {
+    /// Transcribe in the spoken language
+    #[default]
+    Transcribe,
+    /// Translate the speech into English
+    Translate,
+}
+
 /// Global recording state
 pub struct RecordingStateManager {
     state: Arc<Mutex<RecordingState>>,
@@ -49,6 +61,7 @@ pub struct RecordingStateManager {
     error_message: Arc<Mutex<Option<String>>>,
     recording_device: Arc<Mutex<Option<String>>>,
     start_time: Arc<Mutex<Option<i64>>>,
+    dictation_mode: Arc<Mutex<DictationMode>>,
 }
 
 impl RecordingStateManager {
@@ -59,6 +72,7 @@ impl RecordingStateManager {
             error_message: Arc::new(Mutex::new(None)),
             recording_device: Arc::new(Mutex::new(None)),
             start_time: Arc::new(Mutex::new(None)),
+            dictation_mode: Arc::new(Mutex::new(DictationMode::default())),
         }
     }
 
@@ -139,6 +153,16 @@ impl RecordingStateManager {
     pub fn get_start_time(&self) -> Option<i64> {
         *self.start_time.lock().unwrap()
     }
+
+    /// Set the mode of the current dictation
+    pub fn set_dictation_mode(&self, mode: DictationMode) {
+        *self.dictation_mode.lock().unwrap() = mode;
+    }
+
+    /// Get the mode of the current dictation
+    pub fn get_dictation_mode(&self) -> DictationMode {
+        *self.dictation_mode.lock().unwrap()
+    }
 }
 
 impl Default for RecordingStateManager {
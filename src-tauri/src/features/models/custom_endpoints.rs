@@ -0,0 +1,372 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{command, AppHandle};
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use super::models_registry::{ModelDefinition, ModelProvider, ModelPurpose, ModelType};
+use crate::features::security;
+
+/// Key in models.json holding user-defined endpoints
+const CUSTOM_ENDPOINTS_KEY: &str = "customEndpoints";
+
+/// A user-defined OpenAI-compatible endpoint
+///
+/// Speech-to-text endpoints are servers exposing `/audio/transcriptions`
+/// (faster-whisper, whisper.cpp server, Groq, ...). Post-processing
+/// endpoints expose `/chat/completions` (Ollama, LM Studio, OpenRouter,
+/// vLLM, ...). Stored in models.json under `customEndpoints` and surfaced
+/// as a regular model by `get_all_models`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/features/models/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct CustomEndpoint {
+    pub id: String,
+    /// Display name shown in the models list
+    pub name: String,
+    /// Base URL including the API version (e.g., "http://localhost:8000/v1")
+    pub base_url: String,
+    /// Model name sent to the endpoint (e.g., "whisper-large-v3", "llama3.2")
+    pub model: String,
+    /// "openai-compatible", or "ollama"/"lmstudio" for those local servers
+    #[serde(default = "default_provider")]
+    pub provider: ModelProvider,
+    #[serde(default = "default_purpose")]
+    pub purpose: ModelPurpose,
+}
+
+fn default_provider() -> ModelProvider {
+    ModelProvider::OpenAICompatible
+}
+
+fn default_purpose() -> ModelPurpose {
+    ModelPurpose::SpeechToText
+}
+
+impl CustomEndpoint {
+    pub fn to_model_definition(&self) -> ModelDefinition {
+        ModelDefinition {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            provider: self.provider,
+            model_type: ModelType::Cloud,
+            purpose: self.purpose.clone(),
+            engine: None,
+            size: None,
+            // The key is optional, so never block selection on it
+            requires_api_key: false,
+            is_selected: false,
+            is_downloaded: None,
+            path: None,
+            description: Some(format!("{} at {}", self.model, self.base_url)),
+            download_url: None,
+            filename: None,
+            base_url: Some(self.base_url.clone()),
+            languages: None,
+            recommended_ram_mb: None,
+            features: None,
+        }
+    }
+}
+
+/// Default server address for known local providers
+fn default_base_url(provider: ModelProvider) -> Option<&'static str> {
+    match provider {
+        ModelProvider::Ollama => Some("http://localhost:11434/v1"),
+        ModelProvider::LMStudio => Some("http://localhost:1234/v1"),
+        _ => None,
+    }
+}
+
+/// Validate a base URL and strip trailing slashes
+fn normalize_base_url(base_url: &str) -> Result<String, String> {
+    let trimmed = base_url.trim().trim_end_matches('/');
+
+    if !(trimmed.starts_with("http://") || trimmed.starts_with("https://")) {
+        return Err(format!(
+            "Invalid base URL '{}': must start with http:// or https://",
+            base_url
+        ));
+    }
+
+    Ok(trimmed.to_string())
+}
+
+/// Read all user-defined endpoints from models.json
+pub fn get_custom_endpoints(app: &AppHandle) -> Result<Vec<CustomEndpoint>, String> {
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to access models store: {}", e))?;
+
+    match store.get(CUSTOM_ENDPOINTS_KEY) {
+        Some(value) => serde_json::from_value(value)
+            .map_err(|e| format!("Failed to parse custom endpoints: {}", e)),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Look up a user-defined endpoint by model ID
+pub fn get_custom_endpoint(app: &AppHandle, model_id: &str) -> Result<CustomEndpoint, String> {
+    get_custom_endpoints(app)?
+        .into_iter()
+        .find(|endpoint| endpoint.id == model_id)
+        .ok_or_else(|| format!("Custom endpoint not found: {}", model_id))
+}
+
+fn save_custom_endpoints(app: &AppHandle, endpoints: &[CustomEndpoint]) -> Result<(), String> {
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to access models store: {}", e))?;
+
+    let value = serde_json::to_value(endpoints)
+        .map_err(|e| format!("Failed to serialize custom endpoints: {}", e))?;
+
+    store.set(CUSTOM_ENDPOINTS_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save models: {}", e))
+}
+
+/// Add a user-defined OpenAI-compatible endpoint
+///
+/// `provider` defaults to "openai-compatible" and `purpose` to speech-to-text.
+/// Ollama and LM Studio fall back to their default localhost address when no
+/// base URL is given. The model is also added to the `models` list so it can
+/// be selected and hold an encrypted API key right away.
+#[command]
+pub async fn add_custom_endpoint_model(
+    app: AppHandle,
+    name: String,
+    base_url: Option<String>,
+    model: String,
+    api_key: Option<String>,
+    provider: Option<ModelProvider>,
+    purpose: Option<ModelPurpose>,
+) -> Result<ModelDefinition, String> {
+    let name = name.trim();
+    let model = model.trim();
+    if name.is_empty() || model.is_empty() {
+        return Err("Name and model are required".to_string());
+    }
+
+    let provider = provider.unwrap_or_else(default_provider);
+    let purpose = purpose.unwrap_or_else(default_purpose);
+
+    match (&purpose, provider) {
+        (ModelPurpose::SpeechToText, ModelProvider::OpenAICompatible) => {}
+        (
+            ModelPurpose::PostProcessing,
+            ModelProvider::OpenAICompatible | ModelProvider::Ollama | ModelProvider::LMStudio,
+        ) => {}
+        _ => {
+            return Err(format!(
+                "Provider '{}' is not supported for custom {:?} endpoints",
+                provider, purpose
+            ))
+        }
+    }
+
+    let base_url = base_url
+        .filter(|url| !url.trim().is_empty())
+        .or_else(|| default_base_url(provider).map(String::from))
+        .ok_or("Base URL is required")?;
+
+    let endpoint = CustomEndpoint {
+        id: format!("{}-{}", provider, chrono::Utc::now().timestamp_millis()),
+        name: name.to_string(),
+        base_url: normalize_base_url(&base_url)?,
+        model: model.to_string(),
+        provider,
+        purpose,
+    };
+
+    let mut endpoints = get_custom_endpoints(&app)?;
+    endpoints.push(endpoint.clone());
+    save_custom_endpoints(&app, &endpoints)?;
+
+    let definition = endpoint.to_model_definition();
+
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to access models store: {}", e))?;
+
+    let mut models = store
+        .get("models")
+        .and_then(|v| v.as_array().cloned())
+        .unwrap_or_default();
+
+    models.push(
+        serde_json::to_value(&definition)
+            .map_err(|e| format!("Failed to serialize model: {}", e))?,
+    );
+    store.set("models", Value::Array(models));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save models: {}", e))?;
+
+    if let Some(api_key) = api_key.filter(|key| !key.trim().is_empty()) {
+        security::store_api_key(app.clone(), definition.id.clone(), api_key).await?;
+    }
+
+    log::info!(
+        "Added custom endpoint '{}' ({} at {})",
+        endpoint.name,
+        endpoint.model,
+        endpoint.base_url
+    );
+
+    Ok(definition)
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelListResponse {
+    data: Vec<ModelListEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelListEntry {
+    id: String,
+}
+
+/// Fetch the model IDs an OpenAI-compatible server advertises at `/models`
+async fn fetch_endpoint_models(
+    base_url: &str,
+    api_key: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let client = reqwest::Client::new();
+    let mut request = client.get(format!("{}/models", base_url));
+
+    if let Some(key) = api_key {
+        request = request.header("Authorization", format!("Bearer {}", key));
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach {}: {}", base_url, e))?;
+
+    let status = response.status();
+    let response_text = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    if !status.is_success() {
+        return Err(format!(
+            "Model listing failed with status {}: {}",
+            status, response_text
+        ));
+    }
+
+    let models: ModelListResponse = serde_json::from_str(&response_text)
+        .map_err(|e| format!("Failed to parse model list: {}", e))?;
+
+    let mut ids: Vec<String> = models.data.into_iter().map(|m| m.id).collect();
+    ids.sort();
+    Ok(ids)
+}
+
+/// List models available on an OpenAI-compatible server (e.g., a local Ollama)
+#[command]
+pub async fn list_endpoint_models(
+    base_url: Option<String>,
+    api_key: Option<String>,
+    provider: Option<ModelProvider>,
+) -> Result<Vec<String>, String> {
+    let provider = provider.unwrap_or_else(default_provider);
+    let base_url = base_url
+        .filter(|url| !url.trim().is_empty())
+        .or_else(|| default_base_url(provider).map(String::from))
+        .ok_or("Base URL is required")?;
+
+    let api_key = api_key.filter(|key| !key.trim().is_empty());
+    fetch_endpoint_models(&normalize_base_url(&base_url)?, api_key.as_deref()).await
+}
+
+/// Remove a user-defined endpoint, its model entry and its API key
+#[command]
+pub async fn remove_custom_endpoint_model(app: AppHandle, model_id: String) -> Result<(), String> {
+    let mut endpoints = get_custom_endpoints(&app)?;
+    let count = endpoints.len();
+    endpoints.retain(|endpoint| endpoint.id != model_id);
+
+    if endpoints.len() == count {
+        return Err(format!("Custom endpoint not found: {}", model_id));
+    }
+
+    save_custom_endpoints(&app, &endpoints)?;
+
+    // Drop the saved credentials too; a key may never have been set
+    let _ = security::remove_api_key(app.clone(), model_id.clone()).await;
+
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to access models store: {}", e))?;
+
+    if let Some(mut models) = store.get("models").and_then(|v| v.as_array().cloned()) {
+        models.retain(|m| m.get("id").and_then(|v| v.as_str()) != Some(model_id.as_str()));
+        store.set("models", Value::Array(models));
+        store
+            .save()
+            .map_err(|e| format!("Failed to save models: {}", e))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::transcription::providers::test_server::TestServer;
+
+    #[test]
+    fn test_normalize_base_url() {
+        assert_eq!(
+            normalize_base_url(" http://localhost:8000/v1/ ").unwrap(),
+            "http://localhost:8000/v1"
+        );
+        assert_eq!(
+            normalize_base_url("https://api.groq.com/openai/v1").unwrap(),
+            "https://api.groq.com/openai/v1"
+        );
+        assert!(normalize_base_url("localhost:8000").is_err());
+    }
+
+    #[test]
+    fn test_endpoint_defaults_for_older_entries() {
+        let endpoint: CustomEndpoint = serde_json::from_str(
+            r#"{"id":"openai-compatible-1","name":"Groq","baseUrl":"https://api.groq.com/openai/v1","model":"whisper-large-v3"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(endpoint.provider, ModelProvider::OpenAICompatible);
+        assert_eq!(endpoint.purpose, ModelPurpose::SpeechToText);
+        assert_eq!(
+            default_base_url(ModelProvider::Ollama),
+            Some("http://localhost:11434/v1")
+        );
+    }
+
+    #[test]
+    fn test_fetch_endpoint_models() {
+        let server = TestServer::start(|_| {
+            (
+                200,
+                r#"{"object":"list","data":[{"id":"qwen2.5:7b","object":"model"},{"id":"llama3.2","object":"model"}]}"#
+                    .to_string(),
+            )
+        });
+
+        let models = tauri::async_runtime::block_on(fetch_endpoint_models(
+            &format!("{}/v1", server.base_url),
+            Some("sk-local"),
+        ))
+        .unwrap();
+
+        assert_eq!(models, vec!["llama3.2", "qwen2.5:7b"]);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.route(), "/v1/models");
+        assert_eq!(request.header("authorization"), Some("Bearer sk-local"));
+    }
+}
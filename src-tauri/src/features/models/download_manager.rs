@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+use tokio::sync::watch;
+
+/// What a running download should do next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadSignal {
+    Run,
+    /// Stop and keep the partial file so the download can resume later
+    Pause,
+    /// Stop and discard the partial file
+    Cancel,
+}
+
+/// Everything needed to start (or restart) a model download
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadRequest {
+    pub model_id: String,
+    pub download_url: String,
+    pub filename: String,
+    pub engine_type: String,
+}
+
+struct DownloadJob {
+    request: DownloadRequest,
+    control: watch::Sender<DownloadSignal>,
+    /// No task is running; the partial file waits for a resume
+    paused: bool,
+}
+
+/// Tracks model downloads by model id
+///
+/// Held as Tauri state. Only one download per model can run at a time, so
+/// two clicks can't write the same file. Paused downloads stay registered
+/// until they are resumed or cancelled.
+pub struct DownloadManager {
+    jobs: Mutex<HashMap<String, DownloadJob>>,
+}
+
+impl DownloadManager {
+    pub fn new() -> Self {
+        Self {
+            jobs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Register a download and get the channel that controls it
+    ///
+    /// Fails if the model is already downloading. A paused download of the
+    /// same model is replaced.
+    pub fn start(
+        &self,
+        request: DownloadRequest,
+    ) -> Result<watch::Receiver<DownloadSignal>, String> {
+        let mut jobs = self.jobs.lock().unwrap();
+
+        if jobs.get(&request.model_id).is_some_and(|job| !job.paused) {
+            return Err(format!("{} is already downloading", request.model_id));
+        }
+
+        let (control, receiver) = watch::channel(DownloadSignal::Run);
+        jobs.insert(
+            request.model_id.clone(),
+            DownloadJob {
+                request,
+                control,
+                paused: false,
+            },
+        );
+
+        Ok(receiver)
+    }
+
+    /// Ask a running download to pause or cancel
+    pub fn signal(&self, model_id: &str, signal: DownloadSignal) -> Result<(), String> {
+        let jobs = self.jobs.lock().unwrap();
+        let job = jobs
+            .get(model_id)
+            .filter(|job| !job.paused)
+            .ok_or_else(|| format!("{} is not downloading", model_id))?;
+
+        job.control
+            .send(signal)
+            .map_err(|_| format!("Download of {} already finished", model_id))
+    }
+
+    /// Record that a download stopped on a pause request
+    pub fn mark_paused(&self, model_id: &str) {
+        if let Some(job) = self.jobs.lock().unwrap().get_mut(model_id) {
+            job.paused = true;
+        }
+    }
+
+    /// Remove a paused download and return how to restart it
+    pub fn take_paused(&self, model_id: &str) -> Result<DownloadRequest, String> {
+        let mut jobs = self.jobs.lock().unwrap();
+
+        match jobs.get(model_id) {
+            Some(job) if job.paused => Ok(jobs.remove(model_id).unwrap().request),
+            Some(_) => Err(format!("{} is still downloading", model_id)),
+            None => Err(format!("{} has no paused download", model_id)),
+        }
+    }
+
+    /// Forget a download that completed, failed or was cancelled
+    pub fn finish(&self, model_id: &str) {
+        self.jobs.lock().unwrap().remove(model_id);
+    }
+}
+
+impl Default for DownloadManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Smoothed transfer rate for progress events
+pub struct SpeedTracker {
+    last_bytes: u64,
+    last_time: Instant,
+    bytes_per_second: f64,
+}
+
+impl SpeedTracker {
+    /// Weight of the newest sample; lower is smoother
+    const SMOOTHING: f64 = 0.3;
+
+    pub fn new(bytes: u64, now: Instant) -> Self {
+        Self {
+            last_bytes: bytes,
+            last_time: now,
+            bytes_per_second: 0.0,
+        }
+    }
+
+    /// Add a sample and return the smoothed bytes per second
+    pub fn update(&mut self, bytes: u64, now: Instant) -> f64 {
+        let elapsed = now.duration_since(self.last_time).as_secs_f64();
+        if elapsed > 0.0 {
+            let rate = bytes.saturating_sub(self.last_bytes) as f64 / elapsed;
+            self.bytes_per_second = if self.bytes_per_second == 0.0 {
+                rate
+            } else {
+                Self::SMOOTHING * rate + (1.0 - Self::SMOOTHING) * self.bytes_per_second
+            };
+            self.last_bytes = bytes;
+            self.last_time = now;
+        }
+
+        self.bytes_per_second
+    }
+
+    /// Seconds left at the current rate, if the total and rate are known
+    pub fn eta_seconds(&self, downloaded: u64, total: u64) -> Option<f64> {
+        (total > 0 && self.bytes_per_second > 0.0)
+            .then(|| total.saturating_sub(downloaded) as f64 / self.bytes_per_second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn request(model_id: &str) -> DownloadRequest {
+        DownloadRequest {
+            model_id: model_id.to_string(),
+            download_url: "https://example.com/model.bin".to_string(),
+            filename: "model.bin".to_string(),
+            engine_type: "whisper".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_one_download_per_model() {
+        let manager = DownloadManager::new();
+
+        let control = manager.start(request("whisper-tiny")).unwrap();
+        assert!(manager.start(request("whisper-tiny")).is_err());
+        assert!(manager.start(request("whisper-base")).is_ok());
+
+        manager
+            .signal("whisper-tiny", DownloadSignal::Cancel)
+            .unwrap();
+        assert_eq!(*control.borrow(), DownloadSignal::Cancel);
+
+        manager.finish("whisper-tiny");
+        assert!(manager
+            .signal("whisper-tiny", DownloadSignal::Pause)
+            .is_err());
+        assert!(manager.start(request("whisper-tiny")).is_ok());
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let manager = DownloadManager::new();
+        let _control = manager.start(request("whisper-tiny")).unwrap();
+
+        // Only a paused download can be taken for resuming
+        assert!(manager.take_paused("whisper-tiny").is_err());
+
+        manager.mark_paused("whisper-tiny");
+        assert!(manager
+            .signal("whisper-tiny", DownloadSignal::Pause)
+            .is_err());
+        assert_eq!(
+            manager.take_paused("whisper-tiny").unwrap(),
+            request("whisper-tiny")
+        );
+        assert!(manager.take_paused("whisper-tiny").is_err());
+    }
+
+    #[test]
+    fn test_speed_and_eta() {
+        let start = Instant::now();
+        let mut speed = SpeedTracker::new(0, start);
+        assert_eq!(speed.eta_seconds(0, 1000), None);
+
+        assert_eq!(speed.update(100, start + Duration::from_secs(1)), 100.0);
+        assert_eq!(speed.eta_seconds(100, 1000), Some(9.0));
+
+        // A faster second is smoothed rather than taken as is
+        let rate = speed.update(300, start + Duration::from_secs(2));
+        assert!((rate - 130.0).abs() < 1e-9);
+        assert_eq!(speed.eta_seconds(300, 0), None);
+    }
+}
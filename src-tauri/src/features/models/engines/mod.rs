@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::features::transcription::providers::TranscriptionResponse;
+
 pub mod whisper;
 
 /// Status of a local model engine
@@ -18,6 +20,9 @@ pub struct ModelConfig {
     pub model_path: String,
     pub model_name: String,
     pub language: Option<String>,
+    /// Engine-specific decoding parameters from the model's models.json
+    /// entry (`decodeOptions`); each engine parses its own format
+    pub decode_options: serde_json::Value,
 }
 
 /// Information about a loaded model
@@ -26,12 +31,34 @@ pub struct ModelInfo {
     pub name: String,
     pub path: String,
     pub engine_type: String,
+    /// Decoding parameters in use, in the engine's own format
+    pub decode_options: serde_json::Value,
+}
+
+/// What a local engine is used for
+///
+/// The manager keeps one loaded model of each kind, so a dictation model
+/// and a post-processing model can be in memory at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EngineKind {
+    SpeechToText,
+    TextGeneration,
+}
+
+/// Limits for one text completion
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationOptions {
+    /// Maximum number of tokens to generate
+    pub max_tokens: u32,
+    /// Generation ends before the first of these appears in the output
+    pub stop_sequences: Vec<String>,
 }
 
-/// Common interface for all local model engines
+/// Lifecycle shared by all local model engines
 ///
-/// Any local model (Whisper, Llama, Mistral, etc.) must implement this trait
-/// to be managed by the LocalModelManager
+/// Engines implement this together with the trait for what they do:
+/// `SpeechToTextEngine` (Whisper) or `TextGenerationEngine` (Llama,
+/// Mistral, etc.).
 pub trait LocalModelEngine: Send + Sync {
     /// Load a model into memory
     fn load_model(&mut self, config: ModelConfig) -> Result<(), String>;
@@ -39,16 +66,6 @@ pub trait LocalModelEngine: Send + Sync {
     /// Unload the currently loaded model from memory
     fn unload_model(&mut self);
 
-    /// Process audio data and return transcription
-    ///
-    /// Note: For non-transcription models (like LLMs), this might be used differently
-    /// or they might implement a different trait in the future
-    fn transcribe(
-        &mut self,
-        audio_data: Vec<u8>,
-        language: Option<String>,
-    ) -> Result<String, String>;
-
     /// Get current status of the engine
     fn get_status(&self) -> ModelStatus;
 
@@ -58,3 +75,23 @@ pub trait LocalModelEngine: Send + Sync {
     /// Get the engine type identifier (e.g., "whisper", "llama")
     fn engine_type(&self) -> &'static str;
 }
+
+/// A local engine that turns speech into text
+pub trait SpeechToTextEngine: LocalModelEngine {
+    /// Process audio data and return transcription with segment/word timings
+    fn transcribe(
+        &mut self,
+        audio_data: Vec<u8>,
+        language: Option<String>,
+        options: Option<serde_json::Value>,
+    ) -> Result<TranscriptionResponse, String>;
+
+    /// Replace the decoding parameters of the loaded model
+    fn set_decode_options(&mut self, options: serde_json::Value) -> Result<(), String>;
+}
+
+/// A local engine that continues a text prompt
+pub trait TextGenerationEngine: LocalModelEngine {
+    /// Generate a completion for `prompt`, without the prompt or stop sequence
+    fn generate(&mut self, prompt: &str, options: &GenerationOptions) -> Result<String, String>;
+}
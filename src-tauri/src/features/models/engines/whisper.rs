@@ -1,7 +1,14 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use ts_rs::TS;
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 
-use super::{LocalModelEngine, ModelConfig, ModelInfo, ModelStatus};
+use crate::features::audio::NormalizedAudio;
+use crate::features::transcription::providers::{
+    TranscriptionResponse, TranscriptionSegment, TranscriptionWord,
+};
+
+use super::{LocalModelEngine, ModelConfig, ModelInfo, ModelStatus, SpeechToTextEngine};
 
 /// Error types for Whisper operations
 #[derive(Debug)]
@@ -33,11 +40,110 @@ impl fmt::Display for WhisperError {
 
 impl std::error::Error for WhisperError {}
 
+/// Decoding parameters for a local Whisper model
+///
+/// Stored per model in models.json (`decodeOptions`). Defaults match
+/// whisper.cpp's own defaults, except that decoding is greedy with a
+/// single candidate unless `beamSize` is set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/features/models/types/generated/")]
+#[serde(rename_all = "camelCase", default)]
+pub struct WhisperDecodeOptions {
+    /// Beam search width; `None` (or 1) uses greedy decoding
+    pub beam_size: Option<u32>,
+    /// Candidates sampled per temperature step when decoding greedily
+    pub best_of: u32,
+    /// Initial sampling temperature
+    pub temperature: f32,
+    /// Temperature added on each fallback retry; 0 disables fallback
+    pub temperature_increment: f32,
+    /// Segments above this no-speech probability are treated as silence
+    pub no_speech_threshold: f32,
+    /// Retry with a higher temperature below this average log probability
+    pub logprob_threshold: f32,
+    /// CPU threads to use; `None` lets whisper.cpp decide
+    pub threads: Option<u32>,
+    pub suppress_blank: bool,
+    pub suppress_non_speech_tokens: bool,
+    /// Text fed to the decoder as previous context (names, jargon, style)
+    pub initial_prompt: Option<String>,
+    /// Translate into English; chosen per dictation, never stored
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    #[ts(skip)]
+    pub translate: bool,
+}
+
+impl Default for WhisperDecodeOptions {
+    fn default() -> Self {
+        Self {
+            beam_size: None,
+            best_of: 1,
+            temperature: 0.0,
+            temperature_increment: 0.2,
+            no_speech_threshold: 0.6,
+            logprob_threshold: -1.0,
+            threads: None,
+            suppress_blank: true,
+            suppress_non_speech_tokens: false,
+            initial_prompt: None,
+            translate: false,
+        }
+    }
+}
+
+impl WhisperDecodeOptions {
+    /// Parse the engine-agnostic `decodeOptions` value; null means defaults
+    pub fn from_value(value: serde_json::Value) -> Result<Self, String> {
+        if value.is_null() {
+            return Ok(Self::default());
+        }
+        serde_json::from_value(value).map_err(|e| format!("Invalid Whisper decode options: {}", e))
+    }
+
+    /// Reject values whisper.cpp would misbehave with
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(beam_size) = self.beam_size {
+            if !(1..=16).contains(&beam_size) {
+                return Err("Beam size must be between 1 and 16".to_string());
+            }
+        }
+        if !(1..=16).contains(&self.best_of) {
+            return Err("Best-of must be between 1 and 16".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.temperature) {
+            return Err("Temperature must be between 0 and 1".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.temperature_increment) {
+            return Err("Temperature increment must be between 0 and 1".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.no_speech_threshold) {
+            return Err("No-speech threshold must be between 0 and 1".to_string());
+        }
+        if self.threads == Some(0) {
+            return Err("Thread count must be at least 1".to_string());
+        }
+        Ok(())
+    }
+
+    fn sampling_strategy(&self) -> SamplingStrategy {
+        match self.beam_size {
+            Some(beam_size) if beam_size > 1 => SamplingStrategy::BeamSearch {
+                beam_size: beam_size as i32,
+                patience: -1.0,
+            },
+            _ => SamplingStrategy::Greedy {
+                best_of: self.best_of.max(1) as i32,
+            },
+        }
+    }
+}
+
 /// Represents a loaded Whisper model instance
 struct LoadedWhisperModel {
     name: String,
     path: String,
     context: WhisperContext,
+    decode_options: WhisperDecodeOptions,
 }
 
 /// Whisper engine implementation
@@ -61,16 +167,18 @@ impl WhisperEngine {
     ///
     /// # Arguments
     /// * `audio_data` - Audio samples as f32 values (normalized to -1.0 to 1.0)
-    /// * `language` - Optional language code (e.g., "en", "es", "fr")
+    /// * `language` - Optional language code (e.g., "en", "es", "fr"); `None` auto-detects
+    /// * `options` - Decoding parameters; `None` uses the ones the model was loaded with
     ///
     /// # Returns
-    /// * `Ok(String)` containing the transcribed text
+    /// * `Ok(TranscriptionResponse)` with text, segments and word timings
     /// * `Err(WhisperError)` if transcription failed
     fn transcribe_internal(
         &mut self,
         audio_data: Vec<f32>,
         language: Option<String>,
-    ) -> Result<String, WhisperError> {
+        options: Option<WhisperDecodeOptions>,
+    ) -> Result<TranscriptionResponse, WhisperError> {
         // Ensure a model is loaded
         let model = self
             .current_model
@@ -83,19 +191,41 @@ impl WhisperEngine {
         }
 
         // Create transcription parameters
-        let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+        let options = options.unwrap_or_else(|| model.decode_options.clone());
+        let mut params = FullParams::new(options.sampling_strategy());
+
+        params.set_temperature(options.temperature);
+        params.set_temperature_inc(options.temperature_increment);
+        params.set_no_speech_thold(options.no_speech_threshold);
+        params.set_logprob_thold(options.logprob_threshold);
+        params.set_suppress_blank(options.suppress_blank);
+        params.set_suppress_nst(options.suppress_non_speech_tokens);
+
+        if let Some(threads) = options.threads {
+            params.set_n_threads(threads as i32);
+        }
 
-        // Set language if provided
-        if let Some(ref lang) = language {
-            params.set_language(Some(lang));
+        if let Some(prompt) = options
+            .initial_prompt
+            .as_deref()
+            .filter(|prompt| !prompt.trim().is_empty())
+        {
+            params.set_initial_prompt(prompt);
         }
 
+        // Whisper detects the language when asked for "auto"
+        params.set_language(Some(language.as_deref().unwrap_or("auto")));
+        params.set_translate(options.translate);
+
         // Disable printing to stdout
         params.set_print_progress(false);
         params.set_print_special(false);
         params.set_print_realtime(false);
         params.set_print_timestamps(false);
 
+        // Per-token timings are needed for word-level timestamps
+        params.set_token_timestamps(true);
+
         // Create a state for transcription
         let mut state = model
             .context
@@ -107,130 +237,73 @@ impl WhisperEngine {
             .full(params, &audio_data)
             .map_err(|e| WhisperError::TranscriptionFailed(e.to_string()))?;
 
+        // Special tokens ([_BEG_], [_TT_...], etc.) have IDs at or above EOT
+        let token_eot = model.context.token_eot();
+
         // Collect all text segments using iterator
         let mut full_text = String::new();
+        let mut segments = Vec::new();
+        let mut words: Vec<TranscriptionWord> = Vec::new();
+
         for segment in state.as_iter() {
             let text = segment
                 .to_str()
                 .map_err(|e| WhisperError::TranscriptionFailed(e.to_string()))?;
             full_text.push_str(text.trim());
             full_text.push(' ');
-        }
-
-        Ok(full_text.trim().to_string())
-    }
-
-    /// Convert WAV audio bytes to f32 samples and resample to 16kHz for Whisper
-    fn convert_audio_to_samples(audio_data: Vec<u8>) -> Result<Vec<f32>, String> {
-        use hound::WavReader;
-        use std::io::Cursor;
 
-        let cursor = Cursor::new(audio_data);
-        let mut reader =
-            WavReader::new(cursor).map_err(|e| format!("Failed to parse WAV audio: {}", e))?;
-
-        let spec = reader.spec();
-        let input_sample_rate = spec.sample_rate;
-        let channels = spec.channels as usize;
-
-        log::info!(
-            "Input audio: {} Hz, {} channel(s)",
-            input_sample_rate,
-            channels
-        );
+            // Timestamps are in centiseconds
+            segments.push(TranscriptionSegment {
+                start: segment.start_timestamp() as f64 / 100.0,
+                end: segment.end_timestamp() as f64 / 100.0,
+                text: text.trim().to_string(),
+                speaker: None,
+            });
+
+            // Tokens are sub-word pieces; a leading space starts a new word
+            let mut current: Option<TranscriptionWord> = None;
+            for token in (0..segment.n_tokens()).filter_map(|i| segment.get_token(i)) {
+                if token.token_id() >= token_eot {
+                    continue;
+                }
 
-        // Read all samples and convert to f32
-        let mut samples: Vec<f32> = match spec.sample_format {
-            hound::SampleFormat::Float => reader
-                .samples::<f32>()
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| format!("Failed to read samples: {}", e))?,
-            hound::SampleFormat::Int => {
-                // Convert i16 samples to f32
-                let max_val = i16::MAX as f32;
-                reader
-                    .samples::<i16>()
-                    .map(|s| s.map(|v| v as f32 / max_val))
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|e| format!("Failed to read samples: {}", e))?
+                let piece = token
+                    .to_str_lossy()
+                    .map_err(|e| WhisperError::TranscriptionFailed(e.to_string()))?;
+                let data = token.token_data();
+                let (start, end) = (data.t0 as f64 / 100.0, data.t1 as f64 / 100.0);
+
+                match current.as_mut() {
+                    Some(word) if !piece.starts_with(' ') => {
+                        word.word.push_str(&piece);
+                        word.end = end;
+                    }
+                    _ => {
+                        words.extend(current.take());
+                        current = Some(TranscriptionWord {
+                            start,
+                            end,
+                            word: piece.trim_start().to_string(),
+                            speaker: None,
+                        });
+                    }
+                }
             }
-        };
-
-        // If stereo, convert to mono by averaging channels
-        if channels == 2 {
-            log::info!("Converting stereo to mono");
-            samples = samples
-                .chunks(2)
-                .map(|chunk| (chunk[0] + chunk[1]) / 2.0)
-                .collect();
-        } else if channels > 2 {
-            return Err(format!(
-                "Unsupported channel count: {}. Only mono and stereo are supported.",
-                channels
-            ));
-        }
-
-        // Whisper expects 16kHz audio - resample if necessary
-        const TARGET_SAMPLE_RATE: u32 = 16000;
-        if input_sample_rate != TARGET_SAMPLE_RATE {
-            log::info!(
-                "Resampling from {} Hz to {} Hz",
-                input_sample_rate,
-                TARGET_SAMPLE_RATE
-            );
-            samples = Self::resample_audio(samples, input_sample_rate, TARGET_SAMPLE_RATE)?;
-            log::info!("Resampling complete: {} samples", samples.len());
-        }
-
-        Ok(samples)
-    }
-
-    /// Resample audio from one sample rate to another
-    fn resample_audio(
-        input: Vec<f32>,
-        input_rate: u32,
-        output_rate: u32,
-    ) -> Result<Vec<f32>, String> {
-        use rubato::{
-            Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType,
-            WindowFunction,
-        };
-
-        if input.is_empty() {
-            return Ok(input);
+            words.extend(current);
         }
 
-        // Calculate resampling ratio
-        let resample_ratio = output_rate as f64 / input_rate as f64;
+        words.retain(|word| !word.word.trim().is_empty());
 
-        // Create resampler with high quality settings
-        let params = SincInterpolationParameters {
-            sinc_len: 256,
-            f_cutoff: 0.95,
-            interpolation: SincInterpolationType::Linear,
-            oversampling_factor: 256,
-            window: WindowFunction::BlackmanHarris2,
-        };
+        // Report the language Whisper actually decoded with
+        let detected_language =
+            whisper_rs::get_lang_str(state.full_lang_id_from_state()).map(String::from);
 
-        let mut resampler = SincFixedIn::<f32>::new(
-            resample_ratio,
-            2.0,
-            params,
-            input.len(),
-            1, // 1 channel (mono)
-        )
-        .map_err(|e| format!("Failed to create resampler: {}", e))?;
-
-        // Rubato expects Vec<Vec<f32>> where outer vec is channels
-        let input_frames = vec![input];
-
-        // Perform resampling
-        let output_frames = resampler
-            .process(&input_frames, None)
-            .map_err(|e| format!("Resampling failed: {}", e))?;
-
-        // Extract mono channel
-        Ok(output_frames[0].clone())
+        Ok(TranscriptionResponse {
+            text: full_text.trim().to_string(),
+            language: detected_language.or(language),
+            segments: Some(segments),
+            words: Some(words),
+        })
     }
 }
 
@@ -249,6 +322,12 @@ impl LocalModelEngine for WhisperEngine {
             return Err("Model file not found".to_string());
         }
 
+        let decode_options =
+            WhisperDecodeOptions::from_value(config.decode_options).map_err(|e| {
+                self.status = ModelStatus::Error;
+                e
+            })?;
+
         // Load whisper model with default parameters
         let ctx_params = WhisperContextParameters::default();
 
@@ -269,6 +348,7 @@ impl LocalModelEngine for WhisperEngine {
             name: config.model_name,
             path: config.model_path,
             context,
+            decode_options,
         });
 
         self.status = ModelStatus::Ready;
@@ -280,19 +360,6 @@ impl LocalModelEngine for WhisperEngine {
         self.status = ModelStatus::Stopped;
     }
 
-    fn transcribe(
-        &mut self,
-        audio_data: Vec<u8>,
-        language: Option<String>,
-    ) -> Result<String, String> {
-        // Convert audio bytes to samples
-        let samples = Self::convert_audio_to_samples(audio_data)?;
-
-        // Perform transcription
-        self.transcribe_internal(samples, language)
-            .map_err(|e| e.to_string())
-    }
-
     fn get_status(&self) -> ModelStatus {
         self.status
     }
@@ -302,6 +369,7 @@ impl LocalModelEngine for WhisperEngine {
             name: m.name.clone(),
             path: m.path.clone(),
             engine_type: "whisper".to_string(),
+            decode_options: serde_json::to_value(&m.decode_options).unwrap_or_default(),
         })
     }
 
@@ -310,8 +378,94 @@ impl LocalModelEngine for WhisperEngine {
     }
 }
 
+impl SpeechToTextEngine for WhisperEngine {
+    fn transcribe(
+        &mut self,
+        audio_data: Vec<u8>,
+        language: Option<String>,
+        options: Option<serde_json::Value>,
+    ) -> Result<TranscriptionResponse, String> {
+        let options = options.map(WhisperDecodeOptions::from_value).transpose()?;
+
+        // Convert audio bytes to 16kHz mono samples
+        let samples = NormalizedAudio::from_wav(&audio_data)?.samples;
+
+        // Perform transcription
+        self.transcribe_internal(samples, language, options)
+            .map_err(|e| e.to_string())
+    }
+
+    fn set_decode_options(&mut self, options: serde_json::Value) -> Result<(), String> {
+        let options = WhisperDecodeOptions::from_value(options)?;
+        if let Some(model) = self.current_model.as_mut() {
+            model.decode_options = options;
+        }
+        Ok(())
+    }
+}
+
 impl Default for WhisperEngine {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_options_defaults_and_partial_json() {
+        let options: WhisperDecodeOptions =
+            serde_json::from_str(r#"{"beamSize": 5, "initialPrompt": "Dicta, Tauri"}"#).unwrap();
+
+        assert_eq!(options.beam_size, Some(5));
+        assert_eq!(options.initial_prompt.as_deref(), Some("Dicta, Tauri"));
+        assert_eq!(options.temperature_increment, 0.2);
+        assert!(options.suppress_blank);
+        assert!(options.validate().is_ok());
+    }
+
+    #[test]
+    fn test_decode_options_validation() {
+        let invalid = [
+            WhisperDecodeOptions {
+                beam_size: Some(0),
+                ..Default::default()
+            },
+            WhisperDecodeOptions {
+                temperature: 1.5,
+                ..Default::default()
+            },
+            WhisperDecodeOptions {
+                threads: Some(0),
+                ..Default::default()
+            },
+        ];
+
+        for options in invalid {
+            assert!(options.validate().is_err(), "{:?}", options);
+        }
+    }
+
+    #[test]
+    fn test_decode_options_from_value() {
+        assert_eq!(
+            WhisperDecodeOptions::from_value(serde_json::Value::Null),
+            Ok(WhisperDecodeOptions::default())
+        );
+        assert!(WhisperDecodeOptions::from_value(serde_json::json!({"beamSize": "five"})).is_err());
+
+        // Translate travels with a dictation's options but is never stored
+        let stored = serde_json::to_value(WhisperDecodeOptions::default()).unwrap();
+        assert!(stored.get("translate").is_none());
+
+        let dictation = WhisperDecodeOptions {
+            translate: true,
+            ..Default::default()
+        };
+        let parsed =
+            WhisperDecodeOptions::from_value(serde_json::to_value(&dictation).unwrap()).unwrap();
+        assert_eq!(parsed, dictation);
+    }
+}
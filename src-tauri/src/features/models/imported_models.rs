@@ -0,0 +1,381 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+
+use super::model_catalog::ModelFeatures;
+use super::models_registry::{ModelDefinition, ModelProvider, ModelPurpose, ModelType};
+
+/// Key in models.json holding imported model files
+const IMPORTED_MODELS_KEY: &str = "importedModels";
+
+/// `GGML_FILE_MAGIC` as whisper.cpp reads it (a little-endian u32)
+const GGML_MAGIC: u32 = 0x6767_6d6c;
+
+/// whisper.cpp stores the quantization version as `ftype / 1000`
+const QNT_VERSION_FACTOR: i32 = 1000;
+
+/// Hyperparameters at the start of a ggml Whisper model file
+#[derive(Debug, Clone, PartialEq)]
+struct GgmlHeader {
+    n_vocab: i32,
+    n_audio_layer: i32,
+    n_text_layer: i32,
+    n_mels: i32,
+    ftype: i32,
+}
+
+impl GgmlHeader {
+    /// Weight type name (e.g., "f16", "q5_0")
+    fn weight_type(&self) -> String {
+        match self.ftype % QNT_VERSION_FACTOR {
+            0 => "f32".to_string(),
+            1 => "f16".to_string(),
+            2 => "q4_0".to_string(),
+            3 => "q4_1".to_string(),
+            7 => "q8_0".to_string(),
+            8 => "q5_0".to_string(),
+            9 => "q5_1".to_string(),
+            10 => "q2_k".to_string(),
+            11 => "q3_k".to_string(),
+            12 => "q4_k".to_string(),
+            13 => "q5_k".to_string(),
+            14 => "q6_k".to_string(),
+            other => format!("type {}", other),
+        }
+    }
+}
+
+/// Read and sanity-check the header of a ggml Whisper model
+///
+/// Only the ggml format whisper.cpp loads is accepted. The checks catch
+/// other ggml/GGUF models (LLMs) and truncated or unrelated files before
+/// they are copied.
+fn read_ggml_header(reader: &mut impl Read) -> Result<GgmlHeader, String> {
+    let mut bytes = [0u8; 48];
+    reader
+        .read_exact(&mut bytes)
+        .map_err(|_| "File is too small to be a Whisper model".to_string())?;
+
+    let field = |index: usize| {
+        let start = index * 4;
+        i32::from_le_bytes(bytes[start..start + 4].try_into().unwrap())
+    };
+
+    if &bytes[..4] == b"GGUF" {
+        return Err(
+            "GGUF files can't be loaded by whisper.cpp; convert the model to ggml first"
+                .to_string(),
+        );
+    }
+    if field(0) as u32 != GGML_MAGIC {
+        return Err("Not a ggml model file".to_string());
+    }
+
+    // Fields follow the magic in whisper.cpp's `whisper_hparams` order
+    let header = GgmlHeader {
+        n_vocab: field(1),
+        n_audio_layer: field(5),
+        n_text_layer: field(9),
+        n_mels: field(10),
+        ftype: field(11),
+    };
+    let n_audio_ctx = field(2);
+
+    // Every Whisper model has a 1500-frame encoder context, a vocabulary
+    // of about 51,865 tokens and 80 (or, for large-v3, 128) mel bands
+    if n_audio_ctx != 1500
+        || !(51_000..=52_000).contains(&header.n_vocab)
+        || !matches!(header.n_mels, 80 | 128)
+        || header.n_audio_layer <= 0
+        || header.n_text_layer <= 0
+    {
+        return Err("File is a ggml model, but not a Whisper model".to_string());
+    }
+
+    Ok(header)
+}
+
+/// A Whisper model file imported by the user
+///
+/// Stored in models.json under `importedModels` and surfaced as a regular
+/// local model by `get_all_models`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedModel {
+    pub id: String,
+    /// Display name shown in the models list
+    pub name: String,
+    /// File name inside `local_models/whisper`
+    pub filename: String,
+    /// Weight type read from the header (e.g., "q5_0")
+    pub weight_type: String,
+    pub size_bytes: u64,
+    /// File the model was imported from
+    pub source_path: String,
+}
+
+impl ImportedModel {
+    pub fn to_model_definition(&self, whisper_dir: &Path) -> ModelDefinition {
+        let model_path = whisper_dir.join(&self.filename);
+
+        ModelDefinition {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            provider: ModelProvider::LocalWhisper,
+            model_type: ModelType::Local,
+            purpose: ModelPurpose::SpeechToText,
+            engine: Some("whisper".to_string()),
+            size: Some(format_size(self.size_bytes)),
+            requires_api_key: false,
+            is_selected: false,
+            is_downloaded: Some(true),
+            path: Some(model_path.to_string_lossy().to_string()),
+            description: Some(format!(
+                "Imported Whisper model ({}) - Runs locally without internet",
+                self.weight_type
+            )),
+            download_url: None,
+            filename: Some(self.filename.clone()),
+            base_url: None,
+            languages: None,
+            recommended_ram_mb: None,
+            // Every ggml Whisper model can time segments and translate
+            features: Some(ModelFeatures {
+                timestamps: true,
+                translate: true,
+                diarization: false,
+            }),
+        }
+    }
+}
+
+/// Human-readable file size in the registry's style ("75 MB", "1.5 GB")
+fn format_size(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    let mb = bytes as f64 / MB;
+
+    if mb >= 1024.0 {
+        format!("{:.1} GB", mb / 1024.0)
+    } else {
+        format!("{:.0} MB", mb)
+    }
+}
+
+/// Read all imported models from models.json
+pub fn get_imported_models(app: &AppHandle) -> Result<Vec<ImportedModel>, String> {
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to access models store: {}", e))?;
+
+    match store.get(IMPORTED_MODELS_KEY) {
+        Some(value) => serde_json::from_value(value)
+            .map_err(|e| format!("Failed to parse imported models: {}", e)),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn save_imported_models(app: &AppHandle, models: &[ImportedModel]) -> Result<(), String> {
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to access models store: {}", e))?;
+
+    let value = serde_json::to_value(models)
+        .map_err(|e| format!("Failed to serialize imported models: {}", e))?;
+
+    store.set(IMPORTED_MODELS_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save models: {}", e))
+}
+
+/// Drop the imported model stored at `model_path`, if any
+pub fn forget_imported_model(app: &AppHandle, model_path: &Path) -> Result<(), String> {
+    let whisper_dir = get_whisper_dir(app)?;
+    let mut imported_models = get_imported_models(app)?;
+
+    let before = imported_models.len();
+    imported_models.retain(|model| whisper_dir.join(&model.filename) != model_path);
+
+    if imported_models.len() != before {
+        save_imported_models(app, &imported_models)?;
+    }
+
+    Ok(())
+}
+
+/// Directory holding Whisper model files
+pub fn get_whisper_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+
+    Ok(app_data_dir.join("local_models").join("whisper"))
+}
+
+/// Import a ggml Whisper model file (fine-tuned, quantized, converted, ...)
+///
+/// The file is hard-linked into `local_models/whisper` when it is on the
+/// same volume, and copied otherwise. It is then listed by `get_all_models`
+/// and can be started and used like a built-in model.
+#[command]
+pub async fn import_local_model(
+    app: AppHandle,
+    path: String,
+    display_name: String,
+) -> Result<ModelDefinition, String> {
+    let name = display_name.trim();
+    if name.is_empty() {
+        return Err("Display name is required".to_string());
+    }
+
+    let source = PathBuf::from(&path);
+    let mut file = File::open(&source).map_err(|e| format!("Failed to open model file: {}", e))?;
+    let header = read_ggml_header(&mut file)?;
+    let size_bytes = file
+        .metadata()
+        .map_err(|e| format!("Failed to read model file size: {}", e))?
+        .len();
+
+    let id = format!("whisper-imported-{}", chrono::Utc::now().timestamp_millis());
+    let filename = format!("{}.bin", id);
+
+    let whisper_dir = get_whisper_dir(&app)?;
+    std::fs::create_dir_all(&whisper_dir)
+        .map_err(|e| format!("Failed to create engine directory: {}", e))?;
+    let destination = whisper_dir.join(&filename);
+
+    // Copying a large model is slow; keep it off the async runtime
+    let target = destination.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        if std::fs::hard_link(&source, &target).is_err() {
+            std::fs::copy(&source, &target)
+                .map_err(|e| format!("Failed to copy model file: {}", e))?;
+        }
+        Ok::<(), String>(())
+    })
+    .await
+    .map_err(|e| format!("Model import task failed: {}", e))??;
+
+    let imported = ImportedModel {
+        id,
+        name: name.to_string(),
+        filename,
+        weight_type: header.weight_type(),
+        size_bytes,
+        source_path: path,
+    };
+
+    let definition = imported.to_model_definition(&whisper_dir);
+
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to access models store: {}", e))?;
+
+    let mut imported_models = get_imported_models(&app)?;
+    imported_models.push(imported.clone());
+    let imported_value = serde_json::to_value(&imported_models)
+        .map_err(|e| format!("Failed to serialize imported models: {}", e))?;
+
+    let previous_models = store.get("models");
+    let mut models = previous_models
+        .as_ref()
+        .and_then(|v| v.as_array().cloned())
+        .unwrap_or_default();
+    models.push(
+        serde_json::to_value(&definition)
+            .map_err(|e| format!("Failed to serialize model: {}", e))?,
+    );
+
+    // Both keys go out in one save, so the model is never half registered
+    let previous_imported = store.get(IMPORTED_MODELS_KEY);
+    store.set(IMPORTED_MODELS_KEY, imported_value);
+    store.set("models", Value::Array(models));
+
+    if let Err(e) = store.save() {
+        match previous_imported {
+            Some(value) => store.set(IMPORTED_MODELS_KEY, value),
+            None => {
+                store.delete(IMPORTED_MODELS_KEY);
+            }
+        }
+        match previous_models {
+            Some(value) => store.set("models", value),
+            None => {
+                store.delete("models");
+            }
+        }
+        let _ = std::fs::remove_file(&destination);
+        return Err(format!("Failed to save models: {}", e));
+    }
+
+    log::info!(
+        "Imported Whisper model '{}' ({}, {} layers) from {}",
+        imported.name,
+        imported.weight_type,
+        header.n_text_layer,
+        imported.source_path
+    );
+
+    Ok(definition)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Header of a q5_0 large-v3 model
+    fn header_bytes(magic: &[u8; 4], n_vocab: i32, n_mels: i32) -> Vec<u8> {
+        let mut bytes = magic.to_vec();
+        for value in [n_vocab, 1500, 1280, 20, 32, 448, 1280, 20, 32, n_mels, 1008] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_reads_whisper_header() {
+        let bytes = header_bytes(b"lmgg", 51866, 128);
+        let header = read_ggml_header(&mut Cursor::new(bytes)).unwrap();
+
+        assert_eq!(header.n_vocab, 51866);
+        assert_eq!(header.n_audio_layer, 32);
+        assert_eq!(header.n_text_layer, 32);
+        assert_eq!(header.n_mels, 128);
+        assert_eq!(header.weight_type(), "q5_0");
+    }
+
+    #[test]
+    fn test_rejects_other_files() {
+        let gguf = header_bytes(b"GGUF", 51866, 128);
+        assert!(read_ggml_header(&mut Cursor::new(gguf))
+            .unwrap_err()
+            .starts_with("GGUF"));
+
+        // A ggml language model has a much larger vocabulary
+        let llm = header_bytes(b"lmgg", 32000, 128);
+        assert!(read_ggml_header(&mut Cursor::new(llm))
+            .unwrap_err()
+            .contains("not a Whisper model"));
+
+        let text = b"definitely not a model file at all, just some text".to_vec();
+        assert_eq!(
+            read_ggml_header(&mut Cursor::new(text)).unwrap_err(),
+            "Not a ggml model file"
+        );
+
+        let short = b"lmgg".to_vec();
+        assert!(read_ggml_header(&mut Cursor::new(short)).is_err());
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(77_691_713), "74 MB");
+        assert_eq!(format_size(1_533_763_059), "1.4 GB");
+    }
+}
@@ -1,9 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tauri::{command, AppHandle, Emitter, State};
+use tauri_plugin_store::StoreExt;
 use tokio::sync::Mutex;
 
-use super::engines::{ModelConfig, ModelStatus};
+use super::engines::{whisper::WhisperDecodeOptions, EngineKind, ModelConfig, ModelStatus};
 use super::local_model_manager::LocalModelManager;
 
 /// Shared state type for local model manager
@@ -53,6 +54,7 @@ pub async fn start_local_model(
         model_path,
         model_name: model_name.clone(),
         language: None,
+        decode_options: get_decode_options(&app, &model_id),
     };
 
     // Load the model using the specified engine
@@ -85,18 +87,29 @@ pub async fn start_local_model(
     }
 }
 
-/// Stop (unload) the current local model from memory
+/// Stop (unload) a local model from memory
 ///
 /// # Arguments
+/// * `engine_type` - Engine of the model to stop (e.g., "whisper"); `None` stops all
 /// * `state` - Shared local model manager state
 /// * `app` - Tauri app handle for emitting events
 #[command]
 pub async fn stop_local_model(
+    engine_type: Option<String>,
     state: State<'_, LocalModelState>,
     app: AppHandle,
 ) -> Result<(), String> {
     let mut manager = state.lock().await;
-    manager.unload_model();
+
+    match engine_type {
+        Some(engine_type) => {
+            let kind = manager
+                .engine_kind(&engine_type)
+                .ok_or_else(|| format!("Unknown engine type: {}", engine_type))?;
+            manager.unload_model(kind);
+        }
+        None => manager.unload_all(),
+    }
 
     let _ = app.emit(
         "local-model-status",
@@ -125,20 +138,19 @@ pub async fn get_local_model_status(
 ) -> Result<LocalModelStatusInfo, String> {
     let manager = state.lock().await;
 
-    let model_info = manager.get_loaded_model_info();
-
-    // If a specific model_id is requested, check if it matches the loaded model
+    // If a specific model_id is requested, check if it matches a loaded model
     if let Some(requested_id) = model_id {
-        // Check if we have a loaded model and it matches
-        if let Some(info) = model_info {
+        for kind in [EngineKind::SpeechToText, EngineKind::TextGeneration] {
             // For now, we compare by model name since we don't store the full ID
             // This works for whisper models like "whisper-tiny" -> name is "tiny"
             // Future: Consider storing the full model_id in ModelInfo
-            let matches = requested_id.contains(&info.name);
+            let loaded = manager
+                .get_loaded_model_info(kind)
+                .filter(|info| requested_id.contains(&info.name));
 
-            if matches {
+            if let Some(info) = loaded {
                 return Ok(LocalModelStatusInfo {
-                    status: manager.get_status(),
+                    status: manager.get_status(kind),
                     model_name: Some(info.name),
                     model_id: Some(requested_id),
                 });
@@ -153,10 +165,96 @@ pub async fn get_local_model_status(
         });
     }
 
-    // Return current loaded model status
+    // Return current loaded speech-to-text model status
     Ok(LocalModelStatusInfo {
-        status: manager.get_status(),
-        model_name: model_info.as_ref().map(|i| i.name.clone()),
+        status: manager.get_status(EngineKind::SpeechToText),
+        model_name: manager.get_loaded_model_name(EngineKind::SpeechToText),
         model_id: None, // We don't have the full model ID without the request parameter
     })
 }
+
+/// Decoding parameters saved on a models.json entry (null if unset)
+pub fn decode_options_from_entry(entry: &serde_json::Value) -> serde_json::Value {
+    entry.get("decodeOptions").cloned().unwrap_or_default()
+}
+
+/// Decoding parameters saved for a model in models.json (null if unset)
+pub fn get_decode_options(app: &AppHandle, model_id: &str) -> serde_json::Value {
+    app.store("models.json")
+        .ok()
+        .and_then(|store| store.get("models"))
+        .and_then(|models| {
+            models.as_array().and_then(|models| {
+                models
+                    .iter()
+                    .find(|m| m.get("id").and_then(|v| v.as_str()) == Some(model_id))
+                    .map(decode_options_from_entry)
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// Save Whisper decoding parameters for a local model
+///
+/// The options are stored on the model's entry in models.json and applied
+/// straight away if the model is currently loaded.
+///
+/// # Arguments
+/// * `model_id` - The model identifier (e.g., "whisper-base")
+/// * `options` - Decoding parameters to use for this model
+/// * `state` - Shared local model manager state
+/// * `app` - Tauri app handle for accessing the models store
+#[command]
+pub async fn set_whisper_decode_options(
+    model_id: String,
+    options: WhisperDecodeOptions,
+    state: State<'_, LocalModelState>,
+    app: AppHandle,
+) -> Result<(), String> {
+    options.validate()?;
+
+    let store = app
+        .store("models.json")
+        .map_err(|e| format!("Failed to get models store: {}", e))?;
+
+    let mut models = store
+        .get("models")
+        .and_then(|v| v.as_array().cloned())
+        .ok_or("No models found in store")?;
+
+    let entry = models
+        .iter_mut()
+        .find(|m| m.get("id").and_then(|v| v.as_str()) == Some(model_id.as_str()))
+        .and_then(|m| m.as_object_mut())
+        .ok_or_else(|| format!("Model not found: {}", model_id))?;
+
+    if entry.get("engine").and_then(|v| v.as_str()) != Some("whisper") {
+        return Err(format!("{} is not a local Whisper model", model_id));
+    }
+
+    let model_path = entry.get("path").and_then(|v| v.as_str()).map(String::from);
+
+    let options = serde_json::to_value(WhisperDecodeOptions {
+        translate: false,
+        ..options
+    })
+    .map_err(|e| format!("Failed to serialize decode options: {}", e))?;
+    entry.insert("decodeOptions".to_string(), options.clone());
+
+    store.set("models", serde_json::Value::Array(models));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save models store: {}", e))?;
+
+    let mut manager = state.lock().await;
+    let is_loaded = manager
+        .get_loaded_model_info(EngineKind::SpeechToText)
+        .is_some_and(|info| Some(info.path) == model_path);
+    if is_loaded {
+        manager.set_decode_options(options)?;
+    }
+
+    let _ = app.emit("models-changed", ());
+
+    Ok(())
+}
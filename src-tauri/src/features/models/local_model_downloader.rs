@@ -1,7 +1,14 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
-use tauri::{command, AppHandle, Emitter, Manager};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use tauri::{command, AppHandle, Emitter, Manager, State};
 use tokio::io::AsyncWriteExt;
+use tokio::sync::watch;
+
+use super::download_manager::{DownloadManager, DownloadRequest, DownloadSignal, SpeedTracker};
+use super::imported_models::forget_imported_model;
+use super::model_catalog::model_checksum;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -10,10 +17,29 @@ pub struct DownloadProgress {
     pub total: u64,
     pub percentage: f64,
     pub model_id: String,
+    pub bytes_per_second: f64,
+    /// Seconds left at the current speed, once the size and speed are known
+    pub eta_seconds: Option<f64>,
+}
+
+/// Payload of the download completed, failed, paused and cancelled events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadStatus {
+    pub model_id: String,
+    /// Model file, once completed
+    pub path: Option<String>,
+    pub error: Option<String>,
+}
+
+/// How a download that didn't fail ended
+enum DownloadOutcome {
+    Completed(String),
+    Stopped(DownloadSignal),
 }
 
 /// Get the base directory for storing local models
-fn get_models_base_dir(app: &AppHandle) -> Result<PathBuf, String> {
+pub fn get_models_base_dir(app: &AppHandle) -> Result<PathBuf, String> {
     let app_data_dir = app
         .path()
         .app_data_dir()
@@ -39,8 +65,101 @@ fn get_engine_dir(app: &AppHandle, engine_type: &str) -> Result<PathBuf, String>
     Ok(engine_dir)
 }
 
+/// Minimum time between two progress events
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Where a download is written until it has been verified
+fn part_path(model_path: &Path) -> PathBuf {
+    let mut name = model_path.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// Check a downloaded file against the catalog's size and, if known, SHA-256
+fn verify_file(
+    path: &Path,
+    expected_size: u64,
+    expected_sha256: Option<&str>,
+) -> Result<(), String> {
+    let mut file =
+        std::fs::File::open(path).map_err(|e| format!("Failed to open download: {}", e))?;
+
+    let size = file
+        .metadata()
+        .map_err(|e| format!("Failed to read download size: {}", e))?
+        .len();
+    if size != expected_size {
+        return Err(format!(
+            "Downloaded file has {} bytes, expected {}",
+            size, expected_size
+        ));
+    }
+
+    let Some(expected_sha256) = expected_sha256 else {
+        return Ok(());
+    };
+
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(|e| format!("Failed to read download: {}", e))?;
+    let sha256 = format!("{:x}", hasher.finalize());
+
+    if !sha256.eq_ignore_ascii_case(expected_sha256) {
+        return Err(format!(
+            "Downloaded file checksum {} does not match expected {}",
+            sha256, expected_sha256
+        ));
+    }
+
+    Ok(())
+}
+
+fn emit_progress(
+    app: &AppHandle,
+    model_id: &str,
+    downloaded: u64,
+    total: u64,
+    speed: &mut SpeedTracker,
+) {
+    let percentage = if total > 0 {
+        (downloaded as f64 / total as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    let progress = DownloadProgress {
+        downloaded,
+        total,
+        percentage,
+        model_id: model_id.to_string(),
+        bytes_per_second: speed.update(downloaded, Instant::now()),
+        eta_seconds: speed.eta_seconds(downloaded, total),
+    };
+
+    let _ = app.emit("local-model-download-progress", progress);
+}
+
+fn emit_status(
+    app: &AppHandle,
+    event: &str,
+    model_id: &str,
+    path: Option<String>,
+    error: Option<String>,
+) {
+    let status = DownloadStatus {
+        model_id: model_id.to_string(),
+        path,
+        error,
+    };
+
+    let _ = app.emit(event, status);
+}
+
 /// Download a local model from a URL
 ///
+/// Only one download per model runs at a time; it can be paused, resumed
+/// and cancelled by model id. Emits `local-model-download-started`,
+/// `-progress`, `-completed`, `-failed`, `-paused` and `-cancelled`.
+///
 /// # Arguments
 /// * `model_id` - The model identifier (e.g., "whisper-tiny", "llama-3-8b")
 /// * `download_url` - The URL to download the model from
@@ -54,79 +173,282 @@ pub async fn download_local_model(
     filename: String,
     engine_type: String,
 ) -> Result<String, String> {
-    let engine_dir = get_engine_dir(&app, &engine_type)?;
-    let model_path = engine_dir.join(&filename);
+    let request = DownloadRequest {
+        model_id,
+        download_url,
+        filename,
+        engine_type,
+    };
+
+    run_download(&app, request).await
+}
+
+/// Stop a download and keep what was downloaded so far
+#[command]
+pub async fn pause_model_download(
+    manager: State<'_, DownloadManager>,
+    model_id: String,
+) -> Result<(), String> {
+    manager.signal(&model_id, DownloadSignal::Pause)
+}
+
+/// Continue a paused download from where it stopped
+#[command]
+pub async fn resume_model_download(app: AppHandle, model_id: String) -> Result<String, String> {
+    let request = app.state::<DownloadManager>().take_paused(&model_id)?;
+    run_download(&app, request).await
+}
+
+/// Stop a running or paused download and delete its partial file
+#[command]
+pub async fn cancel_model_download(app: AppHandle, model_id: String) -> Result<(), String> {
+    let manager = app.state::<DownloadManager>();
+
+    // A running download cleans up after itself once it sees the signal
+    if manager.signal(&model_id, DownloadSignal::Cancel).is_ok() {
+        return Ok(());
+    }
+
+    let request = manager.take_paused(&model_id)?;
+    let model_path = get_engine_dir(&app, &request.engine_type)?.join(&request.filename);
+    let _ = std::fs::remove_file(part_path(&model_path));
+
+    emit_status(
+        &app,
+        "local-model-download-cancelled",
+        &model_id,
+        None,
+        None,
+    );
+    Ok(())
+}
+
+/// Register a download with the manager, run it and report how it ended
+async fn run_download(app: &AppHandle, request: DownloadRequest) -> Result<String, String> {
+    let manager = app.state::<DownloadManager>();
+    let mut control = manager.start(request.clone())?;
+    let model_id = request.model_id.clone();
+
+    let _ = app.emit("local-model-download-started", &request);
+
+    match download_model(app, &request, &mut control).await {
+        Ok(DownloadOutcome::Completed(path)) => {
+            manager.finish(&model_id);
+            emit_status(
+                app,
+                "local-model-download-completed",
+                &model_id,
+                Some(path.clone()),
+                None,
+            );
+            Ok(path)
+        }
+        Ok(DownloadOutcome::Stopped(DownloadSignal::Pause)) => {
+            manager.mark_paused(&model_id);
+            emit_status(app, "local-model-download-paused", &model_id, None, None);
+            Err("Download paused".to_string())
+        }
+        Ok(DownloadOutcome::Stopped(_)) => {
+            manager.finish(&model_id);
+            if let Ok(engine_dir) = get_engine_dir(app, &request.engine_type) {
+                let _ = std::fs::remove_file(part_path(&engine_dir.join(&request.filename)));
+            }
+            emit_status(app, "local-model-download-cancelled", &model_id, None, None);
+            Err("Download cancelled".to_string())
+        }
+        Err(e) => {
+            manager.finish(&model_id);
+            emit_status(
+                app,
+                "local-model-download-failed",
+                &model_id,
+                None,
+                Some(e.clone()),
+            );
+            Err(e)
+        }
+    }
+}
+
+/// Download a model file, stopping early when `control` asks to
+///
+/// The file is written to `<filename>.part` and resumed with an HTTP Range
+/// request if a previous attempt was interrupted. Models in the catalog are
+/// checked against their size and SHA-256 before being renamed into place,
+/// so a model file only exists once it is complete.
+async fn download_model(
+    app: &AppHandle,
+    request: &DownloadRequest,
+    control: &mut watch::Receiver<DownloadSignal>,
+) -> Result<DownloadOutcome, String> {
+    let model_id = &request.model_id;
+    let engine_dir = get_engine_dir(app, &request.engine_type)?;
+    let model_path = engine_dir.join(&request.filename);
+    let part_path = part_path(&model_path);
+    let checksum = model_checksum(app, &request.filename);
 
     // Check if already downloaded
-    if model_path.exists() {
-        return Ok(model_path.to_string_lossy().to_string());
+    if let Ok(metadata) = std::fs::metadata(&model_path) {
+        match &checksum {
+            Some((size, _)) if metadata.len() != *size => {
+                // Truncated by a download from before resuming was supported
+                std::fs::remove_file(&model_path)
+                    .map_err(|e| format!("Failed to remove incomplete model: {}", e))?;
+            }
+            _ => {
+                return Ok(DownloadOutcome::Completed(
+                    model_path.to_string_lossy().to_string(),
+                ))
+            }
+        }
     }
 
+    let mut resume_from = std::fs::metadata(&part_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+
     // Download the model with progress tracking
     let client = reqwest::Client::new();
-    let response = client
-        .get(&download_url)
+    let mut http_request = client.get(&request.download_url);
+    if resume_from > 0 {
+        http_request =
+            http_request.header(reqwest::header::RANGE, format!("bytes={}-", resume_from));
+    }
+
+    let response = http_request
         .send()
         .await
         .map_err(|e| format!("Failed to start download: {}", e))?;
 
-    let total_size = response.content_length().unwrap_or(0);
-
-    // Create the file
-    let mut file = tokio::fs::File::create(&model_path)
-        .await
-        .map_err(|e| format!("Failed to create file: {}", e))?;
+    // Paused or cancelled while connecting
+    let signal = *control.borrow();
+    if signal != DownloadSignal::Run {
+        return Ok(DownloadOutcome::Stopped(signal));
+    }
 
-    let mut downloaded: u64 = 0;
+    let status = response.status();
+    let already_complete = status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE
+        && checksum
+            .as_ref()
+            .is_some_and(|(size, _)| *size == resume_from);
 
-    // Read the response in chunks
-    use bytes::Buf;
-    use futures_util::StreamExt;
+    if !already_complete {
+        if !status.is_success() {
+            // A stale partial file can make the range invalid; start over next time
+            if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+                let _ = std::fs::remove_file(&part_path);
+            }
+            return Err(format!("Download failed with status {}", status));
+        }
 
-    let mut stream = response.bytes_stream();
+        // Servers that ignore Range send the whole file again
+        if status != reqwest::StatusCode::PARTIAL_CONTENT {
+            resume_from = 0;
+        }
 
-    while let Some(chunk_result) = stream.next().await {
-        let chunk = chunk_result.map_err(|e| format!("Error downloading chunk: {}", e))?;
+        let total_size = response
+            .content_length()
+            .map(|length| length + resume_from)
+            .or(checksum.as_ref().map(|(size, _)| *size))
+            .unwrap_or(0);
 
-        file.write_all(chunk.chunk())
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resume_from > 0)
+            .truncate(resume_from == 0)
+            .open(&part_path)
             .await
-            .map_err(|e| format!("Error writing to file: {}", e))?;
+            .map_err(|e| format!("Failed to create file: {}", e))?;
 
-        downloaded += chunk.len() as u64;
+        let mut downloaded = resume_from;
+        let mut speed = SpeedTracker::new(downloaded, Instant::now());
+        let mut last_emit = Instant::now();
+        emit_progress(app, model_id, downloaded, total_size, &mut speed);
 
-        // Emit progress event every 100KB to avoid too many events
-        if downloaded % 102400 == 0 || downloaded >= total_size {
-            let percentage = if total_size > 0 {
-                (downloaded as f64 / total_size as f64) * 100.0
-            } else {
-                0.0
-            };
+        // Read the response in chunks
+        use bytes::Buf;
+        use futures_util::StreamExt;
+
+        let mut stream = response.bytes_stream();
+
+        loop {
+            let chunk_result = tokio::select! {
+                chunk = stream.next() => match chunk {
+                    Some(chunk) => chunk,
+                    None => break,
+                },
+                Ok(()) = control.changed() => {
+                    let signal = *control.borrow();
+                    if signal == DownloadSignal::Run {
+                        continue;
+                    }
 
-            let progress = DownloadProgress {
-                downloaded,
-                total: total_size,
-                percentage,
-                model_id: model_id.clone(),
+                    // Keep what arrived so a pause can resume from here
+                    file.flush()
+                        .await
+                        .map_err(|e| format!("Error flushing file: {}", e))?;
+                    return Ok(DownloadOutcome::Stopped(signal));
+                }
             };
 
-            let _ = app.emit("local-model-download-progress", progress);
+            let chunk = chunk_result.map_err(|e| format!("Error downloading chunk: {}", e))?;
+
+            file.write_all(chunk.chunk())
+                .await
+                .map_err(|e| format!("Error writing to file: {}", e))?;
+
+            downloaded += chunk.len() as u64;
+
+            if last_emit.elapsed() >= PROGRESS_INTERVAL {
+                last_emit = Instant::now();
+                emit_progress(app, model_id, downloaded, total_size, &mut speed);
+            }
         }
+
+        file.flush()
+            .await
+            .map_err(|e| format!("Error flushing file: {}", e))?;
+        file.sync_all()
+            .await
+            .map_err(|e| format!("Error syncing file: {}", e))?;
     }
 
-    // Emit final progress
-    let progress = DownloadProgress {
-        downloaded,
-        total: total_size,
-        percentage: 100.0,
-        model_id: model_id.clone(),
-    };
-    let _ = app.emit("local-model-download-progress", progress);
+    if let Some((size, sha256)) = checksum {
+        // Hashing a large model is CPU-bound
+        let path = part_path.clone();
+        let verified = tauri::async_runtime::spawn_blocking(move || {
+            verify_file(&path, size, sha256.as_deref())
+        })
+        .await
+        .map_err(|e| format!("Verification task failed: {}", e))?;
+
+        if let Err(e) = verified {
+            // Resuming a corrupt file can't fix it
+            let _ = std::fs::remove_file(&part_path);
+            return Err(e);
+        }
+    }
 
-    file.flush()
+    tokio::fs::rename(&part_path, &model_path)
         .await
-        .map_err(|e| format!("Error flushing file: {}", e))?;
+        .map_err(|e| format!("Failed to move downloaded model into place: {}", e))?;
+
+    // Emit final progress
+    let size = std::fs::metadata(&model_path)
+        .map(|metadata| metadata.len())
+        .unwrap_or(0);
+    emit_progress(
+        app,
+        model_id,
+        size,
+        size,
+        &mut SpeedTracker::new(size, Instant::now()),
+    );
 
-    Ok(model_path.to_string_lossy().to_string())
+    Ok(DownloadOutcome::Completed(
+        model_path.to_string_lossy().to_string(),
+    ))
 }
 
 /// Delete a local model file
@@ -134,12 +456,55 @@ pub async fn download_local_model(
 /// # Arguments
 /// * `model_path` - The full path to the model file to delete
 #[command]
-pub async fn delete_local_model(model_path: String) -> Result<(), String> {
+pub async fn delete_local_model(app: AppHandle, model_path: String) -> Result<(), String> {
     let path = PathBuf::from(&model_path);
 
     if path.exists() {
         std::fs::remove_file(&path).map_err(|e| format!("Failed to delete model: {}", e))?;
     }
 
-    Ok(())
+    // Also discard any interrupted download of the same model
+    let part = part_path(&path);
+    if part.exists() {
+        std::fs::remove_file(&part)
+            .map_err(|e| format!("Failed to delete partial download: {}", e))?;
+    }
+
+    forget_imported_model(&app, &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_path() {
+        assert_eq!(
+            part_path(Path::new("/models/whisper/ggml-tiny.bin")),
+            PathBuf::from("/models/whisper/ggml-tiny.bin.part")
+        );
+    }
+
+    #[test]
+    fn test_verify_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.bin");
+        std::fs::write(&path, b"abc").unwrap();
+
+        // SHA-256 of "abc"
+        let sha256 = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+        assert!(verify_file(&path, 3, Some(sha256)).is_ok());
+        assert!(verify_file(&path, 3, Some(&sha256.to_uppercase())).is_ok());
+        assert!(verify_file(&path, 4, Some(sha256))
+            .unwrap_err()
+            .contains("expected 4"));
+        assert!(verify_file(&path, 3, Some(&"0".repeat(64)))
+            .unwrap_err()
+            .contains("does not match"));
+
+        // Catalog entries without a hash are only checked for size
+        assert!(verify_file(&path, 3, None).is_ok());
+        assert!(verify_file(&path, 4, None).is_err());
+    }
 }
@@ -1,44 +1,126 @@
 use std::collections::HashMap;
 
 use super::engines::{
-    whisper::WhisperEngine, LocalModelEngine, ModelConfig, ModelInfo, ModelStatus,
+    whisper::WhisperEngine, EngineKind, GenerationOptions, LocalModelEngine, ModelConfig,
+    ModelInfo, ModelStatus, SpeechToTextEngine, TextGenerationEngine,
 };
+use crate::features::transcription::providers::TranscriptionResponse;
 
 /// Generic manager for all local model engines
 ///
-/// This manager can handle any type of local model (Whisper, Llama, Mistral, etc.)
-/// as long as they implement the LocalModelEngine trait.
+/// Engines are registered by kind: speech-to-text engines (Whisper) and
+/// text-generation engines (Llama, Mistral, etc.). One model of each kind
+/// can be loaded at a time, so loading a post-processing model leaves the
+/// transcription model in memory and vice versa.
 ///
-/// The manager maintains a registry of available engines and routes commands
-/// to the appropriate engine based on the model's engine type.
+/// Commands are routed to the appropriate engine based on the model's
+/// engine type.
 pub struct LocalModelManager {
-    /// Available engine instances
-    engines: HashMap<String, Box<dyn LocalModelEngine>>,
+    /// Available speech-to-text engine instances
+    speech_to_text_engines: HashMap<String, Box<dyn SpeechToTextEngine>>,
 
-    /// Currently active engine type (if any)
-    active_engine: Option<String>,
+    /// Available text-generation engine instances
+    text_generation_engines: HashMap<String, Box<dyn TextGenerationEngine>>,
+
+    /// Engine type of the loaded speech-to-text model (if any)
+    active_speech_to_text: Option<String>,
+
+    /// Engine type of the loaded text-generation model (if any)
+    active_text_generation: Option<String>,
+}
+
+/// Load a model, unloading the active engine of the same kind if it differs
+fn load_engine<E: LocalModelEngine + ?Sized>(
+    engines: &mut HashMap<String, Box<E>>,
+    active: &mut Option<String>,
+    engine_type: &str,
+    config: ModelConfig,
+) -> Result<(), String> {
+    if let Some(previous) = active
+        .as_deref()
+        .filter(|previous| *previous != engine_type)
+    {
+        if let Some(engine) = engines.get_mut(previous) {
+            engine.unload_model();
+        }
+    }
+
+    let engine = engines
+        .get_mut(engine_type)
+        .ok_or_else(|| format!("Unknown engine type: {}", engine_type))?;
+
+    engine.load_model(config)?;
+
+    *active = Some(engine_type.to_string());
+    Ok(())
+}
+
+fn unload_engine<E: LocalModelEngine + ?Sized>(
+    engines: &mut HashMap<String, Box<E>>,
+    active: &mut Option<String>,
+) {
+    if let Some(active) = active.take() {
+        if let Some(engine) = engines.get_mut(&active) {
+            engine.unload_model();
+        }
+    }
+}
+
+fn active_engine<'a, E: ?Sized>(
+    engines: &'a HashMap<String, Box<E>>,
+    active: &Option<String>,
+) -> Option<&'a E> {
+    active
+        .as_ref()
+        .and_then(|active| engines.get(active))
+        .map(|engine| engine.as_ref())
 }
 
 impl LocalModelManager {
     /// Creates a new LocalModelManager with all available engines registered
     pub fn new() -> Self {
-        let mut engines: HashMap<String, Box<dyn LocalModelEngine>> = HashMap::new();
+        let mut speech_to_text_engines: HashMap<String, Box<dyn SpeechToTextEngine>> =
+            HashMap::new();
 
         // Register Whisper engine
-        engines.insert("whisper".to_string(), Box::new(WhisperEngine::new()));
+        speech_to_text_engines.insert("whisper".to_string(), Box::new(WhisperEngine::new()));
+
+        // Text-generation engines can be registered here:
+        // text_generation_engines.insert("llama".to_string(), Box::new(LlamaEngine::new()));
+        let text_generation_engines: HashMap<String, Box<dyn TextGenerationEngine>> =
+            HashMap::new();
 
-        // Future engines can be registered here:
-        // engines.insert("llama".to_string(), Box::new(LlamaEngine::new()));
-        // engines.insert("mistral".to_string(), Box::new(MistralEngine::new()));
+        Self::with_engines(speech_to_text_engines, text_generation_engines)
+    }
 
+    fn with_engines(
+        speech_to_text_engines: HashMap<String, Box<dyn SpeechToTextEngine>>,
+        text_generation_engines: HashMap<String, Box<dyn TextGenerationEngine>>,
+    ) -> Self {
         Self {
-            engines,
-            active_engine: None,
+            speech_to_text_engines,
+            text_generation_engines,
+            active_speech_to_text: None,
+            active_text_generation: None,
+        }
+    }
+
+    /// Kind of a registered engine, or `None` if the engine type is unknown
+    pub fn engine_kind(&self, engine_type: &str) -> Option<EngineKind> {
+        if self.speech_to_text_engines.contains_key(engine_type) {
+            Some(EngineKind::SpeechToText)
+        } else if self.text_generation_engines.contains_key(engine_type) {
+            Some(EngineKind::TextGeneration)
+        } else {
+            None
         }
     }
 
     /// Load a model using the specified engine
     ///
+    /// Replaces the loaded model of the same kind; a loaded model of the
+    /// other kind stays in memory.
+    ///
     /// # Arguments
     /// * `engine_type` - The engine to use (e.g., "whisper", "llama")
     /// * `config` - Configuration for loading the model
@@ -47,102 +129,155 @@ impl LocalModelManager {
     /// * `Ok(())` if the model was loaded successfully
     /// * `Err(String)` if loading failed
     pub fn load_model(&mut self, engine_type: &str, config: ModelConfig) -> Result<(), String> {
-        // Check that the engine exists first
-        if !self.engines.contains_key(engine_type) {
-            return Err(format!("Unknown engine type: {}", engine_type));
+        match self.engine_kind(engine_type) {
+            Some(EngineKind::SpeechToText) => load_engine(
+                &mut self.speech_to_text_engines,
+                &mut self.active_speech_to_text,
+                engine_type,
+                config,
+            ),
+            Some(EngineKind::TextGeneration) => load_engine(
+                &mut self.text_generation_engines,
+                &mut self.active_text_generation,
+                engine_type,
+                config,
+            ),
+            None => Err(format!("Unknown engine type: {}", engine_type)),
         }
+    }
 
-        // Unload any currently active model
-        if let Some(active) = &self.active_engine {
-            if active != engine_type {
-                if let Some(active_engine) = self.engines.get_mut(active) {
-                    active_engine.unload_model();
-                }
-            }
+    /// Unload the loaded model of one kind
+    pub fn unload_model(&mut self, kind: EngineKind) {
+        match kind {
+            EngineKind::SpeechToText => unload_engine(
+                &mut self.speech_to_text_engines,
+                &mut self.active_speech_to_text,
+            ),
+            EngineKind::TextGeneration => unload_engine(
+                &mut self.text_generation_engines,
+                &mut self.active_text_generation,
+            ),
         }
-
-        // Get the engine and load the new model
-        let engine = self
-            .engines
-            .get_mut(engine_type)
-            .ok_or_else(|| format!("Unknown engine type: {}", engine_type))?;
-
-        engine.load_model(config)?;
-
-        self.active_engine = Some(engine_type.to_string());
-        Ok(())
     }
 
-    /// Unload the currently active model
-    pub fn unload_model(&mut self) {
-        if let Some(active) = &self.active_engine {
-            if let Some(engine) = self.engines.get_mut(active) {
-                engine.unload_model();
-            }
-            self.active_engine = None;
-        }
+    /// Unload every loaded model
+    pub fn unload_all(&mut self) {
+        self.unload_model(EngineKind::SpeechToText);
+        self.unload_model(EngineKind::TextGeneration);
     }
 
-    /// Transcribe audio using the currently active engine
+    /// Transcribe audio using the active speech-to-text engine
     ///
     /// # Arguments
     /// * `audio_data` - Raw audio data
     /// * `language` - Optional language code
+    /// * `options` - Engine-specific decoding parameters for this call; `None` uses the model's own
     ///
     /// # Returns
-    /// * `Ok(String)` containing the transcription
+    /// * `Ok(TranscriptionResponse)` containing the transcription
     /// * `Err(String)` if transcription failed or no model is loaded
     pub fn transcribe(
         &mut self,
         audio_data: Vec<u8>,
         language: Option<String>,
+        options: Option<serde_json::Value>,
+    ) -> Result<TranscriptionResponse, String> {
+        let active = self
+            .active_speech_to_text
+            .as_ref()
+            .ok_or("No speech-to-text model is currently loaded")?;
+
+        let engine = self
+            .speech_to_text_engines
+            .get_mut(active)
+            .ok_or("Active engine not found")?;
+
+        engine.transcribe(audio_data, language, options)
+    }
+
+    /// Generate a completion using the active text-generation engine
+    ///
+    /// # Arguments
+    /// * `prompt` - Text for the model to continue
+    /// * `options` - Maximum tokens and stop sequences
+    ///
+    /// # Returns
+    /// * `Ok(String)` containing the generated text
+    /// * `Err(String)` if generation failed or no model is loaded
+    pub fn generate(
+        &mut self,
+        prompt: &str,
+        options: &GenerationOptions,
     ) -> Result<String, String> {
         let active = self
-            .active_engine
+            .active_text_generation
             .as_ref()
-            .ok_or("No model is currently loaded")?;
+            .ok_or("No text-generation model is currently loaded")?;
 
         let engine = self
-            .engines
+            .text_generation_engines
             .get_mut(active)
             .ok_or("Active engine not found")?;
 
-        engine.transcribe(audio_data, language)
+        engine.generate(prompt, options)
+    }
+
+    /// Update the decoding parameters of the loaded speech-to-text model
+    /// without reloading it
+    pub fn set_decode_options(&mut self, options: serde_json::Value) -> Result<(), String> {
+        if let Some(active) = &self.active_speech_to_text {
+            if let Some(engine) = self.speech_to_text_engines.get_mut(active) {
+                engine.set_decode_options(options)?;
+            }
+        }
+        Ok(())
     }
 
-    /// Get the current status of the active engine
-    pub fn get_status(&self) -> ModelStatus {
-        if let Some(active) = &self.active_engine {
-            if let Some(engine) = self.engines.get(active) {
-                return engine.get_status();
+    /// Get the current status of the active engine of one kind
+    pub fn get_status(&self, kind: EngineKind) -> ModelStatus {
+        match kind {
+            EngineKind::SpeechToText => {
+                active_engine(&self.speech_to_text_engines, &self.active_speech_to_text)
+                    .map(|engine| engine.get_status())
+            }
+            EngineKind::TextGeneration => {
+                active_engine(&self.text_generation_engines, &self.active_text_generation)
+                    .map(|engine| engine.get_status())
             }
         }
-        ModelStatus::Stopped
+        .unwrap_or(ModelStatus::Stopped)
     }
 
-    /// Get information about the currently loaded model
-    pub fn get_loaded_model_info(&self) -> Option<ModelInfo> {
-        if let Some(active) = &self.active_engine {
-            if let Some(engine) = self.engines.get(active) {
-                return engine.get_loaded_model_info();
+    /// Get information about the loaded model of one kind
+    pub fn get_loaded_model_info(&self, kind: EngineKind) -> Option<ModelInfo> {
+        match kind {
+            EngineKind::SpeechToText => {
+                active_engine(&self.speech_to_text_engines, &self.active_speech_to_text)
+                    .and_then(|engine| engine.get_loaded_model_info())
+            }
+            EngineKind::TextGeneration => {
+                active_engine(&self.text_generation_engines, &self.active_text_generation)
+                    .and_then(|engine| engine.get_loaded_model_info())
             }
         }
-        None
     }
 
-    /// Get the name of the currently loaded model (if any)
-    pub fn get_loaded_model_name(&self) -> Option<String> {
-        self.get_loaded_model_info().map(|info| info.name)
+    /// Get the name of the loaded model of one kind (if any)
+    pub fn get_loaded_model_name(&self, kind: EngineKind) -> Option<String> {
+        self.get_loaded_model_info(kind).map(|info| info.name)
     }
 
     /// Check if a specific engine type is available
     pub fn has_engine(&self, engine_type: &str) -> bool {
-        self.engines.contains_key(engine_type)
+        self.engine_kind(engine_type).is_some()
     }
 
-    /// Get the currently active engine type
-    pub fn get_active_engine_type(&self) -> Option<&String> {
-        self.active_engine.as_ref()
+    /// Get the active engine type of one kind
+    pub fn get_active_engine_type(&self, kind: EngineKind) -> Option<&String> {
+        match kind {
+            EngineKind::SpeechToText => self.active_speech_to_text.as_ref(),
+            EngineKind::TextGeneration => self.active_text_generation.as_ref(),
+        }
     }
 }
 
@@ -151,3 +286,183 @@ impl Default for LocalModelManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Engine that only records what is loaded
+    struct FakeEngine {
+        engine_type: &'static str,
+        loaded: Option<ModelConfig>,
+    }
+
+    impl FakeEngine {
+        fn boxed(engine_type: &'static str) -> Box<Self> {
+            Box::new(Self {
+                engine_type,
+                loaded: None,
+            })
+        }
+    }
+
+    impl LocalModelEngine for FakeEngine {
+        fn load_model(&mut self, config: ModelConfig) -> Result<(), String> {
+            self.loaded = Some(config);
+            Ok(())
+        }
+
+        fn unload_model(&mut self) {
+            self.loaded = None;
+        }
+
+        fn get_status(&self) -> ModelStatus {
+            if self.loaded.is_some() {
+                ModelStatus::Ready
+            } else {
+                ModelStatus::Stopped
+            }
+        }
+
+        fn get_loaded_model_info(&self) -> Option<ModelInfo> {
+            self.loaded.as_ref().map(|config| ModelInfo {
+                name: config.model_name.clone(),
+                path: config.model_path.clone(),
+                engine_type: self.engine_type.to_string(),
+                decode_options: config.decode_options.clone(),
+            })
+        }
+
+        fn engine_type(&self) -> &'static str {
+            self.engine_type
+        }
+    }
+
+    impl SpeechToTextEngine for FakeEngine {
+        fn transcribe(
+            &mut self,
+            _audio_data: Vec<u8>,
+            _language: Option<String>,
+            _options: Option<serde_json::Value>,
+        ) -> Result<TranscriptionResponse, String> {
+            Err("not implemented".to_string())
+        }
+
+        fn set_decode_options(&mut self, _options: serde_json::Value) -> Result<(), String> {
+            Ok(())
+        }
+    }
+
+    impl TextGenerationEngine for FakeEngine {
+        fn generate(
+            &mut self,
+            prompt: &str,
+            options: &GenerationOptions,
+        ) -> Result<String, String> {
+            Ok(format!("{} [{} tokens]", prompt, options.max_tokens))
+        }
+    }
+
+    fn config(name: &str) -> ModelConfig {
+        ModelConfig {
+            model_path: format!("/models/{}.bin", name),
+            model_name: name.to_string(),
+            language: None,
+            decode_options: serde_json::Value::Null,
+        }
+    }
+
+    fn manager() -> LocalModelManager {
+        let mut speech_to_text: HashMap<String, Box<dyn SpeechToTextEngine>> = HashMap::new();
+        speech_to_text.insert("whisper".to_string(), FakeEngine::boxed("whisper"));
+        speech_to_text.insert("parakeet".to_string(), FakeEngine::boxed("parakeet"));
+
+        let mut text_generation: HashMap<String, Box<dyn TextGenerationEngine>> = HashMap::new();
+        text_generation.insert("llama".to_string(), FakeEngine::boxed("llama"));
+
+        LocalModelManager::with_engines(speech_to_text, text_generation)
+    }
+
+    #[test]
+    fn test_one_model_of_each_kind() {
+        let mut manager = manager();
+        assert_eq!(
+            manager.engine_kind("llama"),
+            Some(EngineKind::TextGeneration)
+        );
+        assert!(manager.load_model("mistral", config("mistral-7b")).is_err());
+
+        manager.load_model("whisper", config("base")).unwrap();
+        manager.load_model("llama", config("llama-3-8b")).unwrap();
+
+        // Loading a text-generation model keeps the speech-to-text model
+        assert_eq!(
+            manager
+                .get_loaded_model_name(EngineKind::SpeechToText)
+                .as_deref(),
+            Some("base")
+        );
+        assert_eq!(
+            manager
+                .get_loaded_model_name(EngineKind::TextGeneration)
+                .as_deref(),
+            Some("llama-3-8b")
+        );
+
+        let options = GenerationOptions {
+            max_tokens: 16,
+            stop_sequences: Vec::new(),
+        };
+        assert_eq!(
+            manager.generate("Fix this", &options).unwrap(),
+            "Fix this [16 tokens]"
+        );
+
+        // Another speech-to-text engine replaces the first one
+        manager.load_model("parakeet", config("tdt-0.6b")).unwrap();
+        assert_eq!(
+            manager
+                .get_active_engine_type(EngineKind::SpeechToText)
+                .map(String::as_str),
+            Some("parakeet")
+        );
+        assert!(manager.speech_to_text_engines["whisper"]
+            .get_loaded_model_info()
+            .is_none());
+        assert_eq!(
+            manager.get_status(EngineKind::TextGeneration),
+            ModelStatus::Ready
+        );
+    }
+
+    #[test]
+    fn test_unload_by_kind() {
+        let mut manager = manager();
+        manager.load_model("whisper", config("base")).unwrap();
+        manager.load_model("llama", config("llama-3-8b")).unwrap();
+
+        manager.unload_model(EngineKind::TextGeneration);
+        assert_eq!(
+            manager.get_status(EngineKind::TextGeneration),
+            ModelStatus::Stopped
+        );
+        assert!(manager
+            .generate(
+                "Fix this",
+                &GenerationOptions {
+                    max_tokens: 16,
+                    stop_sequences: Vec::new(),
+                }
+            )
+            .is_err());
+        assert_eq!(
+            manager.get_status(EngineKind::SpeechToText),
+            ModelStatus::Ready
+        );
+
+        manager.unload_all();
+        assert!(manager
+            .get_loaded_model_info(EngineKind::SpeechToText)
+            .is_none());
+    }
+}
@@ -1,12 +1,26 @@
+pub mod custom_endpoints;
+pub mod download_manager;
 pub mod engines;
+pub mod imported_models;
 pub mod local_model_commands;
 pub mod local_model_downloader;
 pub mod local_model_manager;
+pub mod model_catalog;
 pub mod models_registry;
 pub mod startup;
 
-pub use local_model_commands::{get_local_model_status, start_local_model, stop_local_model};
-pub use local_model_downloader::{delete_local_model, download_local_model};
+pub use custom_endpoints::{
+    add_custom_endpoint_model, list_endpoint_models, remove_custom_endpoint_model,
+};
+pub use download_manager::DownloadManager;
+pub use imported_models::import_local_model;
+pub use local_model_commands::{
+    get_local_model_status, set_whisper_decode_options, start_local_model, stop_local_model,
+};
+pub use local_model_downloader::{
+    cancel_model_download, delete_local_model, download_local_model, pause_model_download,
+    resume_model_download,
+};
 pub use local_model_manager::LocalModelManager;
 pub use models_registry::get_all_models;
 pub use startup::auto_start_selected_models;
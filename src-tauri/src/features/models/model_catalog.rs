@@ -0,0 +1,302 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+use tauri::{AppHandle, Manager};
+use ts_rs::TS;
+
+use super::models_registry::{ModelDefinition, ModelProvider, ModelPurpose, ModelType};
+
+/// Catalog shipped with the app
+const BUNDLED_CATALOG: &str = include_str!("model_catalog.json");
+
+/// File in the app data directory whose entries replace or extend the
+/// bundled catalog, so new models don't need a release
+const CATALOG_OVERRIDE_FILE: &str = "model_catalog.json";
+
+/// What a model can do beyond plain transcription
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/features/models/types/generated/")]
+#[serde(rename_all = "camelCase", default)]
+pub struct ModelFeatures {
+    /// Returns segment or word timings
+    pub timestamps: bool,
+    /// Can translate speech into English
+    pub translate: bool,
+    /// Can label who is speaking
+    pub diarization: bool,
+}
+
+/// Where and how to download a local model file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogDownload {
+    pub url: String,
+    pub filename: String,
+    pub size_bytes: u64,
+    /// Checked after download when present
+    pub sha256: Option<String>,
+}
+
+/// One model in the catalog manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntry {
+    pub id: String,
+    pub name: String,
+    pub provider: ModelProvider,
+    #[serde(rename = "type")]
+    pub model_type: ModelType,
+    pub purpose: ModelPurpose,
+    /// Engine for local models (e.g., "whisper")
+    pub engine: Option<String>,
+    pub description: Option<String>,
+    /// Display size (e.g., "75 MB")
+    pub size: Option<String>,
+    /// Local models only
+    pub download: Option<CatalogDownload>,
+    /// ISO 639-1 codes; `None` means every language the provider supports
+    pub languages: Option<Vec<String>>,
+    pub recommended_ram_mb: Option<u32>,
+    #[serde(default)]
+    pub features: ModelFeatures,
+    /// Selected on first launch
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl CatalogEntry {
+    /// Definition for the models list, with the download state of local
+    /// models read from `models_dir`
+    pub fn to_model_definition(&self, models_dir: &Path) -> ModelDefinition {
+        let is_local = self.model_type == ModelType::Local;
+
+        // A file of the wrong size is left over from an interrupted download
+        let downloaded_path = match (&self.engine, &self.download) {
+            (Some(engine), Some(download)) => {
+                Some(models_dir.join(engine).join(&download.filename)).filter(|path| {
+                    std::fs::metadata(path)
+                        .map(|metadata| metadata.len() == download.size_bytes)
+                        .unwrap_or(false)
+                })
+            }
+            _ => None,
+        };
+
+        ModelDefinition {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            provider: self.provider,
+            model_type: self.model_type.clone(),
+            purpose: self.purpose.clone(),
+            engine: self.engine.clone(),
+            size: self.size.clone(),
+            requires_api_key: !is_local,
+            is_selected: self.is_default,
+            is_downloaded: is_local.then_some(downloaded_path.is_some()),
+            path: downloaded_path.map(|path| path.to_string_lossy().to_string()),
+            description: self.description.clone(),
+            download_url: self.download.as_ref().map(|download| download.url.clone()),
+            filename: self
+                .download
+                .as_ref()
+                .map(|download| download.filename.clone()),
+            base_url: None,
+            languages: self.languages.clone(),
+            recommended_ram_mb: self.recommended_ram_mb,
+            features: Some(self.features.clone()),
+        }
+    }
+}
+
+/// Parse a manifest, skipping entries that don't match the schema
+///
+/// Returns an error only if the file itself isn't a manifest.
+fn parse_catalog(json: &str) -> Result<Vec<CatalogEntry>, String> {
+    let manifest: Value =
+        serde_json::from_str(json).map_err(|e| format!("Invalid model catalog: {}", e))?;
+
+    let entries = manifest
+        .get("models")
+        .and_then(|models| models.as_array())
+        .ok_or("Model catalog has no models list")?;
+
+    Ok(entries
+        .iter()
+        .filter_map(|entry| match serde_json::from_value(entry.clone()) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                log::warn!("Skipping invalid model catalog entry: {}", e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// Replace bundled entries with same-id overrides and append new ones
+fn merge_catalogs(
+    mut bundled: Vec<CatalogEntry>,
+    overrides: Vec<CatalogEntry>,
+) -> Vec<CatalogEntry> {
+    for entry in overrides {
+        match bundled.iter_mut().find(|existing| existing.id == entry.id) {
+            Some(existing) => *existing = entry,
+            None => bundled.push(entry),
+        }
+    }
+
+    bundled
+}
+
+fn load_override(path: &Path) -> Vec<CatalogEntry> {
+    let Ok(json) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    parse_catalog(&json).unwrap_or_else(|e| {
+        log::warn!("Ignoring model catalog override {}: {}", path.display(), e);
+        Vec::new()
+    })
+}
+
+/// The bundled catalog merged with the user's override file, if any
+pub fn load_catalog(app: &AppHandle) -> Vec<CatalogEntry> {
+    let bundled = parse_catalog(BUNDLED_CATALOG).unwrap_or_else(|e| {
+        log::error!("Bundled model catalog is invalid: {}", e);
+        Vec::new()
+    });
+
+    let overrides = app
+        .path()
+        .app_data_dir()
+        .map(|dir| load_override(&dir.join(CATALOG_OVERRIDE_FILE)))
+        .unwrap_or_default();
+
+    merge_catalogs(bundled, overrides)
+}
+
+/// Expected size in bytes and SHA-256 of a downloadable model file
+pub fn model_checksum(app: &AppHandle, filename: &str) -> Option<(u64, Option<String>)> {
+    load_catalog(app)
+        .into_iter()
+        .filter_map(|entry| entry.download)
+        .find(|download| download.filename == filename)
+        .map(|download| (download.size_bytes, download.sha256))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_catalog_is_valid() {
+        let manifest: Value = serde_json::from_str(BUNDLED_CATALOG).unwrap();
+        let entries = parse_catalog(BUNDLED_CATALOG).unwrap();
+
+        // No entry was skipped
+        assert_eq!(entries.len(), manifest["models"].as_array().unwrap().len());
+
+        let mut ids: Vec<&str> = entries.iter().map(|entry| entry.id.as_str()).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), entries.len(), "duplicate model ids");
+
+        for entry in &entries {
+            match entry.model_type {
+                ModelType::Local => {
+                    let download = entry.download.as_ref().expect("local model download");
+                    assert!(entry.engine.is_some(), "{} has no engine", entry.id);
+                    assert_eq!(download.sha256.as_ref().map(|sha| sha.len()), Some(64));
+                }
+                ModelType::Cloud => assert!(entry.download.is_none()),
+            }
+        }
+
+        assert_eq!(entries.iter().filter(|entry| entry.is_default).count(), 1);
+    }
+
+    #[test]
+    fn test_override_replaces_and_extends() {
+        let bundled = parse_catalog(BUNDLED_CATALOG).unwrap();
+        let overrides = parse_catalog(
+            r#"{
+                "models": [
+                    {
+                        "id": "gpt-4o",
+                        "name": "GPT-4o (latest)",
+                        "provider": "openai",
+                        "type": "cloud",
+                        "purpose": "post-processing"
+                    },
+                    {
+                        "id": "whisper-large-v3-turbo-q5_0",
+                        "name": "Whisper Large V3 Turbo (Q5)",
+                        "provider": "local-whisper",
+                        "type": "local",
+                        "purpose": "speech-to-text",
+                        "engine": "whisper",
+                        "download": {
+                            "url": "https://example.com/ggml-large-v3-turbo-q5_0.bin",
+                            "filename": "ggml-large-v3-turbo-q5_0.bin",
+                            "sizeBytes": 574041195
+                        },
+                        "features": { "timestamps": true, "translate": false }
+                    },
+                    { "id": "broken", "provider": "nobody" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(overrides.len(), 2);
+
+        let merged = merge_catalogs(bundled.clone(), overrides);
+        assert_eq!(merged.len(), bundled.len() + 1);
+
+        let gpt = merged.iter().find(|entry| entry.id == "gpt-4o").unwrap();
+        assert_eq!(gpt.name, "GPT-4o (latest)");
+
+        let turbo = merged.last().unwrap();
+        assert_eq!(turbo.download.as_ref().unwrap().sha256, None);
+        assert!(turbo.features.timestamps);
+        assert!(!turbo.features.diarization);
+    }
+
+    #[test]
+    fn test_download_state() {
+        let models_dir = tempfile::tempdir().unwrap();
+        let entries = parse_catalog(BUNDLED_CATALOG).unwrap();
+        let tiny = entries
+            .iter()
+            .find(|entry| entry.id == "whisper-tiny")
+            .unwrap();
+        let cloud = entries
+            .iter()
+            .find(|entry| entry.id == "whisper-1")
+            .unwrap();
+
+        let definition = tiny.to_model_definition(models_dir.path());
+        assert_eq!(definition.is_downloaded, Some(false));
+        assert_eq!(definition.path, None);
+        assert!(!definition.requires_api_key);
+        assert!(definition.is_selected);
+
+        // Only a file of the expected size counts as downloaded
+        let whisper_dir = models_dir.path().join("whisper");
+        std::fs::create_dir_all(&whisper_dir).unwrap();
+        std::fs::write(whisper_dir.join("ggml-tiny.bin"), b"partial").unwrap();
+        assert_eq!(
+            tiny.to_model_definition(models_dir.path()).is_downloaded,
+            Some(false)
+        );
+
+        let definition = cloud.to_model_definition(models_dir.path());
+        assert_eq!(definition.is_downloaded, None);
+        assert!(definition.requires_api_key);
+        assert!(definition.features.unwrap().translate);
+    }
+
+    #[test]
+    fn test_rejects_non_manifest() {
+        assert!(parse_catalog("[]").is_err());
+        assert!(parse_catalog("not json").is_err());
+    }
+}
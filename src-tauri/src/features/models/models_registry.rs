@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
-use tauri::{command, AppHandle, Manager};
+use tauri::{command, AppHandle};
 use ts_rs::TS;
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+use super::custom_endpoints::get_custom_endpoints;
+use super::imported_models::{get_imported_models, get_whisper_dir};
+use super::local_model_downloader::get_models_base_dir;
+use super::model_catalog::{load_catalog, ModelFeatures};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../../src/features/models/types/generated/")]
 #[serde(rename_all = "lowercase")]
 pub enum ModelType {
@@ -10,7 +15,7 @@ pub enum ModelType {
     Local,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../../src/features/models/types/generated/")]
 #[serde(rename_all = "kebab-case")]
 pub enum ModelPurpose {
@@ -18,7 +23,7 @@ pub enum ModelPurpose {
     PostProcessing,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
 #[ts(export, export_to = "../../src/features/models/types/generated/")]
 pub enum ModelProvider {
     #[serde(rename = "openai")]
@@ -29,6 +34,8 @@ pub enum ModelProvider {
     Google,
     #[serde(rename = "assemblyai")]
     AssemblyAI,
+    #[serde(rename = "deepgram")]
+    Deepgram,
     #[serde(rename = "elevenlabs")]
     ElevenLabs,
     #[serde(rename = "local-whisper")]
@@ -37,6 +44,41 @@ pub enum ModelProvider {
     Ollama,
     #[serde(rename = "lmstudio")]
     LMStudio,
+    #[serde(rename = "openai-compatible")]
+    OpenAICompatible,
+}
+
+impl ModelProvider {
+    /// Identifier stored in models.json and settings (e.g., "openai", "local-whisper")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModelProvider::OpenAI => "openai",
+            ModelProvider::Anthropic => "anthropic",
+            ModelProvider::Google => "google",
+            ModelProvider::AssemblyAI => "assemblyai",
+            ModelProvider::Deepgram => "deepgram",
+            ModelProvider::ElevenLabs => "elevenlabs",
+            ModelProvider::LocalWhisper => "local-whisper",
+            ModelProvider::Ollama => "ollama",
+            ModelProvider::LMStudio => "lmstudio",
+            ModelProvider::OpenAICompatible => "openai-compatible",
+        }
+    }
+}
+
+impl std::str::FromStr for ModelProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_value(serde_json::Value::String(s.to_string()))
+            .map_err(|_| format!("Unsupported provider: {}", s))
+    }
+}
+
+impl std::fmt::Display for ModelProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -62,178 +104,43 @@ pub struct ModelDefinition {
     pub download_url: Option<String>,
     /// Filename to save the model as (for local models)
     pub filename: Option<String>,
+    /// Base URL for user-defined OpenAI-compatible endpoints
+    pub base_url: Option<String>,
+    /// ISO 639-1 codes the model understands; None means any language
+    pub languages: Option<Vec<String>>,
+    /// Memory needed to run a local model comfortably
+    pub recommended_ram_mb: Option<u32>,
+    pub features: Option<ModelFeatures>,
 }
 
-// Speech-to-Text cloud models
-const STT_CLOUD_MODELS: &[(&str, &str, &str, &str)] = &[
-    (
-        "whisper-1",
-        "Whisper",
-        "openai",
-        "OpenAI Whisper - Fast and accurate speech recognition",
-    ),
-    (
-        "google-cloud-speech",
-        "Cloud Speech-to-Text",
-        "google",
-        "Google Cloud Speech-to-Text API - High accuracy transcription",
-    ),
-    (
-        "scribe_v1",
-        "Scribe V1",
-        "elevenlabs",
-        "ElevenLabs Scribe - High-quality speech-to-text with multilingual support",
-    ),
-];
-
-// Post-Processing cloud models
-const POST_PROCESSING_CLOUD_MODELS: &[(&str, &str, &str, &str)] = &[
-    (
-        "claude-3-5-sonnet-20241022",
-        "Claude 3.5 Sonnet",
-        "anthropic",
-        "Anthropic's most intelligent model - excellent for text enhancement and formatting",
-    ),
-    (
-        "claude-3-5-haiku-20241022",
-        "Claude 3.5 Haiku",
-        "anthropic",
-        "Fastest Claude model - great for quick post-processing",
-    ),
-    (
-        "gpt-4o",
-        "GPT-4o",
-        "openai",
-        "OpenAI's most advanced model - powerful text processing and enhancement",
-    ),
-    (
-        "gpt-4o-mini",
-        "GPT-4o Mini",
-        "openai",
-        "Affordable and fast OpenAI model - good for basic post-processing",
-    ),
-];
-
-// Local Whisper models
-pub const WHISPER_MODELS: &[(&str, &str, &str)] = &[
-    (
-        "tiny",
-        "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin",
-        "75 MB",
-    ),
-    (
-        "base",
-        "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base.bin",
-        "142 MB",
-    ),
-    (
-        "small",
-        "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-small.bin",
-        "466 MB",
-    ),
-    (
-        "medium",
-        "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-medium.bin",
-        "1.5 GB",
-    ),
-];
-
+/// Every model the app knows about, with local download state
+///
+/// Built from the model catalog (bundled manifest plus the user's override
+/// file), followed by imported model files and custom endpoints.
 #[command]
 pub async fn get_all_models(app: AppHandle) -> Result<Vec<ModelDefinition>, String> {
-    let mut models = Vec::new();
-
-    // Add speech-to-text cloud models
-    for (id, name, provider, description) in STT_CLOUD_MODELS {
-        models.push(ModelDefinition {
-            id: id.to_string(),
-            name: name.to_string(),
-            provider: match *provider {
-                "openai" => ModelProvider::OpenAI,
-                "google" => ModelProvider::Google,
-                "assemblyai" => ModelProvider::AssemblyAI,
-                "elevenlabs" => ModelProvider::ElevenLabs,
-                _ => continue,
-            },
-            model_type: ModelType::Cloud,
-            purpose: ModelPurpose::SpeechToText,
-            engine: None, // Cloud models don't use local engines
-            size: None,
-            requires_api_key: true,
-            is_selected: false,
-            is_downloaded: None,
-            path: None,
-            description: Some(description.to_string()),
-            download_url: None,
-            filename: None,
-        });
+    let models_dir = get_models_base_dir(&app)?;
+    let mut models: Vec<ModelDefinition> = load_catalog(&app)
+        .iter()
+        .map(|entry| entry.to_model_definition(&models_dir))
+        .collect();
+
+    // Add imported model files; a deleted file removes the model
+    let whisper_dir = get_whisper_dir(&app)?;
+    match get_imported_models(&app) {
+        Ok(imported) => models.extend(
+            imported
+                .iter()
+                .filter(|model| whisper_dir.join(&model.filename).exists())
+                .map(|model| model.to_model_definition(&whisper_dir)),
+        ),
+        Err(e) => log::warn!("Failed to load imported models: {}", e),
     }
 
-    // Add post-processing cloud models
-    for (id, name, provider, description) in POST_PROCESSING_CLOUD_MODELS {
-        models.push(ModelDefinition {
-            id: id.to_string(),
-            name: name.to_string(),
-            provider: match *provider {
-                "anthropic" => ModelProvider::Anthropic,
-                "openai" => ModelProvider::OpenAI,
-                _ => continue,
-            },
-            model_type: ModelType::Cloud,
-            purpose: ModelPurpose::PostProcessing,
-            engine: None,
-            size: None,
-            requires_api_key: true,
-            is_selected: false,
-            is_downloaded: None,
-            path: None,
-            description: Some(description.to_string()),
-            download_url: None,
-            filename: None,
-        });
-    }
-
-    // Add local Whisper models with download status
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
-
-    let whisper_dir = app_data_dir.join("local_models").join("whisper");
-
-    for (name, url, size) in WHISPER_MODELS {
-        let filename = format!("ggml-{}.bin", name);
-        let model_path = whisper_dir.join(&filename);
-        let downloaded = model_path.exists();
-
-        let model_name = format!(
-            "Whisper {}",
-            name.chars().next().unwrap().to_uppercase().to_string() + &name[1..]
-        );
-
-        models.push(ModelDefinition {
-            id: format!("whisper-{}", name),
-            name: model_name.clone(),
-            provider: ModelProvider::LocalWhisper,
-            model_type: ModelType::Local,
-            purpose: ModelPurpose::SpeechToText,
-            engine: Some("whisper".to_string()), // Uses Whisper engine
-            size: Some(size.to_string()),
-            requires_api_key: false,
-            // Set whisper-tiny as default selected
-            is_selected: *name == "tiny",
-            is_downloaded: Some(downloaded),
-            path: if downloaded {
-                Some(model_path.to_string_lossy().to_string())
-            } else {
-                None
-            },
-            description: Some(format!(
-                "{} model - Runs locally without internet",
-                model_name
-            )),
-            download_url: Some(url.to_string()),
-            filename: Some(filename),
-        });
+    // Add user-defined OpenAI-compatible endpoints
+    match get_custom_endpoints(&app) {
+        Ok(endpoints) => models.extend(endpoints.iter().map(|e| e.to_model_definition())),
+        Err(e) => log::warn!("Failed to load custom endpoints: {}", e),
     }
 
     Ok(models)
@@ -5,6 +5,7 @@ use tauri_plugin_store::StoreExt;
 use tokio::sync::Mutex;
 
 use super::engines::ModelConfig;
+use super::local_model_commands::decode_options_from_entry;
 use super::LocalModelManager;
 use crate::utils::logger;
 
@@ -99,8 +100,17 @@ pub async fn auto_start_selected_models(
                 let model_name = obj.get("name").and_then(|v| v.as_str()).unwrap_or(pp_id);
 
                 if is_downloaded {
-                    // Start the model (note: for now we only support one local model at a time)
-                    logger::info(&format!("ℹ️  Post-processing model {} is local and downloaded, but multiple local models not yet supported", model_name));
+                    // Loaded next to the speech-to-text model, not in its place
+                    if let Err(e) =
+                        start_local_model_internal(app, model_manager.clone(), obj, pp_id).await
+                    {
+                        logger::error(&format!("Failed to start post-processing model: {}", e));
+                    } else {
+                        logger::info(&format!(
+                            "✅ Auto-started post-processing model: {}",
+                            model_name
+                        ));
+                    }
                 } else {
                     models_to_download.push(model_name.to_string());
                 }
@@ -161,6 +171,7 @@ async fn start_local_model_internal(
         model_path: model_path.to_string(),
         model_name: model_name.to_string(),
         language: None,
+        decode_options: decode_options_from_entry(&serde_json::Value::Object(model_obj.clone())),
     };
 
     manager
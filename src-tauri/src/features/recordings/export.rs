@@ -0,0 +1,385 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{command, AppHandle, Manager};
+use tauri_plugin_store::StoreExt;
+use ts_rs::TS;
+
+use super::metadata::{RecordingMetadata, RecordingSegments, RecordingStatus};
+use super::storage::{get_recordings_dir, read_metadata, read_segments, save_segments};
+use crate::features::audio::NormalizedAudio;
+use crate::features::models::models_registry::ModelProvider;
+use crate::features::transcription::language::resolve_language;
+use crate::features::transcription::local_model::{loaded_local_model, restore_local_model};
+use crate::features::transcription::providers::{
+    TranscriptionOptions, TranscriptionProviderRegistry, TranscriptionRequest, TranscriptionSegment,
+};
+use crate::utils::logger;
+
+/// Output format for `export_recordings`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// SubRip subtitles
+    Srt,
+    /// WebVTT subtitles
+    Vtt,
+    /// Plain text with `[mm:ss]` markers
+    Text,
+    /// Markdown with a heading per recording
+    Markdown,
+}
+
+/// One recording's timings, ready to be rendered
+struct ExportEntry {
+    datetime: String,
+    /// Recording length in seconds
+    duration: f64,
+    segments: Vec<TranscriptionSegment>,
+}
+
+/// Export one or more recordings as subtitles or timestamped notes
+///
+/// Recordings are rendered in the given order. Subtitle formats place them
+/// back to back on a single timeline. Recordings without saved segments are
+/// re-segmented with a downloaded local Whisper model first, whichever
+/// model transcribed them.
+#[command]
+pub async fn export_recordings(
+    app: AppHandle,
+    timestamps: Vec<i64>,
+    format: ExportFormat,
+) -> Result<String, String> {
+    if timestamps.is_empty() {
+        return Err("No recordings selected for export".to_string());
+    }
+
+    let recordings_dir = get_recordings_dir(&app)?;
+    let mut entries = Vec::new();
+
+    for timestamp in timestamps {
+        let recording_folder = recordings_dir.join(timestamp.to_string());
+        if !recording_folder.exists() {
+            return Err(format!("Recording not found: {}", timestamp));
+        }
+
+        let metadata = read_metadata(&recording_folder)?;
+        if metadata.status == RecordingStatus::Failed {
+            return Err(format!(
+                "Recording {} has no transcript yet. Retry it before exporting.",
+                timestamp
+            ));
+        }
+
+        let segments = match read_segments(&recording_folder)? {
+            Some(saved) if !saved.segments.is_empty() => saved.segments,
+            _ => resegment_with_local_whisper(&app, &recording_folder, &metadata)
+                .await
+                .map_err(|e| {
+                    format!(
+                        "Recording {} has no timestamps and re-segmenting failed: {}. Download a local Whisper model and try again.",
+                        timestamp, e
+                    )
+                })?,
+        };
+
+        entries.push(ExportEntry {
+            datetime: metadata.datetime.clone(),
+            duration: metadata.duration / 1000.0, // Stored in ms
+            segments,
+        });
+    }
+
+    Ok(render_export(&entries, format))
+}
+
+/// Run the recording's audio through local Whisper to recover segments
+async fn resegment_with_local_whisper(
+    app: &AppHandle,
+    recording_folder: &PathBuf,
+    metadata: &RecordingMetadata,
+) -> Result<Vec<TranscriptionSegment>, String> {
+    logger::info(&format!(
+        "Re-segmenting {} with local Whisper",
+        recording_folder.display()
+    ));
+
+    let audio_data = fs::read(recording_folder.join("audio.wav"))
+        .map_err(|e| format!("Failed to read audio file: {}", e))?;
+    let audio = NormalizedAudio::from_wav(&audio_data)?;
+    let duration = audio.duration_secs();
+
+    let models = app
+        .store("models.json")
+        .ok()
+        .and_then(|store| store.get("models"))
+        .and_then(|models| models.as_array().cloned())
+        .unwrap_or_default();
+    let loaded = loaded_local_model(app).await;
+    let model_id = resegment_model_id(
+        &models,
+        loaded.as_ref().map(|info| info.path.as_str()),
+        &metadata.model_key,
+    )
+    .ok_or("No local Whisper model is downloaded")?;
+
+    let registry = app.state::<TranscriptionProviderRegistry>();
+    let provider = registry.get(ModelProvider::LocalWhisper.as_str())?;
+
+    let response = provider
+        .transcribe(
+            app,
+            TranscriptionRequest {
+                audio,
+                model_id,
+                language: resolve_language(&metadata.language_selected),
+                vocabulary: Vec::new(),
+                api_key: None,
+                options: TranscriptionOptions::default(),
+            },
+        )
+        .await;
+
+    // Put back whatever was loaded if another model had to be loaded
+    restore_local_model(app, loaded).await;
+    let response = response?.with_fallback_segment(duration);
+
+    let timings = RecordingSegments::from(&response);
+    save_segments(recording_folder, &timings)?;
+
+    Ok(timings.segments)
+}
+
+/// Local Whisper model to re-segment with
+///
+/// Prefers the loaded model, then the one that made the recording, then any
+/// downloaded local Whisper model. The recording may come from a cloud
+/// model, which can't produce segments after the fact.
+fn resegment_model_id(
+    models: &[serde_json::Value],
+    loaded_path: Option<&str>,
+    recording_model: &str,
+) -> Option<String> {
+    fn field<'a>(model: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+        model.get(key).and_then(|v| v.as_str())
+    }
+
+    let downloaded: Vec<&serde_json::Value> = models
+        .iter()
+        .filter(|m| {
+            field(m, "provider") == Some(ModelProvider::LocalWhisper.as_str())
+                && m.get("isDownloaded").and_then(|v| v.as_bool()) == Some(true)
+                && field(m, "path").is_some()
+        })
+        .collect();
+
+    let by_path = downloaded
+        .iter()
+        .find(|m| loaded_path.is_some() && field(m, "path") == loaded_path);
+    let by_id = downloaded
+        .iter()
+        .find(|m| field(m, "id") == Some(recording_model));
+
+    by_path
+        .or(by_id)
+        .or(downloaded.first())
+        .and_then(|m| field(m, "id"))
+        .map(String::from)
+}
+
+fn render_export(entries: &[ExportEntry], format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Srt => render_subtitles(entries, false),
+        ExportFormat::Vtt => render_subtitles(entries, true),
+        ExportFormat::Text => entries
+            .iter()
+            .map(|entry| {
+                entry
+                    .segments
+                    .iter()
+                    .map(|s| format!("[{}] {}", format_minutes(s.start), s.text.trim()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        ExportFormat::Markdown => entries
+            .iter()
+            .map(|entry| {
+                let mut section = format!("## {}\n", entry.datetime);
+                for segment in &entry.segments {
+                    section.push_str(&format!(
+                        "\n**[{}]** {}\n",
+                        format_minutes(segment.start),
+                        segment.text.trim()
+                    ));
+                }
+                section
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Render SRT (or WebVTT) cues, placing recordings back to back
+fn render_subtitles(entries: &[ExportEntry], webvtt: bool) -> String {
+    let mut output = if webvtt {
+        "WEBVTT\n\n".to_string()
+    } else {
+        String::new()
+    };
+
+    let separator = if webvtt { '.' } else { ',' };
+    let mut offset = 0.0;
+    let mut cue = 1;
+
+    for entry in entries {
+        for segment in entry.segments.iter().filter(|s| !s.text.trim().is_empty()) {
+            if !webvtt {
+                output.push_str(&format!("{}\n", cue));
+            }
+            output.push_str(&format!(
+                "{} --> {}\n{}\n\n",
+                format_timestamp(offset + segment.start, separator),
+                format_timestamp(offset + segment.end, separator),
+                segment.text.trim()
+            ));
+            cue += 1;
+        }
+
+        let last_end = entry.segments.last().map(|s| s.end).unwrap_or(0.0);
+        offset += entry.duration.max(last_end);
+    }
+
+    output
+}
+
+/// Format seconds as `HH:MM:SS,mmm` (SRT) or `HH:MM:SS.mmm` (WebVTT)
+fn format_timestamp(seconds: f64, separator: char) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        total_ms / 3_600_000,
+        (total_ms / 60_000) % 60,
+        (total_ms / 1000) % 60,
+        separator,
+        total_ms % 1000
+    )
+}
+
+/// Format seconds as `mm:ss`
+fn format_minutes(seconds: f64) -> String {
+    let total = seconds.max(0.0) as u64;
+    format!("{:02}:{:02}", total / 60, total % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<ExportEntry> {
+        let segment = |start: f64, end: f64, text: &str| TranscriptionSegment {
+            start,
+            end,
+            text: text.to_string(),
+            speaker: None,
+        };
+
+        vec![
+            ExportEntry {
+                datetime: "2025-01-01T10:00:00".to_string(),
+                duration: 5.0,
+                segments: vec![
+                    segment(0.0, 2.5, " Hello there."),
+                    segment(2.5, 4.0, "Second line"),
+                ],
+            },
+            ExportEntry {
+                datetime: "2025-01-01T11:00:00".to_string(),
+                duration: 75.0,
+                segments: vec![segment(61.2, 63.0, "Later")],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_srt_places_recordings_back_to_back() {
+        let srt = render_export(&entries(), ExportFormat::Srt);
+
+        assert!(srt.starts_with("1\n00:00:00,000 --> 00:00:02,500\nHello there.\n\n"));
+        assert!(srt.contains("2\n00:00:02,500 --> 00:00:04,000\nSecond line\n\n"));
+        assert!(srt.contains("3\n00:01:06,200 --> 00:01:08,000\nLater\n\n"));
+    }
+
+    #[test]
+    fn test_vtt_header_and_separator() {
+        let vtt = render_export(&entries(), ExportFormat::Vtt);
+
+        assert!(vtt.starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:02.500\nHello there.\n\n"));
+        assert!(!vtt.contains("\n1\n"));
+    }
+
+    #[test]
+    fn test_text_and_markdown_markers() {
+        let text = render_export(&entries(), ExportFormat::Text);
+        assert_eq!(
+            text,
+            "[00:00] Hello there.\n[00:02] Second line\n\n[01:01] Later"
+        );
+
+        let markdown = render_export(&entries(), ExportFormat::Markdown);
+        assert!(markdown.starts_with("## 2025-01-01T10:00:00\n\n**[00:00]** Hello there.\n"));
+        assert!(markdown.contains("## 2025-01-01T11:00:00\n\n**[01:01]** Later\n"));
+    }
+
+    #[test]
+    fn test_cloud_recording_resegments_with_local_model() {
+        let models = vec![
+            serde_json::json!({"id": "scribe_v1", "provider": "elevenlabs"}),
+            serde_json::json!({
+                "id": "whisper-small",
+                "provider": "local-whisper",
+                "isDownloaded": false,
+            }),
+            serde_json::json!({
+                "id": "whisper-base",
+                "provider": "local-whisper",
+                "isDownloaded": true,
+                "path": "/models/whisper/ggml-base.bin",
+            }),
+            serde_json::json!({
+                "id": "whisper-medium",
+                "provider": "local-whisper",
+                "isDownloaded": true,
+                "path": "/models/whisper/ggml-medium.bin",
+            }),
+        ];
+
+        // A cloud recording falls back to a downloaded local model
+        assert_eq!(
+            resegment_model_id(&models, None, "scribe_v1").as_deref(),
+            Some("whisper-base")
+        );
+        // The loaded model wins over the others
+        assert_eq!(
+            resegment_model_id(
+                &models,
+                Some("/models/whisper/ggml-medium.bin"),
+                "scribe_v1"
+            )
+            .as_deref(),
+            Some("whisper-medium")
+        );
+        // A local recording keeps its own model when nothing is loaded
+        assert_eq!(
+            resegment_model_id(&models, None, "whisper-medium").as_deref(),
+            Some("whisper-medium")
+        );
+        // Nothing downloaded, nothing to re-segment with
+        assert_eq!(resegment_model_id(&models[..2], None, "scribe_v1"), None);
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle};
+
+use super::storage::{create_recording_folder, get_recordings_dir, save_audio_file};
+use crate::features::audio::decode_audio_file;
+use crate::features::transcription::language::language_from_settings;
+use crate::features::transcription::orchestrator::{
+    transcribe_and_process, TranscribeRequest, TranscriptionRecord,
+};
+use crate::features::transcription::retry_queue::record_failed_transcription;
+use crate::utils::logger;
+
+/// Transcribe an audio file from disk into the recordings library
+///
+/// Any format symphonia can decode (WAV, MP3, M4A, FLAC, OGG) is converted
+/// to a 16kHz mono WAV in a new recording folder and transcribed like a
+/// dictation, without pasting. The file name is stored as the recording
+/// device. A failed transcription is queued for retry.
+#[command]
+pub async fn import_audio_file(
+    app: AppHandle,
+    path: String,
+) -> Result<Option<TranscriptionRecord>, String> {
+    let source = PathBuf::from(&path);
+    if !source.is_file() {
+        return Err(format!("Audio file not found: {}", path));
+    }
+
+    import_audio(&app, &source, None).await?.transcription
+}
+
+/// A file whose audio was saved as a recording
+pub struct ImportedAudio {
+    pub timestamp: i64,
+    /// A failed transcription is queued for retry under `timestamp`
+    pub transcription: Result<Option<TranscriptionRecord>, String>,
+}
+
+/// Decode `source` into a new recording and transcribe it in the background
+///
+/// `model_id` overrides the selected speech-to-text model. Fails only if no
+/// recording could be created (e.g. the file can't be decoded).
+pub async fn import_audio(
+    app: &AppHandle,
+    source: &Path,
+    model_id: Option<String>,
+) -> Result<ImportedAudio, String> {
+    let file_name = source
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| source.display().to_string());
+
+    logger::info(&format!("Importing audio file: {}", file_name));
+
+    // Decoding and resampling a long file is CPU-bound
+    let path = source.to_path_buf();
+    let audio = tauri::async_runtime::spawn_blocking(move || decode_audio_file(&path))
+        .await
+        .map_err(|e| format!("Audio decoding task failed: {}", e))??;
+
+    if audio.samples.is_empty() {
+        return Err(format!("{} contains no audio", file_name));
+    }
+
+    let audio_data = audio.to_wav()?;
+    let duration = Some(audio.duration_secs());
+
+    // Two imports in the same millisecond must not share a folder
+    let recordings_dir = get_recordings_dir(app)?;
+    let mut timestamp = chrono::Local::now().timestamp_millis();
+    while recordings_dir.join(timestamp.to_string()).exists() {
+        timestamp += 1;
+    }
+
+    let recording_folder = create_recording_folder(app, timestamp)?;
+    save_audio_file(&recording_folder, &audio_data)?;
+
+    let language = language_from_settings(app);
+    let request = TranscribeRequest {
+        audio_data,
+        timestamp,
+        duration,
+        language: language.clone(),
+        recording_device: Some(file_name.clone()),
+        background: true,
+        translate: false,
+        model_id,
+    };
+
+    let transcription = match transcribe_and_process(request, app.clone()).await {
+        Ok(record) => Ok(record),
+        Err(e) => {
+            logger::error(&format!("Failed to transcribe {}: {}", file_name, e));
+
+            // Keep the imported audio and retry like a failed dictation
+            if let Err(record_err) = record_failed_transcription(
+                app,
+                timestamp,
+                duration,
+                language,
+                Some(file_name),
+                false,
+                &e,
+            ) {
+                logger::warn(&format!("Failed to queue import for retry: {}", record_err));
+            }
+
+            Err(e)
+        }
+    };
+
+    Ok(ImportedAudio {
+        timestamp,
+        transcription,
+    })
+}
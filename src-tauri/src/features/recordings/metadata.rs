@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use crate::features::transcription::providers::{
+    TranscriptionResponse, TranscriptionSegment, TranscriptionWord,
+};
+
 /// Comprehensive metadata for each recording
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(
@@ -9,6 +13,12 @@ use ts_rs::TS;
 )]
 #[serde(rename_all = "camelCase")]
 pub struct RecordingMetadata {
+    // Transcription status
+    #[serde(default)]
+    pub status: RecordingStatus,
+    #[serde(default)]
+    pub error: Option<String>, // Last transcription error (failed recordings)
+
     // Core transcription data
     pub result: String,                        // Final processed text
     pub raw_result: String,                    // Original transcription text
@@ -19,10 +29,13 @@ pub struct RecordingMetadata {
     pub duration: f64,        // Duration in milliseconds
     pub processing_time: u64, // Time taken to process in ms
 
-    // Speech-to-text model information
+    // Speech-to-text model information (the model that produced the text,
+    // which may be a fallback rather than the selected one)
     pub model_key: String,  // Model ID used for transcription
     pub model_name: String, // Human-readable model name
     pub provider: String,   // Provider (openai, google, local-whisper, etc.)
+    #[serde(default = "default_attempts")]
+    pub transcription_attempts: u32, // Attempts across the fallback chain
 
     // Post-processing model information
     pub post_processing_model_id: Option<String>, // Post-processing model ID (if used)
@@ -30,7 +43,11 @@ pub struct RecordingMetadata {
     pub post_processing_provider: Option<String>, // Post-processing provider (if used)
 
     // Language
-    pub language_selected: String, // Language code (e.g., "en")
+    pub language_selected: String, // Language code (e.g., "en") or "auto"
+    #[serde(default)]
+    pub detected_language: Option<String>, // Language reported by the model/provider
+    #[serde(default)]
+    pub translation: Option<TranslationInfo>, // Set when the speech was translated
 
     // Device information
     pub recording_device: String, // Microphone device ID/name
@@ -47,10 +64,79 @@ pub struct RecordingMetadata {
     // Prompt context (for AI post-processing)
     pub prompt_context: PromptContext,
 
+    // Every transcript produced for this audio, oldest first. The fields
+    // above always mirror the latest one. Empty until re-transcribed.
+    #[serde(default)]
+    pub revisions: Vec<TranscriptRevision>,
+
     // App version
     pub app_version: String,
 }
 
+/// One transcript of a recording's audio
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptRevision {
+    pub created_at: String, // ISO 8601 timestamp
+    pub result: String,
+    pub raw_result: String,
+    pub post_processed_result: Option<String>,
+    pub model_key: String,
+    pub model_name: String,
+    pub provider: String,
+    pub post_processing_model_id: Option<String>,
+    pub detected_language: Option<String>,
+    pub processing_time: u64,
+}
+
+impl TranscriptRevision {
+    /// Snapshot the transcript currently stored in the metadata
+    pub fn from_metadata(metadata: &RecordingMetadata, created_at: String) -> Self {
+        Self {
+            created_at,
+            result: metadata.result.clone(),
+            raw_result: metadata.raw_result.clone(),
+            post_processed_result: metadata.post_processed_result.clone(),
+            model_key: metadata.model_key.clone(),
+            model_name: metadata.model_name.clone(),
+            provider: metadata.provider.clone(),
+            post_processing_model_id: metadata.post_processing_model_id.clone(),
+            detected_language: metadata.detected_language.clone(),
+            processing_time: metadata.processing_time,
+        }
+    }
+}
+
+/// Languages of a translated dictation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationInfo {
+    pub source_language: Option<String>, // Spoken language, if known
+    pub target_language: String,         // Language of the result (always "en")
+}
+
+/// Whether a recording has a transcript yet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordingStatus {
+    #[default]
+    Completed,
+    /// Transcription failed; the audio is kept so it can be retried
+    Failed,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(
     export,
@@ -98,6 +184,10 @@ pub struct ApplicationContext {
     pub category: String,
 }
 
+fn default_attempts() -> u32 {
+    1
+}
+
 impl RecordingMetadata {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -131,6 +221,8 @@ impl RecordingMetadata {
             .to_string();
 
         Self {
+            status: RecordingStatus::Completed,
+            error: None,
             result: final_result,
             raw_result,
             post_processed_result,
@@ -140,10 +232,13 @@ impl RecordingMetadata {
             model_key,
             model_name,
             provider,
+            transcription_attempts: default_attempts(),
             post_processing_model_id,
             post_processing_model_name,
             post_processing_provider,
             language_selected: language,
+            detected_language: None,
+            translation: None,
             recording_device,
             post_processing_enabled,
             style_applied,
@@ -151,7 +246,25 @@ impl RecordingMetadata {
             focused_app_name,
             focused_app_category,
             prompt_context,
+            revisions: Vec::new(),
             app_version: env!("CARGO_PKG_VERSION").to_string(),
         }
     }
 }
+
+/// Segment and word timings, stored as segments.json next to meta.json
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordingSegments {
+    pub segments: Vec<TranscriptionSegment>,
+    #[serde(default)]
+    pub words: Vec<TranscriptionWord>,
+}
+
+impl From<&TranscriptionResponse> for RecordingSegments {
+    fn from(response: &TranscriptionResponse) -> Self {
+        Self {
+            segments: response.segments.clone().unwrap_or_default(),
+            words: response.words.clone().unwrap_or_default(),
+        }
+    }
+}
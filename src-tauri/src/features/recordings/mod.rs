@@ -1,9 +1,15 @@
+pub mod export;
+pub mod import;
 pub mod metadata;
 pub mod storage;
+pub mod watched_folders;
 
-pub use metadata::RecordingMetadata;
+pub use export::{export_recordings, ExportFormat};
+pub use import::import_audio_file;
+pub use metadata::{RecordingMetadata, RecordingSegments};
 pub use storage::{
     create_recording_folder, delete_recording, get_all_recordings, get_all_transcriptions,
-    get_recording_audio_path, get_recordings_dir, read_metadata, save_audio_file, save_metadata,
-    TranscriptionRecord,
+    get_recording_audio_path, get_recordings_dir, read_metadata, read_segments, save_audio_file,
+    save_metadata, save_segments, TranscriptionRecord,
 };
+pub use watched_folders::start_folder_watcher;
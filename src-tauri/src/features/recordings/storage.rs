@@ -4,7 +4,7 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::{command, AppHandle, Manager};
 
-use super::metadata::RecordingMetadata;
+use super::metadata::{RecordingMetadata, RecordingSegments, RecordingStatus};
 
 /// Simplified transcription record for frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +17,8 @@ pub struct TranscriptionRecord {
     pub word_count: usize,
     pub model_id: String,
     pub provider: String,
+    pub status: RecordingStatus,
+    pub error: Option<String>,
 }
 
 /// Get the recordings directory path
@@ -76,6 +78,38 @@ pub fn save_metadata(
     Ok(())
 }
 
+/// Save segment and word timings to the recording folder
+pub fn save_segments(
+    recording_folder: &PathBuf,
+    segments: &RecordingSegments,
+) -> Result<(), String> {
+    let segments_path = recording_folder.join("segments.json");
+
+    let json = serde_json::to_string_pretty(segments)
+        .map_err(|e| format!("Failed to serialize segments: {}", e))?;
+
+    fs::write(&segments_path, json).map_err(|e| format!("Failed to save segments file: {}", e))?;
+
+    Ok(())
+}
+
+/// Read segment and word timings from a recording folder (None if never saved)
+pub fn read_segments(recording_folder: &PathBuf) -> Result<Option<RecordingSegments>, String> {
+    let segments_path = recording_folder.join("segments.json");
+
+    if !segments_path.exists() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(&segments_path)
+        .map_err(|e| format!("Failed to read segments file: {}", e))?;
+
+    let segments: RecordingSegments =
+        serde_json::from_str(&json).map_err(|e| format!("Failed to parse segments: {}", e))?;
+
+    Ok(Some(segments))
+}
+
 /// Get all recording folders sorted by timestamp (newest first)
 pub fn get_all_recordings(app: &AppHandle) -> Result<Vec<PathBuf>, String> {
     let recordings_dir = get_recordings_dir(app)?;
@@ -143,6 +177,8 @@ pub async fn get_all_transcriptions(app: AppHandle) -> Result<Vec<TranscriptionR
                     word_count: metadata.result.split_whitespace().count(),
                     model_id: metadata.model_key.clone(),
                     provider: metadata.provider.clone(),
+                    status: metadata.status,
+                    error: metadata.error.clone(),
                 });
             }
             Err(e) => {
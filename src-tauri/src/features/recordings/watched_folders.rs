@@ -0,0 +1,561 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use super::import::import_audio;
+use super::metadata::RecordingStatus;
+use super::storage::{get_recordings_dir, read_metadata};
+use crate::features::transcription::orchestrator::get_settings;
+use crate::types::settings::{WatchedFileAction, WatchedFolder};
+use crate::utils::logger;
+
+/// Store holding the ledger of watched files already handled
+const WATCHED_FOLDERS_STORE: &str = "watched_folders.json";
+const LEDGER_KEY: &str = "processed";
+/// Folders whose existing files were recorded when they started being watched
+const BASELINE_KEY: &str = "baselined";
+
+/// How often watched folders are scanned
+const WORKER_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Files modified more recently than this may still be written or synced
+const SETTLE_TIME_MS: i64 = 10_000;
+
+/// Subfolder used by `move` when no destination is configured
+const DEFAULT_MOVE_FOLDER: &str = "Transcribed";
+
+/// A watched file that has already been handled
+///
+/// Covers imported files, files that can't be decoded (skipped until they
+/// change) and files that were already in a folder when it was added.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessedFile {
+    pub path: String,
+    pub size: u64,
+    /// Modification time in ms; a file that changes is imported again
+    pub modified: i64,
+    /// Recording created from the file
+    pub timestamp: Option<i64>,
+    /// Set while the recording's transcription waits in the retry queue;
+    /// `after_import` is applied once a retry succeeds
+    pub error: Option<String>,
+    pub processed_at: i64,
+}
+
+impl ProcessedFile {
+    fn new(file: &WatchedFile, timestamp: Option<i64>, error: Option<String>, now: i64) -> Self {
+        Self {
+            path: file.path.to_string_lossy().to_string(),
+            size: file.size,
+            modified: file.modified,
+            timestamp,
+            error,
+            processed_at: now,
+        }
+    }
+
+    /// Recording whose transcription failed and may still be retried
+    fn awaiting_retry(&self) -> Option<i64> {
+        self.error.as_ref().and(self.timestamp)
+    }
+}
+
+/// An audio file found in a watched folder
+#[derive(Debug, Clone)]
+struct WatchedFile {
+    path: PathBuf,
+    size: u64,
+    modified: i64,
+}
+
+/// Case-insensitive file name match with `*` and `?` wildcards
+fn matches_glob(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+
+    // Iterative matcher that backtracks to the last `*`
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Whether this exact version of the file was handled before
+fn is_processed(ledger: &[ProcessedFile], file: &WatchedFile) -> bool {
+    let path = file.path.to_string_lossy();
+    ledger.iter().any(|entry| {
+        entry.path == path && entry.size == file.size && entry.modified == file.modified
+    })
+}
+
+/// Where `move` puts a file, without overwriting an earlier one
+fn move_destination(folder: &WatchedFolder, source: &Path) -> PathBuf {
+    let dir = folder
+        .move_to
+        .as_deref()
+        .filter(|dir| !dir.trim().is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(&folder.path).join(DEFAULT_MOVE_FOLDER));
+
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = source
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut destination = dir.join(format!("{}{}", stem, extension));
+    let mut copy = 1;
+    while destination.exists() {
+        destination = dir.join(format!("{} ({}){}", stem, copy, extension));
+        copy += 1;
+    }
+
+    destination
+}
+
+fn load_ledger(app: &AppHandle) -> Result<Vec<ProcessedFile>, String> {
+    let store = app
+        .store(WATCHED_FOLDERS_STORE)
+        .map_err(|e| format!("Failed to access watched folder ledger: {}", e))?;
+
+    match store.get(LEDGER_KEY) {
+        Some(value) => serde_json::from_value(value)
+            .map_err(|e| format!("Failed to parse watched folder ledger: {}", e)),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn save_ledger(app: &AppHandle, ledger: &[ProcessedFile]) -> Result<(), String> {
+    let store = app
+        .store(WATCHED_FOLDERS_STORE)
+        .map_err(|e| format!("Failed to access watched folder ledger: {}", e))?;
+
+    let value = serde_json::to_value(ledger)
+        .map_err(|e| format!("Failed to serialize watched folder ledger: {}", e))?;
+
+    store.set(LEDGER_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save watched folder ledger: {}", e))
+}
+
+fn load_baselined(app: &AppHandle) -> Result<Vec<String>, String> {
+    let store = app
+        .store(WATCHED_FOLDERS_STORE)
+        .map_err(|e| format!("Failed to access watched folder ledger: {}", e))?;
+
+    Ok(store
+        .get(BASELINE_KEY)
+        .and_then(|value| serde_json::from_value(value).ok())
+        .unwrap_or_default())
+}
+
+/// Enabled folders from `transcription.watchedFolders` in settings
+fn get_watched_folders(app: &AppHandle) -> Vec<WatchedFolder> {
+    get_settings(app)
+        .ok()
+        .and_then(|settings| {
+            settings
+                .get("transcription")?
+                .get("watchedFolders")
+                .cloned()
+        })
+        .and_then(|value| serde_json::from_value::<Vec<WatchedFolder>>(value).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|folder| folder.enabled && !folder.path.trim().is_empty())
+        .collect()
+}
+
+/// Settled files in a folder (not recursive) that match its globs
+fn scan_folder(folder: &WatchedFolder, now: i64) -> Result<Vec<WatchedFile>, String> {
+    let entries = fs::read_dir(&folder.path)
+        .map_err(|e| format!("Failed to read watched folder {}: {}", folder.path, e))?;
+
+    let mut files = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(name) = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+
+        // Hidden files include partial downloads from sync clients
+        if name.starts_with('.') || !folder.globs.iter().any(|glob| matches_glob(glob, &name)) {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or(0);
+
+        if now - modified < SETTLE_TIME_MS {
+            continue;
+        }
+
+        files.push(WatchedFile {
+            path,
+            size: metadata.len(),
+            modified,
+        });
+    }
+
+    // Oldest first, so memories land in the library in recording order
+    files.sort_by_key(|file| file.modified);
+    Ok(files)
+}
+
+/// Move or delete a source file after it was transcribed
+fn apply_after_import(folder: &WatchedFolder, source: &Path) -> Result<(), String> {
+    match folder.after_import {
+        WatchedFileAction::Keep => Ok(()),
+        WatchedFileAction::Delete => {
+            fs::remove_file(source).map_err(|e| format!("Failed to delete source file: {}", e))
+        }
+        WatchedFileAction::Move => {
+            let destination = move_destination(folder, source);
+            if let Some(parent) = destination.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create destination folder: {}", e))?;
+            }
+
+            // A rename can't cross volumes; fall back to copy and delete
+            if fs::rename(source, &destination).is_err() {
+                fs::copy(source, &destination)
+                    .map_err(|e| format!("Failed to move source file: {}", e))?;
+                fs::remove_file(source)
+                    .map_err(|e| format!("Failed to remove moved source file: {}", e))?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Import one watched file and record the outcome in the ledger
+async fn process_file(
+    app: &AppHandle,
+    folder: &WatchedFolder,
+    file: &WatchedFile,
+    ledger: &mut Vec<ProcessedFile>,
+) -> Result<(), String> {
+    let (timestamp, result) = match import_audio(app, &file.path, folder.model_id.clone()).await {
+        // No speech: the recording was discarded
+        Ok(imported) if matches!(imported.transcription, Ok(None)) => (None, Ok(None)),
+        Ok(imported) => (Some(imported.timestamp), imported.transcription),
+        Err(e) => (None, Err(e)),
+    };
+
+    let now = chrono::Utc::now().timestamp_millis();
+    ledger.push(ProcessedFile::new(
+        file,
+        timestamp,
+        result.as_ref().err().cloned(),
+        now,
+    ));
+    save_ledger(app, ledger)?;
+
+    // Only a file that produced a transcript is moved or deleted
+    if matches!(result, Ok(Some(_))) {
+        apply_after_import(folder, &file.path)?;
+    }
+
+    result.map(|_| ())
+}
+
+/// Mark the files already in newly watched folders as handled
+///
+/// Only files added after a folder starts being watched are imported, so
+/// adding a folder full of old memos doesn't transcribe all of them.
+fn baseline_new_folders(
+    app: &AppHandle,
+    folders: &[WatchedFolder],
+    ledger: &mut Vec<ProcessedFile>,
+    now: i64,
+) -> Result<(), String> {
+    let baselined = load_baselined(app)?;
+    if baselined.len() == folders.len()
+        && folders
+            .iter()
+            .all(|folder| baselined.contains(&folder.path))
+    {
+        return Ok(());
+    }
+
+    let mut watched = Vec::new();
+    for folder in folders {
+        if !baselined.contains(&folder.path) {
+            // Includes files still being written; they were there first
+            match scan_folder(folder, i64::MAX) {
+                Ok(files) => {
+                    for file in files.iter().filter(|file| !is_processed(ledger, file)) {
+                        ledger.push(ProcessedFile::new(file, None, None, now));
+                    }
+                }
+                Err(e) => {
+                    // Try again once the folder can be read
+                    logger::warn(&e);
+                    continue;
+                }
+            }
+        }
+
+        watched.push(folder.path.clone());
+    }
+
+    // Folders no longer watched are dropped, so re-adding one baselines it again
+    let store = app
+        .store(WATCHED_FOLDERS_STORE)
+        .map_err(|e| format!("Failed to access watched folder ledger: {}", e))?;
+    store.set(BASELINE_KEY, serde_json::json!(watched));
+    save_ledger(app, ledger)
+}
+
+/// Move or delete files whose failed transcription was since retried
+fn finish_retried_imports(
+    app: &AppHandle,
+    folders: &[WatchedFolder],
+    ledger: &mut [ProcessedFile],
+) -> Result<(), String> {
+    if ledger.iter().all(|entry| entry.awaiting_retry().is_none()) {
+        return Ok(());
+    }
+
+    let recordings_dir = get_recordings_dir(app)?;
+    let mut changed = false;
+
+    for entry in ledger.iter_mut() {
+        let Some(timestamp) = entry.awaiting_retry() else {
+            continue;
+        };
+        let completed = read_metadata(&recordings_dir.join(timestamp.to_string()))
+            .is_ok_and(|metadata| metadata.status == RecordingStatus::Completed);
+        if !completed {
+            continue;
+        }
+
+        entry.error = None;
+        changed = true;
+
+        let source = PathBuf::from(&entry.path);
+        let Some(folder) = folders
+            .iter()
+            .find(|folder| source.parent() == Some(Path::new(&folder.path)))
+        else {
+            continue;
+        };
+        if let Err(e) = apply_after_import(folder, &source) {
+            logger::warn(&format!(
+                "Failed to apply after-import action to {}: {}",
+                source.display(),
+                e
+            ));
+        }
+    }
+
+    if changed {
+        save_ledger(app, ledger)?;
+    }
+
+    Ok(())
+}
+
+/// Import new files from every enabled watched folder
+async fn scan_watched_folders(app: &AppHandle) -> Result<(), String> {
+    let folders = get_watched_folders(app);
+    if folders.is_empty() {
+        return Ok(());
+    }
+
+    let mut ledger = load_ledger(app)?;
+
+    // Forget files that are gone (moved, deleted or renamed)
+    let before = ledger.len();
+    ledger.retain(|entry| Path::new(&entry.path).exists());
+    if ledger.len() != before {
+        save_ledger(app, &ledger)?;
+    }
+
+    let now = chrono::Utc::now().timestamp_millis();
+    baseline_new_folders(app, &folders, &mut ledger, now)?;
+    finish_retried_imports(app, &folders, &mut ledger)?;
+
+    for folder in &folders {
+        let files = match scan_folder(folder, now) {
+            Ok(files) => files,
+            Err(e) => {
+                logger::warn(&e);
+                continue;
+            }
+        };
+
+        for file in files.iter().filter(|file| !is_processed(&ledger, file)) {
+            if let Err(e) = process_file(app, folder, file, &mut ledger).await {
+                logger::warn(&format!(
+                    "Watched file {} was not imported: {}",
+                    file.path.display(),
+                    e
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Transcribe new audio files from watched folders in the background
+pub fn start_folder_watcher(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(WORKER_INTERVAL).await;
+
+            if let Err(e) = scan_watched_folders(&app).await {
+                logger::warn(&format!("Failed to scan watched folders: {}", e));
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder(path: &Path) -> WatchedFolder {
+        WatchedFolder {
+            path: path.to_string_lossy().to_string(),
+            enabled: true,
+            globs: vec!["*.m4a".to_string()],
+            model_id: None,
+            after_import: WatchedFileAction::Move,
+            move_to: None,
+        }
+    }
+
+    #[test]
+    fn test_matches_glob() {
+        assert!(matches_glob("*.m4a", "Memo 1.M4A"));
+        assert!(matches_glob("memo-??.wav", "memo-01.wav"));
+        assert!(matches_glob("*meeting*", "Team meeting notes.mp3"));
+        assert!(!matches_glob("*.m4a", "memo.m4a.part"));
+        assert!(!matches_glob("memo-??.wav", "memo-1.wav"));
+    }
+
+    #[test]
+    fn test_ledger_matches_same_file_version() {
+        let file = WatchedFile {
+            path: PathBuf::from("/memos/a.m4a"),
+            size: 100,
+            modified: 5,
+        };
+        let ledger = vec![ProcessedFile {
+            path: "/memos/a.m4a".to_string(),
+            size: 100,
+            modified: 5,
+            timestamp: Some(1),
+            error: None,
+            processed_at: 10,
+        }];
+
+        assert!(is_processed(&ledger, &file));
+        assert!(!is_processed(
+            &ledger,
+            &WatchedFile {
+                modified: 6,
+                ..file.clone()
+            }
+        ));
+        assert!(!is_processed(&[], &file));
+    }
+
+    #[test]
+    fn test_move_destination_avoids_overwriting() {
+        let dir = tempfile::tempdir().unwrap();
+        let folder = folder(dir.path());
+        let source = dir.path().join("memo.m4a");
+
+        let first = move_destination(&folder, &source);
+        assert_eq!(first, dir.path().join("Transcribed").join("memo.m4a"));
+
+        fs::create_dir_all(first.parent().unwrap()).unwrap();
+        fs::write(&first, b"x").unwrap();
+        assert_eq!(
+            move_destination(&folder, &source),
+            dir.path().join("Transcribed").join("memo (1).m4a")
+        );
+    }
+
+    #[test]
+    fn test_failed_import_awaits_retry() {
+        let file = WatchedFile {
+            path: PathBuf::from("/memos/a.m4a"),
+            size: 100,
+            modified: 5,
+        };
+
+        let failed = ProcessedFile::new(&file, Some(42), Some("timeout".to_string()), 10);
+        assert_eq!(failed.awaiting_retry(), Some(42));
+
+        // Undecodable files and baselined files have no recording to wait for
+        let undecodable = ProcessedFile::new(&file, None, Some("no audio".to_string()), 10);
+        assert_eq!(undecodable.awaiting_retry(), None);
+        assert_eq!(
+            ProcessedFile::new(&file, None, None, 10).awaiting_retry(),
+            None
+        );
+        assert_eq!(
+            ProcessedFile::new(&file, Some(42), None, 10).awaiting_retry(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_baseline_scan_includes_unsettled_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let folder = folder(dir.path());
+        fs::write(dir.path().join("memo.m4a"), b"x").unwrap();
+
+        let now = chrono::Utc::now().timestamp_millis();
+        assert!(scan_folder(&folder, now).unwrap().is_empty());
+        assert_eq!(scan_folder(&folder, i64::MAX).unwrap().len(), 1);
+    }
+}
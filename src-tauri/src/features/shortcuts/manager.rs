@@ -1,4 +1,5 @@
-use crate::features::shortcuts::utils::parse_shortcut;
+use crate::features::audio::DictationMode;
+use crate::features::shortcuts::utils::{parse_shortcut, translate_shortcut};
 use crate::utils::logger;
 use std::sync::{Arc, Mutex};
 use tauri::{command, App, AppHandle, Manager, Result, State};
@@ -9,6 +10,8 @@ use tauri_plugin_store::StoreExt;
 
 pub struct ShortcutManager {
     pub voice_input_shortcut: Arc<Mutex<Option<Shortcut>>>,
+    /// Voice input shortcut plus Shift, for dictations translated into English
+    pub translate_shortcut: Arc<Mutex<Option<Shortcut>>>,
     pub push_to_talk_shortcut: Arc<Mutex<Option<Shortcut>>>,
     pub paste_shortcut: Arc<Mutex<Option<Shortcut>>>,
     pub escape_shortcut: Arc<Mutex<Option<Shortcut>>>,
@@ -19,6 +22,7 @@ impl ShortcutManager {
     pub fn new() -> Self {
         Self {
             voice_input_shortcut: Arc::new(Mutex::new(None)),
+            translate_shortcut: Arc::new(Mutex::new(None)),
             push_to_talk_shortcut: Arc::new(Mutex::new(None)),
             paste_shortcut: Arc::new(Mutex::new(None)),
             escape_shortcut: Arc::new(Mutex::new(None)),
@@ -92,9 +96,48 @@ pub fn register_voice_input_shortcut(app: &App) -> Result<()> {
         *current = None;
     }
 
+    // Registered on its own so a clash with another app's shortcut doesn't
+    // take the other shortcuts down with it
+    if let Err(e) = register_translate_shortcut(app.handle(), &shortcut_state, &voice_shortcut) {
+        logger::warn(&e);
+    }
+
+    Ok(())
+}
+
+/// Register the translate variant of `voice_shortcut`, replacing the old one
+fn register_translate_shortcut(
+    app: &AppHandle,
+    shortcut_state: &ShortcutManager,
+    voice_shortcut: &Shortcut,
+) -> std::result::Result<(), String> {
+    unregister_translate_shortcut(app, shortcut_state);
+
+    let Some(shortcut) = translate_shortcut(voice_shortcut) else {
+        return Ok(());
+    };
+
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |app, shortcut, event| {
+            handle_translate_shortcut(app, shortcut, event);
+        })
+        .map_err(|e| format!("Failed to register translate shortcut: {}", e))?;
+
+    if let Ok(mut current) = shortcut_state.translate_shortcut.lock() {
+        *current = Some(shortcut);
+    }
+
     Ok(())
 }
 
+fn unregister_translate_shortcut(app: &AppHandle, shortcut_state: &ShortcutManager) {
+    if let Ok(mut translate_shortcut) = shortcut_state.translate_shortcut.lock() {
+        if let Some(shortcut) = translate_shortcut.take() {
+            let _ = app.global_shortcut().unregister(shortcut);
+        }
+    }
+}
+
 /// Retrieves the voice input shortcut from settings
 fn get_voice_input_shortcut_from_settings(app: &AppHandle) -> String {
     let store = app.store("settings");
@@ -154,7 +197,7 @@ fn handle_voice_input_shortcut(app: &tauri::AppHandle, shortcut: &Shortcut, even
         // Voice input shortcut always uses toggle mode
         tauri::async_runtime::spawn(async move {
             if let Err(e) = handler_clone
-                .handle_toggle_mode(&app_clone, &event_clone)
+                .handle_toggle_mode(&app_clone, &event_clone, None)
                 .await
             {
                 log::error!("Failed to handle toggle mode shortcut: {}", e);
@@ -163,6 +206,26 @@ fn handle_voice_input_shortcut(app: &tauri::AppHandle, shortcut: &Shortcut, even
     }
 }
 
+fn handle_translate_shortcut(app: &tauri::AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
+    if event.id == shortcut.id() {
+        let handler =
+            app.state::<std::sync::Arc<crate::features::shortcuts::RecordingShortcutHandler>>();
+        let handler_clone = handler.inner().clone();
+        let app_clone = app.clone();
+        let event_clone = event.clone();
+
+        // Toggle mode, starting a dictation that is translated into English
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = handler_clone
+                .handle_toggle_mode(&app_clone, &event_clone, Some(DictationMode::Translate))
+                .await
+            {
+                log::error!("Failed to handle translate shortcut: {}", e);
+            }
+        });
+    }
+}
+
 fn handle_ptt_shortcut(app: &tauri::AppHandle, shortcut: &Shortcut, event: ShortcutEvent) {
     if event.id == shortcut.id() {
         let handler =
@@ -248,6 +311,8 @@ pub async fn update_voice_input_shortcut(
         *current = Some(shortcut_clone);
     }
 
+    register_translate_shortcut(&app, &shortcut_state, &new_shortcut)?;
+
     logger::info("Voice input shortcut updated successfully");
     Ok(())
 }
@@ -331,6 +396,9 @@ pub async fn disable_global_shortcuts(
         }
     }
 
+    // Unregister translate shortcut
+    unregister_translate_shortcut(&app, &shortcut_state);
+
     // Unregister paste shortcut
     if let Ok(mut paste_shortcut) = shortcut_state.paste_shortcut.lock() {
         if let Some(shortcut) = paste_shortcut.take() {
@@ -382,6 +450,8 @@ pub async fn enable_global_shortcuts(
         if let Ok(mut current) = shortcut_state.voice_input_shortcut.lock() {
             *current = Some(shortcut_clone);
         }
+
+        register_translate_shortcut(&app, &shortcut_state, &parsed)?;
     }
 
     // Re-register paste shortcut from settings
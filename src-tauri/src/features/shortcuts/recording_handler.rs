@@ -1,6 +1,6 @@
 use crate::features::audio::{
-    cancel_recording, start_recording, stop_recording, AudioRecorder, RecordingState,
-    RecordingStateManager,
+    cancel_recording, start_recording, stop_recording, AudioRecorder, DictationMode,
+    RecordingState, RecordingStateManager,
 };
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -37,10 +37,14 @@ impl RecordingShortcutHandler {
     }
 
     /// Handle toggle mode shortcut (click to start/stop)
+    ///
+    /// `mode` applies to a recording this press starts; `None` transcribes
+    /// in the spoken language.
     pub async fn handle_toggle_mode(
         &self,
         app: &AppHandle,
         event: &ShortcutEvent,
+        mode: Option<DictationMode>,
     ) -> Result<(), String> {
         // Only respond to key press in toggle mode
         if event.state != ShortcutState::Pressed {
@@ -60,8 +64,8 @@ impl RecordingShortcutHandler {
 
         match current_state {
             RecordingState::Idle | RecordingState::Error => {
-                log::info!("Toggle mode: Starting recording");
-                start_recording(app.clone(), recorder.clone(), state_manager.clone()).await?;
+                log::info!("Toggle mode: Starting recording ({:?})", mode);
+                start_recording(app.clone(), recorder.clone(), state_manager.clone(), mode).await?;
             }
             RecordingState::Recording => {
                 log::info!("Toggle mode: Stopping recording");
@@ -94,7 +98,8 @@ impl RecordingShortcutHandler {
                 // Only start if idle or error
                 if matches!(current_state, RecordingState::Idle | RecordingState::Error) {
                     log::info!("PTT mode: Key pressed - starting recording");
-                    start_recording(app.clone(), recorder.clone(), state_manager.clone()).await?;
+                    start_recording(app.clone(), recorder.clone(), state_manager.clone(), None)
+                        .await?;
                 }
             }
             ShortcutState::Released => {
@@ -89,3 +89,36 @@ pub fn parse_shortcut(shortcut_str: &str) -> Option<Shortcut> {
 
     Some(Shortcut::new(Some(modifiers), code))
 }
+
+/// The voice input shortcut with Shift added, which starts a dictation
+/// translated into English
+///
+/// Returns `None` if the voice input shortcut already uses Shift.
+pub fn translate_shortcut(voice_shortcut: &Shortcut) -> Option<Shortcut> {
+    if voice_shortcut.mods.contains(Modifiers::SHIFT) {
+        return None;
+    }
+
+    Some(Shortcut::new(
+        Some(voice_shortcut.mods | Modifiers::SHIFT),
+        voice_shortcut.key,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate_shortcut_adds_shift() {
+        let voice = parse_shortcut("Alt+Space").unwrap();
+        let translate = translate_shortcut(&voice).unwrap();
+
+        assert_eq!(translate, parse_shortcut("Alt+Shift+Space").unwrap());
+        assert_ne!(translate.id(), voice.id());
+
+        // Nothing left to add when the shortcut already uses Shift
+        let shifted = parse_shortcut("Ctrl+Shift+K").unwrap();
+        assert_eq!(translate_shortcut(&shifted), None);
+    }
+}
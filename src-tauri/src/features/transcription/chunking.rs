@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use ts_rs::TS;
+
+use super::providers::TranscriptionResponse;
+use crate::features::audio::normalize::TARGET_SAMPLE_RATE;
+
+/// Longest chunk sent in one request unless a provider asks for less
+///
+/// Ten minutes of 16kHz 16-bit mono WAV is ~19 MB, below OpenAI's 25 MB
+/// upload limit.
+pub const DEFAULT_MAX_CHUNK_SECS: f64 = 600.0;
+
+/// Chunks transcribed at once by cloud providers
+pub const MAX_PARALLEL_CHUNKS: usize = 3;
+
+/// Frame length used to find the quietest place to cut
+const SILENCE_FRAME_SECS: f64 = 0.1;
+
+/// Progress of a recording transcribed in several chunks
+///
+/// Emitted as `transcription-progress` after each chunk finishes.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionProgress {
+    pub model_id: String,
+    pub completed_chunks: usize,
+    pub total_chunks: usize,
+}
+
+/// Split mono 16kHz audio into chunks of at most `max_chunk_secs`
+///
+/// Each cut is placed at the quietest frame in the last quarter of the
+/// chunk, so words are rarely split. Ranges are contiguous and cover all
+/// samples.
+pub fn split_on_silence(samples: &[f32], max_chunk_secs: f64) -> Vec<Range<usize>> {
+    let max_len = (max_chunk_secs * TARGET_SAMPLE_RATE as f64) as usize;
+    let frame_len = (SILENCE_FRAME_SECS * TARGET_SAMPLE_RATE as f64) as usize;
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    // Limits shorter than a frame leave no room to look for silence
+    while max_len >= frame_len && samples.len() - start > max_len {
+        let end = start + max_len;
+        let search_start = end - max_len / 4;
+        let search_end = end.saturating_sub(frame_len);
+
+        let cut = (search_start..=search_end)
+            .step_by(frame_len)
+            .min_by(|&a, &b| {
+                frame_energy(&samples[a..a + frame_len])
+                    .total_cmp(&frame_energy(&samples[b..b + frame_len]))
+            })
+            .map(|frame_start| frame_start + frame_len / 2)
+            .unwrap_or(end);
+
+        chunks.push(start..cut);
+        start = cut;
+    }
+
+    chunks.push(start..samples.len());
+    chunks
+}
+
+/// Mean squared amplitude of a frame
+fn frame_energy(frame: &[f32]) -> f32 {
+    frame.iter().map(|s| s * s).sum::<f32>() / frame.len().max(1) as f32
+}
+
+/// Join chunk transcripts into one, shifting timings by each chunk's offset
+///
+/// `parts` are `(offset in seconds, response)` in audio order. Speaker ids
+/// are prefixed with the chunk index: providers number speakers per request,
+/// so the same id in two chunks isn't necessarily the same person.
+pub fn stitch_responses(parts: Vec<(f64, TranscriptionResponse)>) -> TranscriptionResponse {
+    let mut texts: Vec<String> = Vec::new();
+    let mut language = None;
+    let mut segments = Vec::new();
+    let mut words = Vec::new();
+    let mut has_words = false;
+
+    for (index, (offset, response)) in parts.into_iter().enumerate() {
+        let text = response.text.trim();
+        if !text.is_empty() {
+            texts.push(text.to_string());
+        }
+
+        language = language.or(response.language);
+
+        let speaker = |id: Option<String>| id.map(|id| format!("{}:{}", index, id));
+
+        for mut segment in response.segments.unwrap_or_default() {
+            segment.start += offset;
+            segment.end += offset;
+            segment.speaker = speaker(segment.speaker);
+            segments.push(segment);
+        }
+
+        if let Some(chunk_words) = response.words {
+            has_words = true;
+            for mut word in chunk_words {
+                word.start += offset;
+                word.end += offset;
+                word.speaker = speaker(word.speaker);
+                words.push(word);
+            }
+        }
+    }
+
+    TranscriptionResponse {
+        text: texts.join(" "),
+        language,
+        segments: Some(segments),
+        words: has_words.then_some(words),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::providers::{TranscriptionSegment, TranscriptionWord};
+    use super::*;
+
+    #[test]
+    fn test_split_cuts_in_silence() {
+        let rate = TARGET_SAMPLE_RATE as usize;
+
+        // 10s of tone with a short pause at 4.5s and at 8.5s
+        let mut samples: Vec<f32> = (0..rate * 10)
+            .map(|i| (i as f32 * 0.1).sin() * 0.5)
+            .collect();
+        for pause in [4.5, 8.5] {
+            let at = (pause * rate as f64) as usize;
+            samples[at - rate / 10..at + rate / 10].fill(0.0);
+        }
+
+        let chunks = split_on_silence(&samples, 5.0);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].start, 0);
+        assert_eq!(chunks[2].end, samples.len());
+        assert!(chunks.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert!(chunks.iter().all(|chunk| chunk.len() <= rate * 5));
+
+        // Each cut lands inside a pause
+        for (chunk, pause) in chunks.iter().zip([4.5, 8.5]) {
+            let cut = chunk.end as f64 / rate as f64;
+            assert!((cut - pause).abs() < 0.1, "cut at {}", cut);
+        }
+
+        let short = split_on_silence(&samples[..rate], 5.0);
+        assert_eq!(short.len(), 1);
+        assert_eq!(short[0], 0..rate);
+    }
+
+    #[test]
+    fn test_stitch_offsets_timings() {
+        let part = |text: &str, speaker: &str| TranscriptionResponse {
+            text: format!(" {} ", text),
+            language: Some("en".to_string()),
+            segments: Some(vec![TranscriptionSegment {
+                start: 1.0,
+                end: 2.0,
+                text: text.to_string(),
+                speaker: Some(speaker.to_string()),
+            }]),
+            words: Some(vec![TranscriptionWord {
+                start: 1.0,
+                end: 1.5,
+                word: text.to_string(),
+                speaker: None,
+            }]),
+        };
+
+        let stitched =
+            stitch_responses(vec![(0.0, part("Hello", "A")), (300.0, part("again", "A"))]);
+
+        assert_eq!(stitched.text, "Hello again");
+        assert_eq!(stitched.language.as_deref(), Some("en"));
+
+        let segments = stitched.segments.unwrap();
+        assert_eq!(segments[1].start, 301.0);
+        assert_eq!(segments[1].end, 302.0);
+        assert_eq!(segments[0].speaker.as_deref(), Some("0:A"));
+        assert_eq!(segments[1].speaker.as_deref(), Some("1:A"));
+        assert_eq!(stitched.words.unwrap()[1].start, 301.0);
+    }
+}
@@ -0,0 +1,656 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tauri::{command, AppHandle};
+use ts_rs::TS;
+
+use super::language::resolve_language;
+use super::local_model::{load_local_model, loaded_local_model, restore_local_model};
+use super::orchestrator::{find_model, transcribe_with_provider, SelectedModel};
+use super::orchestrator_helpers::{get_model_name, get_vocabulary_words};
+use super::providers::TranscriptionOptions;
+use crate::features::audio::NormalizedAudio;
+use crate::features::models::get_all_models;
+use crate::features::recordings::metadata::RecordingStatus;
+use crate::features::recordings::storage::{get_recordings_dir, read_metadata};
+use crate::utils::logger;
+
+/// Audio to run through each model in `compare_models`
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ComparisonSource {
+    /// A saved recording, optionally with a reference transcript. The
+    /// recording's own transcript is included as a baseline row.
+    Recording {
+        timestamp: i64,
+        reference: Option<String>,
+    },
+    /// Every `.wav` file in a folder, with `<name>.txt` next to it as the
+    /// reference transcript when present
+    Folder { path: String },
+}
+
+/// Output format for `compare_models`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ComparisonFormat {
+    Json,
+    Markdown,
+}
+
+/// Results of running the same audio through several models
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelComparisonReport {
+    pub created_at: String, // ISO 8601 timestamp
+    pub language: Option<String>,
+    pub samples: Vec<String>,
+    pub models: Vec<ModelComparisonSummary>,
+    pub results: Vec<ComparisonSampleResult>,
+}
+
+/// Aggregate scores for one model across all samples
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelComparisonSummary {
+    pub model_id: String,
+    pub model_name: String,
+    pub provider: String,
+    pub recorded: bool, // Taken from the recording's metadata rather than re-run
+    pub samples: usize,
+    pub failures: usize,
+    pub wer: Option<f64>, // Word error rate over all samples with a reference
+    pub cer: Option<f64>, // Character error rate over all samples with a reference
+    pub avg_latency_ms: Option<f64>,
+    pub real_time_factor: Option<f64>, // Processing time / audio duration
+}
+
+/// One model's transcript of one sample
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonSampleResult {
+    pub sample: String,
+    pub model_id: String,
+    pub recorded: bool,
+    pub text: Option<String>,
+    pub error: Option<String>,
+    pub audio_duration: f64, // Seconds
+    pub latency_ms: Option<u64>,
+    pub wer: Option<f64>,
+    pub cer: Option<f64>,
+}
+
+/// Edit distance against a reference, in words or characters
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct ErrorCounts {
+    errors: usize,
+    total: usize,
+}
+
+impl ErrorCounts {
+    fn rate(&self) -> Option<f64> {
+        if self.total == 0 {
+            None
+        } else {
+            Some(self.errors as f64 / self.total as f64)
+        }
+    }
+
+    fn add(&mut self, other: ErrorCounts) {
+        self.errors += other.errors;
+        self.total += other.total;
+    }
+}
+
+struct ComparisonSample {
+    name: String,
+    audio: NormalizedAudio,
+    reference: Option<String>,
+}
+
+/// Running totals for one row of the summary table
+struct ModelTotals {
+    model_id: String,
+    model_name: String,
+    provider: String,
+    recorded: bool,
+    samples: usize,
+    failures: usize,
+    words: ErrorCounts,
+    chars: ErrorCounts,
+    latency_ms: u64,
+    audio_secs: f64,
+}
+
+impl ModelTotals {
+    fn new(model_id: String, model_name: String, provider: String, recorded: bool) -> Self {
+        Self {
+            model_id,
+            model_name,
+            provider,
+            recorded,
+            samples: 0,
+            failures: 0,
+            words: ErrorCounts::default(),
+            chars: ErrorCounts::default(),
+            latency_ms: 0,
+            audio_secs: 0.0,
+        }
+    }
+
+    /// Record one transcript and return the row for it
+    fn record(
+        &mut self,
+        sample: &ComparisonSample,
+        outcome: Result<(String, u64), String>,
+    ) -> ComparisonSampleResult {
+        self.samples += 1;
+        let audio_duration = sample.audio.duration_secs();
+
+        let mut result = ComparisonSampleResult {
+            sample: sample.name.clone(),
+            model_id: self.model_id.clone(),
+            recorded: self.recorded,
+            text: None,
+            error: None,
+            audio_duration,
+            latency_ms: None,
+            wer: None,
+            cer: None,
+        };
+
+        match outcome {
+            Ok((text, latency_ms)) => {
+                self.latency_ms += latency_ms;
+                self.audio_secs += audio_duration;
+
+                if let Some(reference) = &sample.reference {
+                    let (words, chars) = score(reference, &text);
+                    self.words.add(words);
+                    self.chars.add(chars);
+                    result.wer = words.rate();
+                    result.cer = chars.rate();
+                }
+
+                result.text = Some(text);
+                result.latency_ms = Some(latency_ms);
+            }
+            Err(e) => {
+                self.failures += 1;
+                result.error = Some(e);
+            }
+        }
+
+        result
+    }
+
+    fn summary(&self) -> ModelComparisonSummary {
+        let succeeded = self.samples - self.failures;
+
+        ModelComparisonSummary {
+            model_id: self.model_id.clone(),
+            model_name: self.model_name.clone(),
+            provider: self.provider.clone(),
+            recorded: self.recorded,
+            samples: self.samples,
+            failures: self.failures,
+            wer: self.words.rate(),
+            cer: self.chars.rate(),
+            avg_latency_ms: (succeeded > 0).then(|| self.latency_ms as f64 / succeeded as f64),
+            real_time_factor: (self.audio_secs > 0.0)
+                .then(|| self.latency_ms as f64 / 1000.0 / self.audio_secs),
+        }
+    }
+}
+
+/// Run the same audio through several models and score the transcripts
+///
+/// Reports word/character error rate (when a reference transcript is
+/// available), average latency and real-time factor per model, as either
+/// JSON or Markdown tables. Local models are loaded in turn and the
+/// previously loaded model is restored afterwards; a local model that hasn't
+/// been downloaded is reported as such on each of its rows.
+#[command]
+pub async fn compare_models(
+    app: AppHandle,
+    source: ComparisonSource,
+    model_ids: Vec<String>,
+    language: Option<String>,
+    format: ComparisonFormat,
+) -> Result<String, String> {
+    if model_ids.is_empty() {
+        return Err("No models selected for comparison".to_string());
+    }
+
+    let (samples, baseline, recording_language) = load_samples(&app, &source)?;
+    let language = language
+        .or(recording_language)
+        .as_deref()
+        .and_then(resolve_language);
+
+    let models = model_ids
+        .iter()
+        .map(|id| find_model(&app, id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let model_names = get_all_models(app.clone()).await?;
+    let vocabulary = get_vocabulary_words(&app)?.unwrap_or_default();
+
+    let previous_local_model = loaded_local_model(&app).await;
+
+    let mut totals = Vec::new();
+    let mut results = Vec::new();
+
+    if let Some((model_totals, result)) = baseline {
+        totals.push(model_totals);
+        results.push(result);
+    }
+
+    for model in &models {
+        let model_name = model_names
+            .iter()
+            .find(|m| m.id == model.id)
+            .map(|m| m.name.clone())
+            .unwrap_or_else(|| get_model_name(model));
+        let mut model_totals =
+            ModelTotals::new(model.id.clone(), model_name, model.provider.clone(), false);
+
+        logger::info(&format!(
+            "Comparing {} on {} sample(s)",
+            model.id,
+            samples.len()
+        ));
+
+        let loaded = load_local_model(&app, model).await;
+
+        for sample in &samples {
+            let outcome = match &loaded {
+                Ok(()) => {
+                    let started = Instant::now();
+                    transcribe_with_provider(
+                        &app,
+                        sample.audio.clone(),
+                        model,
+                        language.clone(),
+                        vocabulary.clone(),
+                        TranscriptionOptions::default(),
+                    )
+                    .await
+                    .map(|response| {
+                        (
+                            response.text.trim().to_string(),
+                            started.elapsed().as_millis() as u64,
+                        )
+                    })
+                }
+                Err(e) => Err(e.clone()),
+            };
+
+            results.push(model_totals.record(sample, outcome));
+        }
+
+        totals.push(model_totals);
+    }
+
+    restore_local_model(&app, previous_local_model).await;
+
+    let report = ModelComparisonReport {
+        created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
+        language,
+        samples: samples.iter().map(|s| s.name.clone()).collect(),
+        models: totals.iter().map(ModelTotals::summary).collect(),
+        results,
+    };
+
+    match format {
+        ComparisonFormat::Json => serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Failed to serialize comparison report: {}", e)),
+        ComparisonFormat::Markdown => Ok(render_markdown(&report)),
+    }
+}
+
+/// Read the audio (and reference transcripts) for a comparison
+///
+/// For a recording this also returns its stored transcript as a baseline
+/// row and the language it was recorded with.
+#[allow(clippy::type_complexity)]
+fn load_samples(
+    app: &AppHandle,
+    source: &ComparisonSource,
+) -> Result<
+    (
+        Vec<ComparisonSample>,
+        Option<(ModelTotals, ComparisonSampleResult)>,
+        Option<String>,
+    ),
+    String,
+> {
+    match source {
+        ComparisonSource::Recording {
+            timestamp,
+            reference,
+        } => {
+            let recording_folder = get_recordings_dir(app)?.join(timestamp.to_string());
+            if !recording_folder.exists() {
+                return Err(format!("Recording not found: {}", timestamp));
+            }
+
+            let metadata = read_metadata(&recording_folder)?;
+            let audio_data = fs::read(recording_folder.join("audio.wav"))
+                .map_err(|e| format!("Failed to read audio file: {}", e))?;
+
+            let sample = ComparisonSample {
+                name: metadata.datetime.clone(),
+                audio: NormalizedAudio::from_wav(&audio_data)?,
+                reference: reference.clone().filter(|r| !r.trim().is_empty()),
+            };
+
+            let baseline = (metadata.status == RecordingStatus::Completed).then(|| {
+                let mut totals = ModelTotals::new(
+                    metadata.model_key.clone(),
+                    metadata.model_name.clone(),
+                    metadata.provider.clone(),
+                    true,
+                );
+                let result = totals.record(
+                    &sample,
+                    Ok((metadata.raw_result.clone(), metadata.processing_time)),
+                );
+                (totals, result)
+            });
+
+            Ok((vec![sample], baseline, Some(metadata.language_selected)))
+        }
+        ComparisonSource::Folder { path } => {
+            let samples = read_sample_folder(Path::new(path))?;
+            Ok((samples, None, None))
+        }
+    }
+}
+
+/// Load every `.wav` in a folder, sorted by name
+fn read_sample_folder(folder: &Path) -> Result<Vec<ComparisonSample>, String> {
+    let mut wav_files: Vec<PathBuf> = fs::read_dir(folder)
+        .map_err(|e| format!("Failed to read folder {}: {}", folder.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"))
+        })
+        .collect();
+    wav_files.sort();
+
+    if wav_files.is_empty() {
+        return Err(format!("No WAV files found in {}", folder.display()));
+    }
+
+    wav_files
+        .into_iter()
+        .map(|path| {
+            let audio_data =
+                fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            let audio = NormalizedAudio::from_wav(&audio_data)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+            let reference = fs::read_to_string(path.with_extension("txt"))
+                .ok()
+                .filter(|r| !r.trim().is_empty());
+
+            Ok(ComparisonSample {
+                name: path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                audio,
+                reference,
+            })
+        })
+        .collect()
+}
+
+/// Lowercase words with punctuation removed, so formatting isn't scored
+fn normalize_words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '\'' {
+                c
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Levenshtein distance between two sequences
+fn edit_distance<T: PartialEq>(reference: &[T], hypothesis: &[T]) -> usize {
+    let mut previous: Vec<usize> = (0..=hypothesis.len()).collect();
+    let mut current = vec![0; hypothesis.len() + 1];
+
+    for (i, r) in reference.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, h) in hypothesis.iter().enumerate() {
+            let substitution = previous[j] + usize::from(r != h);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[hypothesis.len()]
+}
+
+/// Word and character errors of a transcript against its reference
+fn score(reference: &str, hypothesis: &str) -> (ErrorCounts, ErrorCounts) {
+    let reference_words = normalize_words(reference);
+    let hypothesis_words = normalize_words(hypothesis);
+
+    let reference_chars: Vec<char> = reference_words.join(" ").chars().collect();
+    let hypothesis_chars: Vec<char> = hypothesis_words.join(" ").chars().collect();
+
+    (
+        ErrorCounts {
+            errors: edit_distance(&reference_words, &hypothesis_words),
+            total: reference_words.len(),
+        },
+        ErrorCounts {
+            errors: edit_distance(&reference_chars, &hypothesis_chars),
+            total: reference_chars.len(),
+        },
+    )
+}
+
+fn render_markdown(report: &ModelComparisonReport) -> String {
+    let mut out = String::from("# Model comparison\n\n");
+    out.push_str(&format!(
+        "{} sample(s), language: {}, generated {}\n\n",
+        report.samples.len(),
+        report.language.as_deref().unwrap_or("auto"),
+        report.created_at
+    ));
+
+    out.push_str("| Model | Provider | WER | CER | Avg latency | RTF | Failures |\n");
+    out.push_str("|---|---|---|---|---|---|---|\n");
+    for model in &report.models {
+        out.push_str(&format!(
+            "| {}{} | {} | {} | {} | {} | {} | {}/{} |\n",
+            model.model_name,
+            if model.recorded { " (recorded)" } else { "" },
+            model.provider,
+            format_rate(model.wer),
+            format_rate(model.cer),
+            model
+                .avg_latency_ms
+                .map(|ms| format!("{:.0} ms", ms))
+                .unwrap_or_else(|| "-".to_string()),
+            model
+                .real_time_factor
+                .map(|rtf| format!("{:.2}", rtf))
+                .unwrap_or_else(|| "-".to_string()),
+            model.failures,
+            model.samples
+        ));
+    }
+
+    out.push_str("\n## Samples\n\n");
+    out.push_str("| Sample | Model | WER | Latency | Transcript |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for result in &report.results {
+        let transcript = match (&result.text, &result.error) {
+            (Some(text), _) => text.clone(),
+            (None, Some(error)) => format!("**Error:** {}", error),
+            (None, None) => String::new(),
+        };
+        out.push_str(&format!(
+            "| {} | {}{} | {} | {} | {} |\n",
+            result.sample,
+            result.model_id,
+            if result.recorded { " (recorded)" } else { "" },
+            format_rate(result.wer),
+            result
+                .latency_ms
+                .map(|ms| format!("{} ms", ms))
+                .unwrap_or_else(|| "-".to_string()),
+            transcript.replace('|', "\\|").replace('\n', " ")
+        ));
+    }
+
+    out
+}
+
+fn format_rate(rate: Option<f64>) -> String {
+    rate.map(|r| format!("{:.1}%", r * 100.0))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_error_rate_ignores_case_and_punctuation() {
+        let (words, _) = score("Hello, world! How are you?", "hello world how are you");
+        assert_eq!(
+            words,
+            ErrorCounts {
+                errors: 0,
+                total: 5
+            }
+        );
+
+        // One substitution, one deletion
+        let (words, _) = score("the cat sat on the mat", "the hat sat on mat");
+        assert_eq!(
+            words,
+            ErrorCounts {
+                errors: 2,
+                total: 6
+            }
+        );
+        assert!((words.rate().unwrap() - 2.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_character_error_rate() {
+        let (_, chars) = score("kitten", "sitting");
+        assert_eq!(
+            chars,
+            ErrorCounts {
+                errors: 3,
+                total: 6
+            }
+        );
+
+        let (_, chars) = score("", "anything");
+        assert_eq!(chars.rate(), None);
+    }
+
+    #[test]
+    fn test_markdown_escapes_transcripts() {
+        let report = ModelComparisonReport {
+            created_at: "2024-01-01T00:00:00".to_string(),
+            language: Some("en".to_string()),
+            samples: vec!["a.wav".to_string()],
+            models: vec![ModelComparisonSummary {
+                model_id: "whisper-base".to_string(),
+                model_name: "Whisper Base".to_string(),
+                provider: "local-whisper".to_string(),
+                recorded: false,
+                samples: 1,
+                failures: 0,
+                wer: Some(0.125),
+                cer: None,
+                avg_latency_ms: Some(820.0),
+                real_time_factor: Some(0.21),
+            }],
+            results: vec![ComparisonSampleResult {
+                sample: "a.wav".to_string(),
+                model_id: "whisper-base".to_string(),
+                recorded: false,
+                text: Some("a | b".to_string()),
+                error: None,
+                audio_duration: 4.0,
+                latency_ms: Some(820),
+                wer: Some(0.125),
+                cer: None,
+            }],
+        };
+
+        let markdown = render_markdown(&report);
+        assert!(
+            markdown.contains("| Whisper Base | local-whisper | 12.5% | - | 820 ms | 0.21 | 0/1 |")
+        );
+        assert!(markdown.contains("| a.wav | whisper-base | 12.5% | 820 ms | a \\| b |"));
+    }
+
+    #[test]
+    fn test_not_downloaded_model_is_not_scored() {
+        let sample = ComparisonSample {
+            name: "a.wav".to_string(),
+            audio: NormalizedAudio {
+                samples: vec![0.0; 16_000],
+            },
+            reference: Some("hello world".to_string()),
+        };
+        let mut totals = ModelTotals::new(
+            "whisper-small".to_string(),
+            "Whisper Small".to_string(),
+            "local-whisper".to_string(),
+            false,
+        );
+
+        let row = totals.record(&sample, Err("whisper-small is not downloaded".to_string()));
+        assert_eq!(
+            row.error.as_deref(),
+            Some("whisper-small is not downloaded")
+        );
+        assert_eq!((row.text, row.wer, row.cer), (None, None, None));
+
+        let summary = totals.summary();
+        assert_eq!((summary.samples, summary.failures), (1, 1));
+        assert_eq!((summary.wer, summary.avg_latency_ms), (None, None));
+    }
+}
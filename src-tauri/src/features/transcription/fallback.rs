@@ -0,0 +1,233 @@
+use std::future::Future;
+use std::time::Duration;
+
+use crate::utils::logger;
+
+/// Attempts per model before moving on to the next one in the chain
+pub const MAX_ATTEMPTS_PER_MODEL: u32 = 3;
+
+/// Delay before the first retry; doubled for each retry after that
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound for a single backoff delay
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+/// Retry limits for a fallback chain
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: MAX_ATTEMPTS_PER_MODEL,
+            initial_backoff: INITIAL_BACKOFF,
+            max_backoff: MAX_BACKOFF,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `retry` (1-based): 500ms, 1s, 2s, ...
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Result of a successful run through the chain
+#[derive(Debug)]
+pub struct FallbackOutcome<C, T> {
+    pub value: T,
+    /// The candidate that produced the value
+    pub candidate: C,
+    /// Total attempts across every candidate, including the successful one
+    pub attempts: u32,
+}
+
+/// Whether a provider error is worth retrying
+///
+/// Providers report errors as strings, so this matches the shared formats:
+/// "Failed to send request ..." for network failures and timeouts, and
+/// "... API error (<status>" / "... failed with status <status>" for HTTP
+/// errors. 5xx, 408 and 429 responses are transient; everything else
+/// (bad key, unsupported audio, missing model) fails the candidate at once.
+pub fn is_transient_error(error: &str) -> bool {
+    let lower = error.to_lowercase();
+    if lower.starts_with("failed to send request") || lower.contains("timed out") {
+        return true;
+    }
+
+    ["api error (", "failed with status "]
+        .iter()
+        .filter_map(|marker| lower.find(marker).map(|i| &lower[i + marker.len()..]))
+        .filter_map(|rest| rest.get(..3).and_then(|code| code.parse::<u16>().ok()))
+        .any(|status| status >= 500 || status == 408 || status == 429)
+}
+
+/// Try each candidate in order, retrying transient failures with backoff
+///
+/// `attempt` is called with the candidate and the 1-based attempt number for
+/// that candidate. Non-transient errors skip straight to the next candidate.
+/// When every candidate fails, the error lists each candidate's last error.
+pub async fn run_with_fallback<C, T, F, Fut>(
+    candidates: Vec<C>,
+    policy: RetryPolicy,
+    label: impl Fn(&C) -> String,
+    mut attempt: F,
+) -> Result<FallbackOutcome<C, T>, String>
+where
+    F: FnMut(&C, u32) -> Fut,
+    Fut: Future<Output = Result<T, String>>,
+{
+    if candidates.is_empty() {
+        return Err("No speech-to-text model available".to_string());
+    }
+
+    let mut total_attempts = 0;
+    let mut failures = Vec::new();
+
+    for candidate in candidates {
+        let name = label(&candidate);
+
+        for try_number in 1..=policy.max_attempts.max(1) {
+            total_attempts += 1;
+
+            match attempt(&candidate, try_number).await {
+                Ok(value) => {
+                    return Ok(FallbackOutcome {
+                        value,
+                        candidate,
+                        attempts: total_attempts,
+                    })
+                }
+                Err(e) if is_transient_error(&e) && try_number < policy.max_attempts => {
+                    let delay = policy.backoff(try_number);
+                    logger::warn(&format!(
+                        "{} failed (attempt {}), retrying in {}ms: {}",
+                        name,
+                        try_number,
+                        delay.as_millis(),
+                        e
+                    ));
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => {
+                    logger::warn(&format!("{} failed, trying next model: {}", name, e));
+                    failures.push(format!("{}: {}", name, e));
+                    break;
+                }
+            }
+        }
+    }
+
+    Err(format!(
+        "All speech-to-text models failed ({})",
+        failures.join("; ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn test_is_transient_error() {
+        assert!(is_transient_error(
+            "Failed to send request to OpenAI: operation timed out"
+        ));
+        assert!(is_transient_error(
+            "OpenAI API error (503 Service Unavailable): overloaded"
+        ));
+        assert!(is_transient_error(
+            "ElevenLabs API request failed with status 502 Bad Gateway: "
+        ));
+        assert!(is_transient_error(
+            "Deepgram API error (429 Too Many Requests): slow down"
+        ));
+        assert!(!is_transient_error(
+            "OpenAI API error (401 Unauthorized): bad key"
+        ));
+        assert!(!is_transient_error("Google API error (400): bad audio"));
+        assert!(!is_transient_error("No model loaded"));
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_limit() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(2), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(10), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_retries_then_falls_back() {
+        let calls = RefCell::new(Vec::new());
+
+        let outcome = tauri::async_runtime::block_on(run_with_fallback(
+            vec!["scribe_v1", "whisper-1", "whisper-base"],
+            fast_policy(),
+            |c| c.to_string(),
+            |candidate, try_number| {
+                calls.borrow_mut().push((*candidate, try_number));
+                let result = match *candidate {
+                    "scribe_v1" => {
+                        Err("ElevenLabs API error (500 Internal Server Error): x".into())
+                    }
+                    "whisper-1" => Err("OpenAI API error (401 Unauthorized): bad key".into()),
+                    _ => Ok("hello"),
+                };
+                async move { result }
+            },
+        ))
+        .unwrap();
+
+        assert_eq!(outcome.value, "hello");
+        assert_eq!(outcome.candidate, "whisper-base");
+        assert_eq!(outcome.attempts, 5);
+        assert_eq!(
+            *calls.borrow(),
+            vec![
+                ("scribe_v1", 1),
+                ("scribe_v1", 2),
+                ("scribe_v1", 3),
+                ("whisper-1", 1),
+                ("whisper-base", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_all_candidates_fail() {
+        let error = tauri::async_runtime::block_on(run_with_fallback(
+            vec!["a", "b"],
+            fast_policy(),
+            |c| c.to_string(),
+            |candidate, _| {
+                let message = format!("{} broke", candidate);
+                async move { Err::<(), _>(message) }
+            },
+        ))
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            "All speech-to-text models failed (a: a broke; b: b broke)"
+        );
+    }
+}
@@ -0,0 +1,400 @@
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+/// Setting value that lets the provider detect the spoken language
+pub const AUTO_LANGUAGE: &str = "auto";
+
+/// Whisper (local and OpenAI) can only translate into English
+pub const TRANSLATION_TARGET_LANGUAGE: &str = "en";
+
+/// Language used when settings have never been saved
+const DEFAULT_LANGUAGE: &str = "en";
+
+/// ISO 639-1 code to the BCP-47 tag Google Speech-to-Text expects
+///
+/// Each language maps to its most widely used regional variant.
+const BCP47_TAGS: &[(&str, &str)] = &[
+    ("af", "af-ZA"),
+    ("am", "am-ET"),
+    ("ar", "ar-SA"),
+    ("az", "az-AZ"),
+    ("bg", "bg-BG"),
+    ("bn", "bn-IN"),
+    ("bs", "bs-BA"),
+    ("ca", "ca-ES"),
+    ("cs", "cs-CZ"),
+    ("cy", "cy-GB"),
+    ("da", "da-DK"),
+    ("de", "de-DE"),
+    ("el", "el-GR"),
+    ("en", "en-US"),
+    ("es", "es-ES"),
+    ("et", "et-EE"),
+    ("eu", "eu-ES"),
+    ("fa", "fa-IR"),
+    ("fi", "fi-FI"),
+    ("fil", "fil-PH"),
+    ("fr", "fr-FR"),
+    ("ga", "ga-IE"),
+    ("gl", "gl-ES"),
+    ("gu", "gu-IN"),
+    ("he", "iw-IL"), // Google still uses the legacy Hebrew code
+    ("hi", "hi-IN"),
+    ("hr", "hr-HR"),
+    ("hu", "hu-HU"),
+    ("hy", "hy-AM"),
+    ("id", "id-ID"),
+    ("is", "is-IS"),
+    ("it", "it-IT"),
+    ("ja", "ja-JP"),
+    ("jv", "jv-ID"),
+    ("ka", "ka-GE"),
+    ("kk", "kk-KZ"),
+    ("km", "km-KH"),
+    ("kn", "kn-IN"),
+    ("ko", "ko-KR"),
+    ("lo", "lo-LA"),
+    ("lt", "lt-LT"),
+    ("lv", "lv-LV"),
+    ("mk", "mk-MK"),
+    ("ml", "ml-IN"),
+    ("mn", "mn-MN"),
+    ("mr", "mr-IN"),
+    ("ms", "ms-MY"),
+    ("my", "my-MM"),
+    ("nb", "nb-NO"),
+    ("ne", "ne-NP"),
+    ("nl", "nl-NL"),
+    ("no", "no-NO"),
+    ("pa", "pa-Guru-IN"),
+    ("pl", "pl-PL"),
+    ("pt", "pt-BR"),
+    ("ro", "ro-RO"),
+    ("ru", "ru-RU"),
+    ("si", "si-LK"),
+    ("sk", "sk-SK"),
+    ("sl", "sl-SI"),
+    ("sq", "sq-AL"),
+    ("sr", "sr-RS"),
+    ("su", "su-ID"),
+    ("sv", "sv-SE"),
+    ("sw", "sw-KE"),
+    ("ta", "ta-IN"),
+    ("te", "te-IN"),
+    ("th", "th-TH"),
+    ("tl", "fil-PH"),
+    ("tr", "tr-TR"),
+    ("uk", "uk-UA"),
+    ("ur", "ur-PK"),
+    ("uz", "uz-UZ"),
+    ("vi", "vi-VN"),
+    ("yue", "yue-Hant-HK"),
+    ("zh", "cmn-Hans-CN"),
+    ("zu", "zu-ZA"),
+];
+
+/// ISO 639-3 code (as returned by ElevenLabs) to ISO 639-1
+///
+/// Covers the languages in `BCP47_TAGS`. Languages without a two-letter
+/// code ("fil", "yue") are already stored in their three-letter form.
+const ISO_639_3_CODES: &[(&str, &str)] = &[
+    ("afr", "af"),
+    ("amh", "am"),
+    ("ara", "ar"),
+    ("aze", "az"),
+    ("ben", "bn"),
+    ("bos", "bs"),
+    ("bul", "bg"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("cmn", "zh"),
+    ("cym", "cy"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("est", "et"),
+    ("eus", "eu"),
+    ("fas", "fa"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("gle", "ga"),
+    ("glg", "gl"),
+    ("guj", "gu"),
+    ("heb", "he"),
+    ("hin", "hi"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ind", "id"),
+    ("isl", "is"),
+    ("ita", "it"),
+    ("jav", "jv"),
+    ("jpn", "ja"),
+    ("kan", "kn"),
+    ("kat", "ka"),
+    ("kaz", "kk"),
+    ("khm", "km"),
+    ("kor", "ko"),
+    ("lao", "lo"),
+    ("lav", "lv"),
+    ("lit", "lt"),
+    ("mal", "ml"),
+    ("mar", "mr"),
+    ("mkd", "mk"),
+    ("mon", "mn"),
+    ("msa", "ms"),
+    ("mya", "my"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nob", "nb"),
+    ("nor", "no"),
+    ("pan", "pa"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("ron", "ro"),
+    ("rus", "ru"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slv", "sl"),
+    ("spa", "es"),
+    ("sqi", "sq"),
+    ("srp", "sr"),
+    ("sun", "su"),
+    ("swa", "sw"),
+    ("swe", "sv"),
+    ("tam", "ta"),
+    ("tel", "te"),
+    ("tgl", "tl"),
+    ("tha", "th"),
+    ("tur", "tr"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("uzb", "uz"),
+    ("vie", "vi"),
+    ("zho", "zh"),
+    ("zsm", "ms"),
+    ("zul", "zu"),
+];
+
+/// English language name (as returned by OpenAI's `verbose_json`) to
+/// ISO 639-1
+///
+/// Covers the languages in `BCP47_TAGS`, using Whisper's spelling of each
+/// name ("myanmar" as well as "burmese").
+const LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("afrikaans", "af"),
+    ("albanian", "sq"),
+    ("amharic", "am"),
+    ("arabic", "ar"),
+    ("armenian", "hy"),
+    ("azerbaijani", "az"),
+    ("basque", "eu"),
+    ("bengali", "bn"),
+    ("bosnian", "bs"),
+    ("bulgarian", "bg"),
+    ("burmese", "my"),
+    ("cantonese", "yue"),
+    ("catalan", "ca"),
+    ("chinese", "zh"),
+    ("croatian", "hr"),
+    ("czech", "cs"),
+    ("danish", "da"),
+    ("dutch", "nl"),
+    ("english", "en"),
+    ("estonian", "et"),
+    ("filipino", "fil"),
+    ("finnish", "fi"),
+    ("french", "fr"),
+    ("galician", "gl"),
+    ("georgian", "ka"),
+    ("german", "de"),
+    ("greek", "el"),
+    ("gujarati", "gu"),
+    ("hebrew", "he"),
+    ("hindi", "hi"),
+    ("hungarian", "hu"),
+    ("icelandic", "is"),
+    ("indonesian", "id"),
+    ("irish", "ga"),
+    ("italian", "it"),
+    ("japanese", "ja"),
+    ("javanese", "jv"),
+    ("kannada", "kn"),
+    ("kazakh", "kk"),
+    ("khmer", "km"),
+    ("korean", "ko"),
+    ("lao", "lo"),
+    ("latvian", "lv"),
+    ("lithuanian", "lt"),
+    ("macedonian", "mk"),
+    ("malay", "ms"),
+    ("malayalam", "ml"),
+    ("marathi", "mr"),
+    ("mongolian", "mn"),
+    ("myanmar", "my"),
+    ("nepali", "ne"),
+    ("norwegian", "no"),
+    ("persian", "fa"),
+    ("polish", "pl"),
+    ("portuguese", "pt"),
+    ("punjabi", "pa"),
+    ("romanian", "ro"),
+    ("russian", "ru"),
+    ("serbian", "sr"),
+    ("sinhala", "si"),
+    ("slovak", "sk"),
+    ("slovenian", "sl"),
+    ("spanish", "es"),
+    ("sundanese", "su"),
+    ("swahili", "sw"),
+    ("swedish", "sv"),
+    ("tagalog", "tl"),
+    ("tamil", "ta"),
+    ("telugu", "te"),
+    ("thai", "th"),
+    ("turkish", "tr"),
+    ("ukrainian", "uk"),
+    ("urdu", "ur"),
+    ("uzbek", "uz"),
+    ("vietnamese", "vi"),
+    ("welsh", "cy"),
+    ("zulu", "zu"),
+];
+
+/// Turn a language setting into the code sent to providers
+///
+/// Returns `None` for "auto" (or an empty value) so providers detect the
+/// language themselves. Region suffixes are dropped ("pt-BR" -> "pt").
+pub fn resolve_language(setting: &str) -> Option<String> {
+    let setting = setting.trim();
+    if setting.is_empty() || setting.eq_ignore_ascii_case(AUTO_LANGUAGE) {
+        return None;
+    }
+
+    setting
+        .split(['-', '_'])
+        .next()
+        .map(|code| code.to_lowercase())
+}
+
+/// Read `transcription.language` from settings and resolve it
+pub fn language_from_settings(app: &AppHandle) -> Option<String> {
+    let setting = app
+        .store("settings")
+        .ok()
+        .and_then(|store| store.get("settings"))
+        .and_then(|settings| {
+            settings
+                .get("transcription")
+                .and_then(|t| t.get("language"))
+                .and_then(|v| v.as_str())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string());
+
+    resolve_language(&setting)
+}
+
+/// Map an ISO 639-1 code to a BCP-47 tag (e.g., "es" -> "es-ES")
+///
+/// Codes missing from the table are passed through unchanged, which Google
+/// accepts for languages without regional variants.
+pub fn to_bcp47(code: &str) -> String {
+    // Already a full tag
+    if code.contains('-') {
+        return code.trim().to_string();
+    }
+
+    let code = code.trim().to_lowercase();
+
+    BCP47_TAGS
+        .iter()
+        .find(|(iso, _)| *iso == code)
+        .map(|(_, tag)| tag.to_string())
+        .unwrap_or(code)
+}
+
+/// Map an ISO 639-3 code to the ISO 639-1 code used across the pipeline
+/// (e.g., "spa" -> "es")
+///
+/// Two-letter codes and codes missing from the table are passed through
+/// lowercased.
+pub fn to_iso639_1(code: &str) -> String {
+    let code = code.trim().to_lowercase();
+
+    ISO_639_3_CODES
+        .iter()
+        .find(|(iso3, _)| *iso3 == code)
+        .map(|(_, iso1)| iso1.to_string())
+        .unwrap_or(code)
+}
+
+/// Map a language name or code reported by a provider to ISO 639-1
+/// (e.g., "german" -> "de")
+///
+/// Names missing from the table are treated as codes by `to_iso639_1`.
+pub fn from_language_name(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+
+    LANGUAGE_NAMES
+        .iter()
+        .find(|(language, _)| *language == name)
+        .map(|(_, iso1)| iso1.to_string())
+        .unwrap_or_else(|| to_iso639_1(&name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_language() {
+        assert_eq!(resolve_language("auto"), None);
+        assert_eq!(resolve_language("AUTO"), None);
+        assert_eq!(resolve_language(""), None);
+        assert_eq!(resolve_language("es").as_deref(), Some("es"));
+        assert_eq!(resolve_language("pt-BR").as_deref(), Some("pt"));
+        assert_eq!(resolve_language("zh_TW").as_deref(), Some("zh"));
+    }
+
+    #[test]
+    fn test_to_bcp47() {
+        assert_eq!(to_bcp47("en"), "en-US");
+        assert_eq!(to_bcp47("es"), "es-ES");
+        assert_eq!(to_bcp47("ES"), "es-ES");
+        assert_eq!(to_bcp47("ja"), "ja-JP");
+        assert_eq!(to_bcp47("he"), "iw-IL");
+        assert_eq!(to_bcp47("en-GB"), "en-GB");
+        assert_eq!(to_bcp47("xx"), "xx");
+    }
+
+    #[test]
+    fn test_to_iso639_1() {
+        assert_eq!(to_iso639_1("eng"), "en");
+        assert_eq!(to_iso639_1("SPA"), "es");
+        assert_eq!(to_iso639_1("cmn"), "zh");
+        assert_eq!(to_iso639_1("en"), "en");
+        assert_eq!(to_iso639_1("fil"), "fil");
+        assert_eq!(to_iso639_1("yue"), "yue");
+
+        // Every mapped code has a BCP-47 tag
+        for (_, iso1) in ISO_639_3_CODES {
+            assert!(BCP47_TAGS.iter().any(|(code, _)| code == iso1), "{}", iso1);
+        }
+    }
+
+    #[test]
+    fn test_from_language_name() {
+        assert_eq!(from_language_name("german"), "de");
+        assert_eq!(from_language_name("English"), "en");
+        assert_eq!(from_language_name("cantonese"), "yue");
+        // Servers that already report codes keep working
+        assert_eq!(from_language_name("de"), "de");
+        assert_eq!(from_language_name("spa"), "es");
+
+        for (_, iso1) in LANGUAGE_NAMES {
+            assert!(BCP47_TAGS.iter().any(|(code, _)| code == iso1), "{}", iso1);
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use ts_rs::TS;
+
+use super::language::language_from_settings;
+use super::orchestrator::{find_model, get_settings};
+use super::providers::TranscriptionWord;
+use crate::features::audio::{
+    DictationMode, LiveAudioBuffer, NormalizedAudio, RecordingState, RecordingStateManager,
+};
+use crate::features::models::engines::{whisper::WhisperDecodeOptions, EngineKind};
+use crate::features::models::models_registry::ModelProvider;
+use crate::features::models::LocalModelManager;
+use crate::utils::logger;
+
+/// How often the in-progress audio is transcribed
+const LIVE_INTERVAL: Duration = Duration::from_millis(1000);
+
+/// Windows shorter than this rarely contain a whole word
+const MIN_WINDOW_SECS: f64 = 0.5;
+
+/// Longest window transcribed before older words are committed unconfirmed
+const MAX_WINDOW_SECS: f64 = 15.0;
+
+/// Audio kept in the window when it is cut back to `MAX_WINDOW_SECS`
+const WINDOW_KEEP_SECS: f64 = 5.0;
+
+/// Text recognized so far in a recording that is still in progress
+///
+/// Emitted as `partial-transcript` about once a second while recording with
+/// a local Whisper model. `stable` only ever grows; `unstable` is the tail
+/// the model may still revise. The transcript produced when the recording
+/// stops replaces both.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/features/voice-input/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct PartialTranscript {
+    pub stable: String,
+    pub unstable: String,
+}
+
+/// Commits words once two consecutive passes agree on them
+///
+/// Each pass transcribes the audio after the last committed word. Words at
+/// the start of the new hypothesis that match the previous hypothesis are
+/// committed and the window moves past them; the rest stays unstable.
+#[derive(Debug, Default)]
+struct LocalAgreement {
+    committed: Vec<String>,
+    /// Words of the last hypothesis that weren't committed yet
+    pending: Vec<TranscriptionWord>,
+    /// Where the next window starts, in seconds from the recording start
+    window_start: f64,
+}
+
+/// Lowercase letters and digits only, so punctuation and casing changes
+/// between passes still agree
+fn normalize_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+impl LocalAgreement {
+    /// Take a hypothesis for the window, timed from the recording start
+    fn update(&mut self, hypothesis: Vec<TranscriptionWord>) {
+        // Words heard mostly before the window belong to committed text
+        let mut hypothesis: Vec<TranscriptionWord> = hypothesis
+            .into_iter()
+            .filter(|word| (word.start + word.end) / 2.0 >= self.window_start)
+            .collect();
+
+        let agreed = self
+            .pending
+            .iter()
+            .zip(&hypothesis)
+            .take_while(|(previous, current)| {
+                normalize_word(&previous.word) == normalize_word(&current.word)
+            })
+            .count();
+
+        let agreed_words: Vec<TranscriptionWord> = hypothesis.drain(..agreed).collect();
+        self.commit(agreed_words);
+        self.pending = hypothesis;
+    }
+
+    /// Commit pending words that start before `time` and move the window to
+    /// at least `time`, confirmed or not
+    fn advance_to(&mut self, time: f64) {
+        let count = self
+            .pending
+            .iter()
+            .take_while(|word| word.start < time)
+            .count();
+
+        let words: Vec<TranscriptionWord> = self.pending.drain(..count).collect();
+        self.commit(words);
+        self.window_start = self.window_start.max(time);
+    }
+
+    fn commit(&mut self, words: Vec<TranscriptionWord>) {
+        for word in words {
+            self.window_start = self.window_start.max(word.end);
+            let text = word.word.trim();
+            if !text.is_empty() {
+                self.committed.push(text.to_string());
+            }
+        }
+    }
+
+    fn partial(&self) -> PartialTranscript {
+        PartialTranscript {
+            stable: self.committed.join(" "),
+            unstable: self
+                .pending
+                .iter()
+                .map(|word| word.word.trim())
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+/// Whether the selected speech-to-text model is the loaded local Whisper model
+async fn live_transcription_available(app: &AppHandle) -> bool {
+    let selected = get_settings(app).ok().and_then(|settings| {
+        settings
+            .get("transcription")?
+            .get("speechToTextModelId")?
+            .as_str()
+            .map(String::from)
+    });
+
+    let Some(model) = selected.and_then(|id| find_model(app, &id).ok()) else {
+        return false;
+    };
+    if model.provider != ModelProvider::LocalWhisper.as_str() {
+        return false;
+    }
+
+    match app.try_state::<Arc<tokio::sync::Mutex<LocalModelManager>>>() {
+        Some(manager) => manager
+            .lock()
+            .await
+            .get_loaded_model_info(EngineKind::SpeechToText)
+            .is_some(),
+        None => false,
+    }
+}
+
+/// Transcribe one window with fast decoding settings
+async fn transcribe_window(
+    app: &AppHandle,
+    audio: &NormalizedAudio,
+    language: Option<String>,
+    translate: bool,
+) -> Result<Vec<TranscriptionWord>, String> {
+    let state = app
+        .try_state::<Arc<tokio::sync::Mutex<LocalModelManager>>>()
+        .ok_or("Local model manager not available")?;
+
+    let audio_data = audio.to_wav()?;
+    let mut manager = state.lock().await;
+
+    // Greedy without temperature fallback keeps each pass well under the
+    // interval; the final pass uses the model's own settings
+    let options = manager
+        .get_loaded_model_info(EngineKind::SpeechToText)
+        .ok_or("No speech-to-text model is currently loaded")?
+        .decode_options;
+    let options = WhisperDecodeOptions {
+        beam_size: None,
+        best_of: 1,
+        temperature_increment: 0.0,
+        translate,
+        ..WhisperDecodeOptions::from_value(options)?
+    };
+    let options = serde_json::to_value(&options)
+        .map_err(|e| format!("Failed to serialize decode options: {}", e))?;
+
+    let response = manager.transcribe(audio_data, language, Some(options))?;
+    Ok(response.words.unwrap_or_default())
+}
+
+/// Emit `partial-transcript` events while a local Whisper dictation records
+///
+/// Does nothing unless the selected model is local Whisper and loaded.
+/// Stops when the recording that started at `started_at` is no longer in
+/// progress. The transcript at `stop_recording` stays authoritative.
+pub fn start_live_transcription(
+    app: AppHandle,
+    live_audio: Arc<Mutex<LiveAudioBuffer>>,
+    started_at: i64,
+) {
+    tauri::async_runtime::spawn(async move {
+        if !live_transcription_available(&app).await {
+            return;
+        }
+
+        let state_manager = app.state::<Arc<RecordingStateManager>>().inner().clone();
+        let language = language_from_settings(&app);
+        let translate = state_manager.get_dictation_mode() == DictationMode::Translate;
+
+        let mut agreement = LocalAgreement::default();
+        let mut last_emitted = PartialTranscript::default();
+
+        loop {
+            tokio::time::sleep(LIVE_INTERVAL).await;
+
+            if state_manager.get_state() != RecordingState::Recording
+                || state_manager.get_start_time() != Some(started_at)
+            {
+                break;
+            }
+
+            let (sample_rate, window_offset, samples) = {
+                let Ok(buffer) = live_audio.lock() else {
+                    break;
+                };
+                let rate = buffer.sample_rate() as f64;
+                let (start, samples) = buffer.since((agreement.window_start * rate) as u64);
+                (rate, start as f64 / rate, samples)
+            };
+
+            let window_secs = samples.len() as f64 / sample_rate;
+            if window_secs < MIN_WINDOW_SECS {
+                continue;
+            }
+
+            let audio = match NormalizedAudio::from_samples(samples, sample_rate as u32, 1) {
+                Ok(audio) => audio,
+                Err(e) => {
+                    logger::warn(&format!("Live transcription stopped: {}", e));
+                    break;
+                }
+            };
+
+            match transcribe_window(&app, &audio, language.clone(), translate).await {
+                Ok(words) => agreement.update(
+                    words
+                        .into_iter()
+                        .map(|mut word| {
+                            word.start += window_offset;
+                            word.end += window_offset;
+                            word
+                        })
+                        .collect(),
+                ),
+                Err(e) => {
+                    logger::warn(&format!("Live transcription stopped: {}", e));
+                    break;
+                }
+            }
+
+            // Keep passes fast by committing older words unconfirmed
+            if window_secs > MAX_WINDOW_SECS {
+                agreement.advance_to(window_offset + window_secs - WINDOW_KEEP_SECS);
+            }
+
+            let partial = agreement.partial();
+            if partial != last_emitted {
+                let _ = app.emit("partial-transcript", &partial);
+                last_emitted = partial;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[(&str, f64, f64)]) -> Vec<TranscriptionWord> {
+        list.iter()
+            .map(|&(word, start, end)| TranscriptionWord {
+                start,
+                end,
+                word: word.to_string(),
+                speaker: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_agreement_commits_confirmed_prefix() {
+        let mut agreement = LocalAgreement::default();
+
+        agreement.update(words(&[("Hello", 0.0, 0.4), ("word", 0.5, 0.8)]));
+        assert_eq!(agreement.partial().stable, "");
+        assert_eq!(agreement.partial().unstable, "Hello word");
+
+        // "hello" is confirmed; the second word was revised
+        agreement.update(words(&[
+            ("hello,", 0.0, 0.4),
+            ("world", 0.5, 0.9),
+            ("again", 1.0, 1.3),
+        ]));
+        assert_eq!(
+            agreement.partial(),
+            PartialTranscript {
+                stable: "hello,".to_string(),
+                unstable: "world again".to_string(),
+            }
+        );
+        assert_eq!(agreement.window_start, 0.4);
+
+        // The next window starts after "hello"; a straddling word is dropped
+        agreement.update(words(&[
+            ("lo", 0.2, 0.45),
+            ("world", 0.5, 0.9),
+            ("again.", 1.0, 1.3),
+        ]));
+        assert_eq!(agreement.partial().stable, "hello, world again.");
+        assert_eq!(agreement.partial().unstable, "");
+        assert_eq!(agreement.window_start, 1.3);
+    }
+
+    #[test]
+    fn test_advance_commits_unconfirmed_words() {
+        let mut agreement = LocalAgreement::default();
+        agreement.update(words(&[("one", 1.0, 1.5), ("two", 11.0, 11.5)]));
+
+        agreement.advance_to(10.0);
+        assert_eq!(agreement.partial().stable, "one");
+        assert_eq!(agreement.partial().unstable, "two");
+        assert_eq!(agreement.window_start, 10.0);
+
+        // Silence moves the window without committing anything
+        let mut silent = LocalAgreement::default();
+        silent.advance_to(10.0);
+        assert_eq!(silent.partial(), PartialTranscript::default());
+        assert_eq!(silent.window_start, 10.0);
+    }
+}
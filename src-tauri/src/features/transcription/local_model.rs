@@ -0,0 +1,155 @@
+use tauri::{AppHandle, Manager};
+
+use super::orchestrator::SelectedModel;
+use super::providers::TranscriptionProviderRegistry;
+use crate::features::models::engines::{EngineKind, ModelConfig, ModelInfo};
+use crate::features::models::local_model_commands::{get_decode_options, LocalModelState};
+use crate::features::models::LocalModelManager;
+use crate::utils::logger;
+
+/// Engine that runs local speech-to-text models
+const LOCAL_ENGINE: &str = "whisper";
+
+/// Whether `model` has to be loaded before it can transcribe
+///
+/// Fails for a model that hasn't been downloaded, so another model's output
+/// is never credited to it.
+fn needs_load(loaded: Option<&ModelInfo>, model: &SelectedModel) -> Result<bool, String> {
+    let path = model
+        .path
+        .as_ref()
+        .ok_or_else(|| format!("{} is not downloaded", model.id))?;
+
+    Ok(!matches!(loaded, Some(info) if &info.path == path))
+}
+
+/// Load `model` into the manager unless it is already the loaded model
+///
+/// Called with the manager locked, right before transcribing, so the
+/// transcript always comes from the model it is recorded under.
+pub fn ensure_loaded(
+    app: &AppHandle,
+    manager: &mut LocalModelManager,
+    model: &SelectedModel,
+) -> Result<(), String> {
+    let loaded = manager.get_loaded_model_info(EngineKind::SpeechToText);
+    if !needs_load(loaded.as_ref(), model)? {
+        return Ok(());
+    }
+
+    logger::info(&format!("Loading local model {}", model.id));
+
+    manager
+        .load_model(
+            LOCAL_ENGINE,
+            ModelConfig {
+                model_path: model.path.clone().unwrap_or_default(),
+                // Same name `start_local_model` uses ("whisper-base" -> "base")
+                model_name: model
+                    .id
+                    .strip_prefix("whisper-")
+                    .unwrap_or(&model.id)
+                    .to_string(),
+                language: None,
+                decode_options: get_decode_options(app, &model.id),
+            },
+        )
+        .map_err(|e| format!("Failed to load {}: {}", model.id, e))
+}
+
+/// Whether the model runs on a local engine rather than a cloud API
+pub fn is_local_model(app: &AppHandle, model: &SelectedModel) -> Result<bool, String> {
+    let registry = app.state::<TranscriptionProviderRegistry>();
+    Ok(registry.get(&model.provider)?.requires_download())
+}
+
+/// Load a local model ahead of time (no-op for cloud models)
+pub async fn load_local_model(app: &AppHandle, model: &SelectedModel) -> Result<(), String> {
+    if !is_local_model(app, model)? {
+        return Ok(());
+    }
+
+    let state = app.state::<LocalModelState>();
+    let mut manager = state.lock().await;
+    ensure_loaded(app, &mut manager, model)
+}
+
+/// The speech-to-text model loaded right now, for `restore_local_model`
+pub async fn loaded_local_model(app: &AppHandle) -> Option<ModelInfo> {
+    let state = app.state::<LocalModelState>();
+    let manager = state.lock().await;
+    manager.get_loaded_model_info(EngineKind::SpeechToText)
+}
+
+/// Put back whichever local model was loaded before
+pub async fn restore_local_model(app: &AppHandle, previous: Option<ModelInfo>) {
+    let state = app.state::<LocalModelState>();
+    let mut manager = state.lock().await;
+
+    let current_path = manager
+        .get_loaded_model_info(EngineKind::SpeechToText)
+        .map(|info| info.path);
+    if current_path == previous.as_ref().map(|info| info.path.clone()) {
+        return;
+    }
+
+    match previous {
+        Some(info) => {
+            let reloaded = manager.load_model(
+                &info.engine_type,
+                ModelConfig {
+                    model_path: info.path,
+                    model_name: info.name,
+                    language: None,
+                    decode_options: info.decode_options,
+                },
+            );
+            if let Err(e) = reloaded {
+                logger::warn(&format!("Failed to restore local model: {}", e));
+            }
+        }
+        None => manager.unload_model(EngineKind::SpeechToText),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(id: &str, path: Option<&str>) -> SelectedModel {
+        SelectedModel {
+            id: id.to_string(),
+            provider: "local-whisper".to_string(),
+            path: path.map(String::from),
+        }
+    }
+
+    fn loaded(name: &str, path: &str) -> ModelInfo {
+        ModelInfo {
+            name: name.to_string(),
+            path: path.to_string(),
+            engine_type: LOCAL_ENGINE.to_string(),
+            decode_options: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_requested_model_replaces_loaded_one() {
+        let tiny = loaded("tiny", "/models/whisper/ggml-tiny.bin");
+        let medium = model("whisper-medium", Some("/models/whisper/ggml-medium.bin"));
+
+        // Re-transcribing with medium while tiny is loaded must load medium
+        assert_eq!(needs_load(Some(&tiny), &medium), Ok(true));
+        assert_eq!(needs_load(None, &medium), Ok(true));
+
+        let loaded_medium = loaded("medium", "/models/whisper/ggml-medium.bin");
+        assert_eq!(needs_load(Some(&loaded_medium), &medium), Ok(false));
+
+        // A model that isn't downloaded never borrows the loaded model
+        let not_downloaded = model("whisper-small", None);
+        assert_eq!(
+            needs_load(Some(&tiny), &not_downloaded),
+            Err("whisper-small is not downloaded".to_string())
+        );
+    }
+}
@@ -1,6 +1,18 @@
+pub mod chunking;
+pub mod comparison;
+pub mod fallback;
+pub mod language;
+pub mod live;
+pub mod local_model;
 pub mod orchestrator;
 pub mod orchestrator_helpers;
 pub mod providers;
+pub mod retranscribe;
+pub mod retry_queue;
 
+pub use comparison::{compare_models, ComparisonFormat, ComparisonSource};
+pub use live::start_live_transcription;
 pub use orchestrator::{get_last_transcript, paste_last_transcript, transcribe_and_process};
-pub use providers::TranscriptionResponse;
+pub use providers::{TranscriptionProviderRegistry, TranscriptionResponse};
+pub use retranscribe::{retranscribe_recording, RetranscribeOptions};
+pub use retry_queue::{retry_recording, start_retry_worker, RetryQueue};
@@ -1,23 +1,36 @@
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tauri::{command, AppHandle, Emitter, State};
+use tauri::{command, AppHandle, Emitter, Manager};
 use tauri_plugin_store::StoreExt;
-use tokio::sync::Mutex;
 
+use crate::features::audio::normalize::TARGET_SAMPLE_RATE;
+use crate::features::audio::NormalizedAudio;
 use crate::features::clipboard;
-use crate::features::models::LocalModelManager;
 use crate::features::security;
 use crate::utils::app_categorization::categorize_app;
 use crate::utils::logger;
 
+use super::chunking::{
+    split_on_silence, stitch_responses, TranscriptionProgress, MAX_PARALLEL_CHUNKS,
+};
+use super::fallback::{run_with_fallback, FallbackOutcome, RetryPolicy};
+use super::language::{resolve_language, AUTO_LANGUAGE, TRANSLATION_TARGET_LANGUAGE};
+use super::local_model::{loaded_local_model, restore_local_model};
 use super::orchestrator_helpers::{
-    apply_ai_post_processing, create_empty_prompt_context, get_model_name,
+    apply_ai_post_processing, create_empty_prompt_context, get_model_name, get_vocabulary_words,
+};
+use super::providers::{
+    TranscriptionOptions, TranscriptionProvider, TranscriptionProviderRegistry,
+    TranscriptionRequest, TranscriptionResponse,
+};
+use crate::features::recordings::metadata::{
+    RecordingMetadata, RecordingSegments, RecordingStatus, TranslationInfo,
+};
+use crate::features::recordings::storage::{
+    get_all_recordings, read_metadata, save_metadata, save_segments,
 };
-use super::providers::{elevenlabs, google, local_whisper, openai};
-use crate::features::recordings::metadata::RecordingMetadata;
-use crate::features::recordings::storage::{get_all_recordings, read_metadata, save_metadata};
 
 // Global state for debouncing paste operations
 static LAST_PASTE_TIME: std::sync::Mutex<Option<Instant>> = std::sync::Mutex::new(None);
@@ -40,8 +53,17 @@ pub struct TranscribeRequest {
     pub audio_data: Vec<u8>,
     pub timestamp: i64,
     pub duration: Option<f64>,
-    pub language: Option<String>, // ISO 639-1 language code (e.g., "en", "es", "fr")
+    pub language: Option<String>, // ISO 639-1 language code (e.g., "en", "es", "fr"), None to auto-detect
     pub recording_device: Option<String>,
+    /// Retries and imports: skip auto-paste/copy and the focused app lookup
+    #[serde(default)]
+    pub background: bool,
+    /// Translate the speech into English instead of transcribing it
+    #[serde(default)]
+    pub translate: bool,
+    /// Use this model instead of the selected one (fallbacks still apply)
+    #[serde(default)]
+    pub model_id: Option<String>,
 }
 
 /// Unified transcription command that handles the entire flow:
@@ -55,24 +77,30 @@ pub struct TranscribeRequest {
 pub async fn transcribe_and_process(
     request: TranscribeRequest,
     app: AppHandle,
-    local_model_state: State<'_, Arc<Mutex<LocalModelManager>>>,
 ) -> Result<Option<TranscriptionRecord>, String> {
     let start_time = Instant::now();
 
-    // Step 1: Get selected transcription model
-    let selected_model = get_selected_model(&app)?;
+    // Step 1: Get selected transcription model and its fallbacks
+    let model_chain = get_model_chain(&app, request.model_id.as_deref())?;
 
-    // Step 2: Get focused application
-    let focused_app =
+    // Step 2: Get focused application (meaningless for background work)
+    let unknown_app = || clipboard::FocusedApp {
+        name: "Unknown".to_string(),
+        bundle_id: "".to_string(),
+    };
+    let focused_app = if request.background {
+        unknown_app()
+    } else {
         clipboard::get_focused_app()
             .await
-            .unwrap_or_else(|_| clipboard::FocusedApp {
-                name: "Unknown".to_string(),
-                bundle_id: "".to_string(),
-            });
+            .unwrap_or_else(|_| unknown_app())
+    };
     let focused_app_name = focused_app.name.clone();
 
-    if is_audio_silent(&request.audio_data)? {
+    // Step 3: Normalize audio to 16kHz mono for the providers
+    let audio = NormalizedAudio::from_wav(&request.audio_data)?;
+
+    if is_audio_silent(&audio.samples) {
         logger::debug("Audio is silent, skipping transcription");
 
         // Clean up the recording folder since audio is silent
@@ -93,15 +121,42 @@ pub async fn transcribe_and_process(
         return Ok(None);
     }
 
-    // Step 4: Transcribe using appropriate provider
-    let raw_transcription = transcribe_with_provider(
+    // Step 4: Transcribe, falling back through the chain ("auto" lets it detect the language)
+    let language = request.language.as_deref().and_then(resolve_language);
+    let vocabulary = get_vocabulary_words(&app)?.unwrap_or_default();
+    let outcome = transcribe_with_fallback(
         &app,
-        request.audio_data.clone(),
-        &selected_model,
-        request.language.clone(),
-        local_model_state,
+        audio,
+        model_chain,
+        language.clone(),
+        vocabulary.clone(),
+        TranscriptionOptions {
+            translate: request.translate,
+            diarize: speaker_diarization_enabled(&app)?,
+            ..Default::default()
+        },
     )
     .await?;
+    let transcription = outcome.value;
+    let selected_model = outcome.candidate;
+    let raw_transcription = transcription
+        .speaker_labelled_text()
+        .unwrap_or_else(|| transcription.text.clone());
+
+    // Prefer the language the model actually heard over the requested one.
+    // A translation is English whatever was spoken.
+    let translation = request.translate.then(|| TranslationInfo {
+        source_language: transcription.language.clone().or(language.clone()),
+        target_language: TRANSLATION_TARGET_LANGUAGE.to_string(),
+    });
+    let language_selected = language.unwrap_or_else(|| AUTO_LANGUAGE.to_string());
+    let effective_language = match &translation {
+        Some(translation) => translation.target_language.clone(),
+        None => transcription
+            .language
+            .clone()
+            .unwrap_or_else(|| language_selected.clone()),
+    };
 
     // Skip if transcription is empty
     if raw_transcription.trim().is_empty() {
@@ -134,13 +189,20 @@ pub async fn transcribe_and_process(
         post_processed_text,
         style_applied,
         style_category,
-        prompt_context,
+        mut prompt_context,
         post_processing_model_id,
         post_processing_model_name,
         post_processing_provider,
     ) = if ai_processing_enabled {
         // Try to apply AI post-processing
-        match apply_ai_post_processing(&app, &raw_transcription, &focused_app.name, &settings).await
+        match apply_ai_post_processing(
+            &app,
+            &raw_transcription,
+            &focused_app.name,
+            &effective_language,
+            &settings,
+        )
+        .await
         {
             Ok(result) => (
                 result.final_text,
@@ -174,7 +236,7 @@ pub async fn transcribe_and_process(
                     None,
                     None,
                     None,
-                    create_empty_prompt_context(),
+                    create_empty_prompt_context(&effective_language),
                     None,
                     None,
                     None,
@@ -188,13 +250,18 @@ pub async fn transcribe_and_process(
             None,
             None,
             None,
-            create_empty_prompt_context(),
+            create_empty_prompt_context(&effective_language),
             None,
             None,
             None,
         )
     };
 
+    // The vocabulary still biased the speech-to-text step without post-processing
+    if post_processed_text.is_none() {
+        prompt_context.vocabulary_used = vocabulary;
+    }
+
     // Step 8: Calculate processing time
     let processing_time = start_time.elapsed().as_millis() as u64;
 
@@ -202,7 +269,7 @@ pub async fn transcribe_and_process(
     let app_category = categorize_app(&focused_app.name);
 
     // Step 10: Create comprehensive metadata
-    let metadata = RecordingMetadata::new(
+    let mut metadata = RecordingMetadata::new(
         raw_transcription.clone(),
         final_text.clone(),
         post_processed_text,
@@ -215,7 +282,7 @@ pub async fn transcribe_and_process(
         post_processing_model_id,
         post_processing_model_name,
         post_processing_provider,
-        request.language.unwrap_or_else(|| "en".to_string()),
+        language_selected,
         request
             .recording_device
             .unwrap_or_else(|| "Unknown".to_string()),
@@ -226,22 +293,31 @@ pub async fn transcribe_and_process(
         style_category,
         prompt_context,
     );
+    metadata.detected_language = transcription.language.clone();
+    metadata.translation = translation;
+    metadata.transcription_attempts = outcome.attempts;
 
-    // Step 11: Save metadata
+    // Step 11: Save metadata and timings
     save_metadata(&recording_folder, &metadata)?;
 
-    // Step 12: Handle auto-paste/copy
-    let auto_paste = settings
-        .get("transcription")
-        .and_then(|t| t.get("autoPaste"))
-        .and_then(|v| v.as_bool())
-        .unwrap_or(false);
+    if let Err(e) = save_segments(&recording_folder, &RecordingSegments::from(&transcription)) {
+        logger::warn(&format!("Failed to save segments: {}", e));
+    }
 
-    let auto_copy_to_clipboard = settings
-        .get("transcription")
-        .and_then(|t| t.get("autoCopyToClipboard"))
-        .and_then(|v| v.as_bool())
-        .unwrap_or(false);
+    // Step 12: Handle auto-paste/copy (never for background work)
+    let auto_paste = !request.background
+        && settings
+            .get("transcription")
+            .and_then(|t| t.get("autoPaste"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+    let auto_copy_to_clipboard = !request.background
+        && settings
+            .get("transcription")
+            .and_then(|t| t.get("autoCopyToClipboard"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
 
     if auto_paste {
         app.emit("hide_voice_input", ())
@@ -274,7 +350,7 @@ pub async fn transcribe_and_process(
 }
 
 /// Get settings from the settings store
-fn get_settings(app: &AppHandle) -> Result<Value, String> {
+pub fn get_settings(app: &AppHandle) -> Result<Value, String> {
     let store = app
         .store("settings")
         .map_err(|e| format!("Failed to get settings store: {}", e))?;
@@ -287,17 +363,58 @@ fn get_settings(app: &AppHandle) -> Result<Value, String> {
     Ok(settings)
 }
 
-/// Get the selected speech-to-text model from settings
-fn get_selected_model(app: &AppHandle) -> Result<SelectedModel, String> {
-    // Get selected model ID from settings
+/// Whether `transcription.speakerDiarization` is turned on in settings
+pub fn speaker_diarization_enabled(app: &AppHandle) -> Result<bool, String> {
     let settings = get_settings(app)?;
-    let selected_model_id = settings
+
+    Ok(settings
         .get("transcription")
-        .and_then(|t| t.get("speechToTextModelId"))
-        .and_then(|v| v.as_str())
-        .ok_or("No speech-to-text model selected in settings")?;
+        .and_then(|t| t.get("speakerDiarization"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false))
+}
+
+/// Get the selected speech-to-text model followed by its configured fallbacks
+///
+/// Fallbacks come from `transcription.fallbackModelIds` in settings, in order.
+/// Duplicates and models that no longer exist are skipped. `preferred`
+/// replaces the selected model at the head of the chain.
+fn get_model_chain(app: &AppHandle, preferred: Option<&str>) -> Result<Vec<SelectedModel>, String> {
+    let settings = get_settings(app)?;
+    let transcription = settings.get("transcription");
+
+    let selected_model_id = match preferred {
+        Some(model_id) => model_id,
+        None => transcription
+            .and_then(|t| t.get("speechToTextModelId"))
+            .and_then(|v| v.as_str())
+            .ok_or("No speech-to-text model selected in settings")?,
+    };
+
+    let mut chain = vec![find_model(app, selected_model_id)?];
+
+    let fallback_ids = transcription
+        .and_then(|t| t.get("fallbackModelIds"))
+        .and_then(|v| v.as_array())
+        .map(|ids| ids.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    for id in fallback_ids {
+        if chain.iter().any(|model| model.id == id) {
+            continue;
+        }
+
+        match find_model(app, id) {
+            Ok(model) => chain.push(model),
+            Err(e) => logger::warn(&format!("Skipping fallback model: {}", e)),
+        }
+    }
+
+    Ok(chain)
+}
 
-    // Get the model details from models store
+/// Look up a speech-to-text model in the models store
+pub fn find_model(app: &AppHandle, model_id: &str) -> Result<SelectedModel, String> {
     let models_store = app
         .store("models.json")
         .map_err(|e| format!("Failed to get models store: {}", e))?;
@@ -313,7 +430,7 @@ fn get_selected_model(app: &AppHandle) -> Result<SelectedModel, String> {
 
         let id = model.get("id").and_then(|v| v.as_str()).unwrap_or("");
 
-        if id == selected_model_id {
+        if id == model_id {
             let provider = model
                 .get("provider")
                 .and_then(|v| v.as_str())
@@ -330,10 +447,7 @@ fn get_selected_model(app: &AppHandle) -> Result<SelectedModel, String> {
         }
     }
 
-    Err(format!(
-        "Model '{}' not found in models store",
-        selected_model_id
-    ))
+    Err(format!("Model '{}' not found in models store", model_id))
 }
 
 /// Get the last (most recent) transcript from the new recordings storage
@@ -346,13 +460,13 @@ pub async fn get_last_transcript(app: AppHandle) -> Result<String, String> {
         return Err("No recordings found".to_string());
     }
 
-    // Get the first (most recent) recording
-    let last_recording = recordings.first().ok_or("No recordings available")?;
-
-    // Read metadata from the recording
-    let metadata = read_metadata(last_recording)?;
-
-    Ok(metadata.result)
+    // Skip recordings still waiting for a successful transcription
+    recordings
+        .iter()
+        .filter_map(|recording| read_metadata(recording).ok())
+        .find(|metadata| metadata.status == RecordingStatus::Completed)
+        .map(|metadata| metadata.result)
+        .ok_or_else(|| "No recordings available".to_string())
 }
 
 /// Paste the last transcript using the clipboard
@@ -391,70 +505,193 @@ pub async fn paste_last_transcript(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
-/// Route transcription to the appropriate provider
-async fn transcribe_with_provider(
+/// Transcribe with each model in the chain until one succeeds
+///
+/// Transient failures (network errors, timeouts, 5xx/429 responses) are
+/// retried with exponential backoff before moving on to the next model.
+/// Local models in the chain are loaded by their provider when their turn
+/// comes; the model the user had loaded is put back afterwards.
+pub async fn transcribe_with_fallback(
+    app: &AppHandle,
+    audio: NormalizedAudio,
+    chain: Vec<SelectedModel>,
+    language: Option<String>,
+    vocabulary: Vec<String>,
+    options: TranscriptionOptions,
+) -> Result<FallbackOutcome<SelectedModel, TranscriptionResponse>, String> {
+    let previous_local_model = loaded_local_model(app).await;
+
+    let outcome = run_with_fallback(
+        chain,
+        RetryPolicy::default(),
+        |model| model.id.clone(),
+        |model, _| {
+            let (model, audio, language, vocabulary, options) = (
+                model.clone(),
+                audio.clone(),
+                language.clone(),
+                vocabulary.clone(),
+                options.clone(),
+            );
+            async move {
+                transcribe_with_provider(app, audio, &model, language, vocabulary, options).await
+            }
+        },
+    )
+    .await;
+
+    // Nothing to put back if no local model was in use
+    if previous_local_model.is_some() {
+        restore_local_model(app, previous_local_model).await;
+    }
+
+    let outcome = outcome?;
+    if outcome.attempts > 1 {
+        logger::info(&format!(
+            "Transcribed with {} after {} attempts",
+            outcome.candidate.id, outcome.attempts
+        ));
+    }
+
+    Ok(outcome)
+}
+
+/// Route transcription to the provider registered for the model
+///
+/// Audio longer than the provider's `max_chunk_secs` is split on silence,
+/// transcribed chunk by chunk (emitting `transcription-progress`) and
+/// stitched back together.
+pub async fn transcribe_with_provider(
     app: &AppHandle,
-    audio_data: Vec<u8>,
+    audio: NormalizedAudio,
     model: &SelectedModel,
     language: Option<String>,
-    local_model_state: State<'_, Arc<Mutex<LocalModelManager>>>,
-) -> Result<String, String> {
-    let response = match model.provider.as_str() {
-        "openai" => {
-            let api_key = security::get_api_key_internal(app, &model.id)
-                .await
-                .map_err(|_| "OpenAI API key not found. Please add your API key in settings.")?;
-
-            openai::transcribe_with_openai(
-                audio_data,
-                api_key,
-                Some(model.id.clone()),
-                language.clone(), // Use selected language
-                None,             // temperature
-            )
-            .await?
-        }
-        "google" => {
-            let api_key = security::get_api_key_internal(app, &model.id)
-                .await
-                .map_err(|_| "Google API key not found. Please add your API key in settings.")?;
-
-            // Convert ISO 639-1 code to Google's format (e.g., "en" -> "en-US")
-            let google_language = language
-                .clone()
-                .map(|lang| format!("{}-US", lang.to_uppercase()))
-                .or(Some("en-US".to_string()));
-
-            google::transcribe_with_google(audio_data, api_key, google_language).await?
-        }
-        "local-whisper" => {
-            local_whisper::transcribe_with_local_whisper(
-                audio_data,
-                Some(model.id.clone()),
-                language.clone(), // Use selected language
-                local_model_state,
-            )
-            .await?
-        }
-        "elevenlabs" => {
-            let api_key = security::get_api_key_internal(app, &model.id)
-                .await
-                .map_err(|_| {
-                    "ElevenLabs API key not found. Please add your API key in settings."
-                })?;
-
-            elevenlabs::transcribe_with_elevenlabs(
-                audio_data,
-                api_key,
-                Some(model.id.clone()),
-                None, // language
+    vocabulary: Vec<String>,
+    options: TranscriptionOptions,
+) -> Result<TranscriptionResponse, String> {
+    let registry = app.state::<TranscriptionProviderRegistry>();
+    let provider = registry.get(&model.provider)?;
+
+    let api_key = if provider.requires_api_key() {
+        let key = security::get_api_key_internal(app, &model.id)
+            .await
+            .map_err(|_| {
+                format!(
+                    "{} API key not found. Please add your API key in settings.",
+                    provider.display_name()
+                )
+            })?;
+        Some(key)
+    } else {
+        None
+    };
+
+    if options.translate && !provider.supports_translation() {
+        return Err(format!(
+            "{} can't translate speech. Use OpenAI or a local Whisper model to translate.",
+            provider.display_name()
+        ));
+    }
+
+    let chunks = split_on_silence(&audio.samples, provider.max_chunk_secs());
+    if chunks.len() <= 1 {
+        return transcribe_chunk(
+            provider, app, audio, model, language, vocabulary, api_key, options,
+        )
+        .await;
+    }
+
+    logger::info(&format!(
+        "Transcribing {:.0}s of audio in {} chunks",
+        audio.duration_secs(),
+        chunks.len()
+    ));
+
+    // Local models share one loaded engine, so only cloud chunks run in parallel
+    let concurrency = if provider.requires_download() {
+        1
+    } else {
+        MAX_PARALLEL_CHUNKS
+    };
+    let total_chunks = chunks.len();
+
+    let mut results = futures_util::stream::iter(chunks.into_iter().map(|range| {
+        let offset = range.start as f64 / TARGET_SAMPLE_RATE as f64;
+        let chunk = NormalizedAudio {
+            samples: audio.samples[range].to_vec(),
+        };
+        let (language, vocabulary, api_key, options) = (
+            language.clone(),
+            vocabulary.clone(),
+            api_key.clone(),
+            options.clone(),
+        );
+        async move {
+            transcribe_chunk(
+                provider, app, chunk, model, language, vocabulary, api_key, options,
             )
-            .await?
+            .await
+            .map(|response| (offset, response))
         }
-        _ => return Err(format!("Unsupported provider: {}", model.provider)),
+    }))
+    .buffered(concurrency);
+
+    let mut parts = Vec::with_capacity(total_chunks);
+    while let Some(result) = results.next().await {
+        parts.push(result?);
+
+        let _ = app.emit(
+            "transcription-progress",
+            TranscriptionProgress {
+                model_id: model.id.clone(),
+                completed_chunks: parts.len(),
+                total_chunks,
+            },
+        );
+    }
+
+    Ok(stitch_responses(parts))
+}
+
+/// Send one piece of audio to the provider, with its request timeout applied
+#[allow(clippy::too_many_arguments)]
+async fn transcribe_chunk(
+    provider: &dyn TranscriptionProvider,
+    app: &AppHandle,
+    audio: NormalizedAudio,
+    model: &SelectedModel,
+    language: Option<String>,
+    vocabulary: Vec<String>,
+    api_key: Option<String>,
+    options: TranscriptionOptions,
+) -> Result<TranscriptionResponse, String> {
+    let duration = audio.duration_secs();
+    let transcription = provider.transcribe(
+        app,
+        TranscriptionRequest {
+            audio,
+            model_id: model.id.clone(),
+            language,
+            vocabulary,
+            api_key,
+            options,
+        },
+    );
+
+    let response = match provider.request_timeout(duration) {
+        Some(limit) => tokio::time::timeout(limit, transcription)
+            .await
+            .map_err(|_| {
+                format!(
+                    "{} request timed out after {}s",
+                    provider.display_name(),
+                    limit.as_secs()
+                )
+            })??,
+        None => transcription.await?,
     };
 
-    Ok(response.text)
+    Ok(response.with_fallback_segment(duration))
 }
 
 #[derive(Debug, Clone)]
@@ -466,36 +703,9 @@ pub struct SelectedModel {
 
 /// Detects if audio is silent by analyzing the waveform
 /// Returns true if audio is mostly silent (no speech detected)
-fn is_audio_silent(audio_data: &[u8]) -> Result<bool, String> {
-    use hound::WavReader;
-    use std::io::Cursor;
-
-    // Parse WAV file
-    let cursor = Cursor::new(audio_data);
-    let mut reader =
-        WavReader::new(cursor).map_err(|e| format!("Failed to parse WAV audio: {}", e))?;
-
-    let spec = reader.spec();
-
-    // Read all samples and calculate RMS (Root Mean Square)
-    let samples: Vec<f32> = match spec.sample_format {
-        hound::SampleFormat::Float => reader
-            .samples::<f32>()
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| format!("Failed to read samples: {}", e))?,
-        hound::SampleFormat::Int => {
-            // Convert i16 samples to f32
-            let max_val = i16::MAX as f32;
-            reader
-                .samples::<i16>()
-                .map(|s| s.map(|v| v as f32 / max_val))
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| format!("Failed to read samples: {}", e))?
-        }
-    };
-
+fn is_audio_silent(samples: &[f32]) -> bool {
     if samples.is_empty() {
-        return Ok(true); // Empty audio is considered silent
+        return true; // Empty audio is considered silent
     }
 
     // Calculate RMS
@@ -517,5 +727,5 @@ fn is_audio_silent(audio_data: &[u8]) -> Result<bool, String> {
         rms, peak, is_silent
     ));
 
-    Ok(is_silent)
+    is_silent
 }
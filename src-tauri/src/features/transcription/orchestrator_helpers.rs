@@ -25,6 +25,7 @@ pub async fn apply_ai_post_processing(
     app: &AppHandle,
     raw_text: &str,
     focused_app_name: &str,
+    language: &str,
     settings: &Value,
 ) -> Result<PostProcessingResult, String> {
     // Get AI processing settings
@@ -71,7 +72,7 @@ pub async fn apply_ai_post_processing(
             .unwrap_or_default(),
         vibe_prompt: vibe_prompt.clone(),
         system_context: SystemContext {
-            language: "en".to_string(), // TODO: get from settings
+            language: language.to_string(),
             time: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
         },
         application_context: ApplicationContext {
@@ -136,7 +137,7 @@ pub async fn apply_ai_post_processing(
 }
 
 /// Get vocabulary words from store
-fn get_vocabulary_words(app: &AppHandle) -> Result<Option<Vec<String>>, String> {
+pub fn get_vocabulary_words(app: &AppHandle) -> Result<Option<Vec<String>>, String> {
     let store = app
         .store("vocabulary.json")
         .map_err(|e| format!("Failed to get vocabulary store: {}", e))?;
@@ -248,13 +249,13 @@ fn get_vibe_for_category(
 }
 
 /// Create empty prompt context when not using AI
-pub fn create_empty_prompt_context() -> PromptContext {
+pub fn create_empty_prompt_context(language: &str) -> PromptContext {
     PromptContext {
         vocabulary_used: vec![],
         snippets_used: vec![],
         vibe_prompt: None,
         system_context: SystemContext {
-            language: "en".to_string(),
+            language: language.to_string(),
             time: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
         },
         application_context: ApplicationContext {
@@ -0,0 +1,441 @@
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+use super::{
+    segments_from_words, TranscriptionProvider, TranscriptionRequest, TranscriptionResponse,
+    TranscriptionWord,
+};
+use crate::features::models::models_registry::ModelProvider;
+
+const ASSEMBLYAI_API_URL: &str = "https://api.assemblyai.com/v2";
+
+/// How often to check whether a queued transcript has finished
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Give up on a transcript that hasn't finished after this long, or after
+/// the length of the audio if that is longer
+const MAX_POLL_DURATION: Duration = Duration::from_secs(600);
+
+/// Longest audio AssemblyAI accepts in one file
+const MAX_AUDIO_SECS: f64 = 10.0 * 60.0 * 60.0;
+
+/// AssemblyAI rejects word boost entries longer than six words
+const MAX_WORD_BOOST_WORDS: usize = 6;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AssemblyAIError {
+    error: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UploadResponse {
+    upload_url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TranscriptRequest {
+    audio_url: String,
+    speech_model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<String>,
+    language_detection: bool,
+    punctuate: bool,
+    format_text: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    word_boost: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost_param: Option<String>,
+    speaker_labels: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum TranscriptStatus {
+    Queued,
+    Processing,
+    Completed,
+    Error,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TranscriptResponse {
+    id: String,
+    status: TranscriptStatus,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    language_code: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    words: Option<Vec<AssemblyAIWord>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AssemblyAIWord {
+    text: String,
+    /// Milliseconds from the start of the audio
+    start: u64,
+    end: u64,
+    /// "A", "B", ... when speaker labels were requested
+    #[serde(default)]
+    speaker: Option<String>,
+}
+
+/// AssemblyAI pre-recorded transcription provider
+///
+/// Audio is uploaded, a transcript job is created for the upload URL, and
+/// the job is polled until it completes.
+///
+/// API Reference: https://www.assemblyai.com/docs/api-reference/transcripts
+pub struct AssemblyAIProvider {
+    base_url: String,
+    poll_interval: Duration,
+}
+
+impl AssemblyAIProvider {
+    pub fn new() -> Self {
+        Self {
+            base_url: ASSEMBLYAI_API_URL.to_string(),
+            poll_interval: POLL_INTERVAL,
+        }
+    }
+
+    /// Transcribe WAV bytes with the given speech model (e.g., "best", "nano")
+    #[allow(clippy::too_many_arguments)]
+    async fn transcribe_wav(
+        &self,
+        audio_data: Vec<u8>,
+        api_key: &str,
+        speech_model: &str,
+        language: Option<String>,
+        vocabulary: &[String],
+        diarize: bool,
+        poll_deadline: Duration,
+    ) -> Result<TranscriptionResponse, String> {
+        let client = reqwest::Client::new();
+
+        // Step 1: Upload the audio
+        let upload: UploadResponse = send(
+            client
+                .post(format!("{}/upload", self.base_url))
+                .header("authorization", api_key)
+                .header("content-type", "application/octet-stream")
+                .body(audio_data),
+        )
+        .await?;
+
+        // Step 2: Create the transcript job
+        let word_boost = build_word_boost(vocabulary);
+        let transcript_request = TranscriptRequest {
+            audio_url: upload.upload_url,
+            speech_model: speech_model.to_string(),
+            language_detection: language.is_none(),
+            language_code: language,
+            punctuate: true,
+            format_text: true,
+            boost_param: if word_boost.is_empty() {
+                None
+            } else {
+                Some("high".to_string())
+            },
+            word_boost,
+            speaker_labels: diarize,
+        };
+
+        let mut transcript: TranscriptResponse = send(
+            client
+                .post(format!("{}/transcript", self.base_url))
+                .header("authorization", api_key)
+                .json(&transcript_request),
+        )
+        .await?;
+
+        // Step 3: Poll until the transcript is done
+        let started = Instant::now();
+        loop {
+            match transcript.status {
+                TranscriptStatus::Completed => break,
+                TranscriptStatus::Error => {
+                    return Err(format!(
+                        "AssemblyAI transcription failed: {}",
+                        transcript
+                            .error
+                            .unwrap_or_else(|| "Unknown error".to_string())
+                    ));
+                }
+                TranscriptStatus::Queued | TranscriptStatus::Processing => {}
+            }
+
+            if started.elapsed() > poll_deadline {
+                return Err(format!(
+                    // Not worded as a timeout: retrying would upload the audio again
+                    "AssemblyAI transcription did not finish within {} seconds",
+                    poll_deadline.as_secs()
+                ));
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+
+            transcript = send(
+                client
+                    .get(format!("{}/transcript/{}", self.base_url, transcript.id))
+                    .header("authorization", api_key),
+            )
+            .await?;
+        }
+
+        let words: Vec<TranscriptionWord> = transcript
+            .words
+            .unwrap_or_default()
+            .into_iter()
+            .map(|w| TranscriptionWord {
+                start: w.start as f64 / 1000.0,
+                end: w.end as f64 / 1000.0,
+                word: w.text,
+                speaker: w.speaker,
+            })
+            .collect();
+
+        Ok(TranscriptionResponse {
+            text: transcript.text.unwrap_or_default(),
+            language: transcript.language_code,
+            segments: Some(segments_from_words(&words)),
+            words: Some(words),
+        })
+    }
+}
+
+impl Default for AssemblyAIProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for AssemblyAIProvider {
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::AssemblyAI
+    }
+
+    fn display_name(&self) -> &'static str {
+        "AssemblyAI"
+    }
+
+    /// Recordings are sent whole, so one person keeps the same speaker
+    /// label throughout instead of being renumbered in every chunk
+    fn max_chunk_secs(&self) -> f64 {
+        MAX_AUDIO_SECS
+    }
+
+    /// Polling has its own deadline; an outer limit would cut off long
+    /// jobs and re-upload the audio
+    fn request_timeout(&self, _audio_secs: f64) -> Option<Duration> {
+        None
+    }
+
+    async fn transcribe(
+        &self,
+        _app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String> {
+        let api_key = request.api_key.ok_or("AssemblyAI API key not provided")?;
+
+        // Model IDs are "assemblyai-<speech_model>" (e.g., "assemblyai-best")
+        let speech_model = request
+            .model_id
+            .strip_prefix("assemblyai-")
+            .unwrap_or("best")
+            .to_string();
+
+        let poll_deadline =
+            MAX_POLL_DURATION.max(Duration::from_secs_f64(request.audio.duration_secs()));
+
+        self.transcribe_wav(
+            request.audio.to_wav()?,
+            &api_key,
+            &speech_model,
+            request.language,
+            &request.vocabulary,
+            request.options.diarize,
+            poll_deadline,
+        )
+        .await
+    }
+}
+
+/// Send a request and parse the JSON response, mapping API errors
+async fn send<T: DeserializeOwned>(request: reqwest::RequestBuilder) -> Result<T, String> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request to AssemblyAI: {}", e))?;
+
+    let status = response.status();
+    let response_text = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    if !status.is_success() {
+        // Try to parse error response
+        if let Ok(error) = serde_json::from_str::<AssemblyAIError>(&response_text) {
+            return Err(format!(
+                "AssemblyAI API error ({}): {}",
+                status, error.error
+            ));
+        }
+        return Err(format!(
+            "AssemblyAI API request failed with status {}: {}",
+            status, response_text
+        ));
+    }
+
+    serde_json::from_str(&response_text)
+        .map_err(|e| format!("Failed to parse AssemblyAI response: {}", e))
+}
+
+/// Keep only vocabulary entries AssemblyAI accepts as word boost phrases
+fn build_word_boost(vocabulary: &[String]) -> Vec<String> {
+    vocabulary
+        .iter()
+        .map(|word| word.trim())
+        .filter(|word| !word.is_empty())
+        .filter(|word| word.split_whitespace().count() <= MAX_WORD_BOOST_WORDS)
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_server::TestServer;
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn provider(base_url: &str) -> AssemblyAIProvider {
+        AssemblyAIProvider {
+            base_url: base_url.to_string(),
+            poll_interval: Duration::from_millis(10),
+        }
+    }
+
+    #[test]
+    fn test_upload_create_and_poll() {
+        let polls = AtomicUsize::new(0);
+        let server = TestServer::start(move |request| match request.route() {
+            "/upload" => (
+                200,
+                r#"{"upload_url":"https://cdn.test/audio"}"#.to_string(),
+            ),
+            "/transcript" => (200, r#"{"id":"t1","status":"queued"}"#.to_string()),
+            "/transcript/t1" => {
+                if polls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    (200, r#"{"id":"t1","status":"processing"}"#.to_string())
+                } else {
+                    (
+                        200,
+                        r#"{"id":"t1","status":"completed","text":"Hello Dicta","language_code":"en","words":[{"text":"Hello","start":250,"end":600,"confidence":0.99,"speaker":"A"},{"text":"Dicta","start":700,"end":1100,"confidence":0.97,"speaker":"B"}]}"#
+                            .to_string(),
+                    )
+                }
+            }
+            _ => (404, r#"{"error":"not found"}"#.to_string()),
+        });
+
+        let vocabulary = vec!["Dicta".to_string(), " ".to_string()];
+        let response = tauri::async_runtime::block_on(provider(&server.base_url).transcribe_wav(
+            vec![1, 2, 3],
+            "test-key",
+            "best",
+            Some("en".to_string()),
+            &vocabulary,
+            true,
+            MAX_POLL_DURATION,
+        ))
+        .unwrap();
+
+        assert_eq!(response.text, "Hello Dicta");
+        assert_eq!(response.language.as_deref(), Some("en"));
+        assert_eq!(response.words.unwrap().len(), 2);
+
+        let segments = response.segments.unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].start, 0.25);
+        assert_eq!(segments[0].speaker.as_deref(), Some("A"));
+        assert_eq!(segments[1].end, 1.1);
+        assert_eq!(segments[1].speaker.as_deref(), Some("B"));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(requests[0].body, vec![1, 2, 3]);
+        assert!(requests
+            .iter()
+            .all(|r| r.header("authorization") == Some("test-key")));
+
+        let create = requests[1].json();
+        assert_eq!(create["audio_url"], "https://cdn.test/audio");
+        assert_eq!(create["speech_model"], "best");
+        assert_eq!(create["language_code"], "en");
+        assert_eq!(create["language_detection"], false);
+        assert_eq!(create["word_boost"], serde_json::json!(["Dicta"]));
+        assert_eq!(create["boost_param"], "high");
+        assert_eq!(create["speaker_labels"], true);
+    }
+
+    #[test]
+    fn test_api_error_is_parsed() {
+        let server = TestServer::start(|_| (401, r#"{"error":"Invalid API key"}"#.to_string()));
+
+        let error = tauri::async_runtime::block_on(provider(&server.base_url).transcribe_wav(
+            vec![0],
+            "bad-key",
+            "best",
+            None,
+            &[],
+            false,
+            MAX_POLL_DURATION,
+        ))
+        .unwrap_err();
+
+        assert!(error.starts_with("AssemblyAI API error (401"));
+        assert!(error.ends_with("Invalid API key"));
+    }
+
+    #[test]
+    fn test_failed_transcript_reports_error() {
+        let server = TestServer::start(|request| match request.route() {
+            "/upload" => (
+                200,
+                r#"{"upload_url":"https://cdn.test/audio"}"#.to_string(),
+            ),
+            _ => (
+                200,
+                r#"{"id":"t1","status":"error","error":"Audio file is empty"}"#.to_string(),
+            ),
+        });
+
+        let error = tauri::async_runtime::block_on(provider(&server.base_url).transcribe_wav(
+            vec![0],
+            "test-key",
+            "nano",
+            None,
+            &[],
+            false,
+            MAX_POLL_DURATION,
+        ))
+        .unwrap_err();
+
+        assert_eq!(
+            error,
+            "AssemblyAI transcription failed: Audio file is empty"
+        );
+
+        let create = server.requests()[1].json();
+        assert_eq!(create["language_detection"], true);
+        assert!(create.get("word_boost").is_none());
+        assert_eq!(create["speaker_labels"], false);
+    }
+}
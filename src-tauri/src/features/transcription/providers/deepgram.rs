@@ -0,0 +1,348 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use super::{
+    segments_from_words, TranscriptionProvider, TranscriptionRequest, TranscriptionResponse,
+    TranscriptionWord,
+};
+use crate::features::models::models_registry::ModelProvider;
+
+const DEEPGRAM_API_URL: &str = "https://api.deepgram.com/v1";
+
+/// Intensifier applied to every keyword (nova-2 and older)
+const KEYWORD_BOOST: u32 = 2;
+
+/// Longest audio sent in one request: about 1.4 GB of 16kHz 16-bit mono
+/// WAV, within Deepgram's 2 GB upload limit
+const MAX_AUDIO_SECS: f64 = 12.0 * 60.0 * 60.0;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeepgramError {
+    err_msg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeepgramResponse {
+    results: DeepgramResults,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeepgramResults {
+    channels: Vec<DeepgramChannel>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeepgramChannel {
+    alternatives: Vec<DeepgramAlternative>,
+    #[serde(default)]
+    detected_language: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeepgramAlternative {
+    transcript: String,
+    #[serde(default)]
+    words: Vec<DeepgramWord>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeepgramWord {
+    word: String,
+    start: f64,
+    end: f64,
+    /// Present when smart_format/punctuate is enabled
+    #[serde(default)]
+    punctuated_word: Option<String>,
+    /// Present when diarize is enabled
+    #[serde(default)]
+    speaker: Option<u32>,
+}
+
+/// Deepgram pre-recorded transcription provider
+///
+/// API Reference: https://developers.deepgram.com/reference/speech-to-text-api/listen
+pub struct DeepgramProvider {
+    base_url: String,
+}
+
+impl DeepgramProvider {
+    pub fn new() -> Self {
+        Self {
+            base_url: DEEPGRAM_API_URL.to_string(),
+        }
+    }
+
+    /// Transcribe WAV bytes with the given Deepgram model (e.g., "nova-3")
+    async fn transcribe_wav(
+        &self,
+        audio_data: Vec<u8>,
+        api_key: &str,
+        model: &str,
+        language: Option<String>,
+        vocabulary: &[String],
+        diarize: bool,
+    ) -> Result<TranscriptionResponse, String> {
+        let url = build_listen_url(
+            &self.base_url,
+            model,
+            language.as_deref(),
+            vocabulary,
+            diarize,
+        )?;
+
+        let client = reqwest::Client::new();
+        let response = client
+            .post(url)
+            .header("Authorization", format!("Token {}", api_key))
+            .header("Content-Type", "audio/wav")
+            .body(audio_data)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to send request to Deepgram: {}", e))?;
+
+        let status = response.status();
+        let response_text = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+
+        if !status.is_success() {
+            // Try to parse error response
+            if let Ok(error) = serde_json::from_str::<DeepgramError>(&response_text) {
+                return Err(format!(
+                    "Deepgram API error ({}): {}",
+                    status, error.err_msg
+                ));
+            }
+            return Err(format!(
+                "Deepgram API request failed with status {}: {}",
+                status, response_text
+            ));
+        }
+
+        let deepgram_response: DeepgramResponse = serde_json::from_str(&response_text)
+            .map_err(|e| format!("Failed to parse Deepgram response: {}", e))?;
+
+        // Audio is mono, so only the first channel matters
+        let channel = deepgram_response.results.channels.into_iter().next();
+        let detected_language = channel.as_ref().and_then(|c| c.detected_language.clone());
+        let Some(alternative) = channel.and_then(|c| c.alternatives.into_iter().next()) else {
+            return Ok(TranscriptionResponse {
+                text: String::new(),
+                language: detected_language.or(language),
+                segments: None,
+                words: None,
+            });
+        };
+
+        let words: Vec<TranscriptionWord> = alternative
+            .words
+            .into_iter()
+            .map(|w| TranscriptionWord {
+                start: w.start,
+                end: w.end,
+                word: w.punctuated_word.unwrap_or(w.word),
+                speaker: w.speaker.map(|speaker| speaker.to_string()),
+            })
+            .collect();
+
+        Ok(TranscriptionResponse {
+            text: alternative.transcript,
+            language: detected_language.or(language),
+            segments: Some(segments_from_words(&words)),
+            words: Some(words),
+        })
+    }
+}
+
+impl Default for DeepgramProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TranscriptionProvider for DeepgramProvider {
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::Deepgram
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Deepgram"
+    }
+
+    /// Recordings are sent whole, so one person keeps the same speaker
+    /// label throughout instead of being renumbered in every chunk
+    fn max_chunk_secs(&self) -> f64 {
+        MAX_AUDIO_SECS
+    }
+
+    async fn transcribe(
+        &self,
+        _app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String> {
+        let api_key = request.api_key.ok_or("Deepgram API key not provided")?;
+
+        // Model IDs are "deepgram-<model>" (e.g., "deepgram-nova-3")
+        let model = request
+            .model_id
+            .strip_prefix("deepgram-")
+            .unwrap_or("nova-3")
+            .to_string();
+
+        self.transcribe_wav(
+            request.audio.to_wav()?,
+            &api_key,
+            &model,
+            request.language,
+            &request.vocabulary,
+            request.options.diarize,
+        )
+        .await
+    }
+}
+
+/// Build the /listen URL with model, formatting, language, keyword and
+/// diarization options
+fn build_listen_url(
+    base_url: &str,
+    model: &str,
+    language: Option<&str>,
+    vocabulary: &[String],
+    diarize: bool,
+) -> Result<Url, String> {
+    let mut url = Url::parse(&format!("{}/listen", base_url))
+        .map_err(|e| format!("Invalid Deepgram URL: {}", e))?;
+
+    {
+        let mut query = url.query_pairs_mut();
+        query
+            .append_pair("model", model)
+            .append_pair("smart_format", "true")
+            .append_pair("punctuate", "true");
+
+        match language {
+            Some(lang) => query.append_pair("language", lang),
+            None => query.append_pair("detect_language", "true"),
+        };
+
+        if diarize {
+            query.append_pair("diarize", "true");
+        }
+
+        // Nova-3 replaced keyword boosting with keyterm prompting
+        let uses_keyterms = model.starts_with("nova-3");
+        for word in vocabulary
+            .iter()
+            .map(|w| w.trim())
+            .filter(|w| !w.is_empty())
+        {
+            if uses_keyterms {
+                query.append_pair("keyterm", word);
+            } else {
+                query.append_pair("keywords", &format!("{}:{}", word, KEYWORD_BOOST));
+            }
+        }
+    }
+
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_server::TestServer;
+    use super::*;
+
+    fn provider(base_url: &str) -> DeepgramProvider {
+        DeepgramProvider {
+            base_url: base_url.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_listen_url_options() {
+        let vocabulary = vec!["Dicta".to_string(), "Tauri app".to_string()];
+
+        let url =
+            build_listen_url(DEEPGRAM_API_URL, "nova-2", Some("en"), &vocabulary, false).unwrap();
+        let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(pairs.contains(&("model".into(), "nova-2".into())));
+        assert!(pairs.contains(&("smart_format".into(), "true".into())));
+        assert!(pairs.contains(&("punctuate".into(), "true".into())));
+        assert!(pairs.contains(&("language".into(), "en".into())));
+        assert!(pairs.contains(&("keywords".into(), "Dicta:2".into())));
+        assert!(pairs.contains(&("keywords".into(), "Tauri app:2".into())));
+        assert!(!pairs.iter().any(|(key, _)| key == "diarize"));
+
+        let url = build_listen_url(DEEPGRAM_API_URL, "nova-3", None, &vocabulary, true).unwrap();
+        let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        assert!(pairs.contains(&("detect_language".into(), "true".into())));
+        assert!(pairs.contains(&("keyterm".into(), "Dicta".into())));
+        assert!(!pairs.iter().any(|(key, _)| key == "keywords"));
+        assert!(pairs.contains(&("diarize".into(), "true".into())));
+    }
+
+    #[test]
+    fn test_transcribe_reads_transcript_and_language() {
+        let server = TestServer::start(|_| {
+            (
+                200,
+                r#"{"results":{"channels":[{"detected_language":"es","alternatives":[{"transcript":"Hola mundo.","confidence":0.98,"words":[{"word":"hola","start":0.1,"end":0.4,"punctuated_word":"Hola"},{"word":"mundo","start":0.5,"end":0.9,"punctuated_word":"mundo."}]}]}]}}"#
+                    .to_string(),
+            )
+        });
+
+        let response = tauri::async_runtime::block_on(provider(&server.base_url).transcribe_wav(
+            vec![1, 2, 3],
+            "test-key",
+            "nova-3",
+            None,
+            &[],
+            false,
+        ))
+        .unwrap();
+
+        assert_eq!(response.text, "Hola mundo.");
+        assert_eq!(response.language.as_deref(), Some("es"));
+        assert_eq!(response.words.unwrap()[0].word, "Hola");
+
+        let segments = response.segments.unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].text, "Hola mundo.");
+        assert_eq!(segments[0].start, 0.1);
+        assert_eq!(segments[0].end, 0.9);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.route(), "/listen");
+        assert_eq!(request.header("authorization"), Some("Token test-key"));
+        assert_eq!(request.header("content-type"), Some("audio/wav"));
+        assert_eq!(request.body, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_api_error_is_parsed() {
+        let server = TestServer::start(|_| {
+            (
+                401,
+                r#"{"err_code":"INVALID_AUTH","err_msg":"Invalid credentials.","request_id":"r1"}"#
+                    .to_string(),
+            )
+        });
+
+        let error = tauri::async_runtime::block_on(provider(&server.base_url).transcribe_wav(
+            vec![0],
+            "bad-key",
+            "nova-3",
+            Some("en".to_string()),
+            &[],
+            false,
+        ))
+        .unwrap_err();
+
+        assert!(error.starts_with("Deepgram API error (401"));
+        assert!(error.ends_with("Invalid credentials."));
+    }
+}
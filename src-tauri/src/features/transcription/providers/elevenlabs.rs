@@ -1,8 +1,18 @@
+use async_trait::async_trait;
 use reqwest::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
-use tauri::command;
+use tauri::AppHandle;
 
-use super::TranscriptionResponse;
+use super::{
+    segments_from_words, TranscriptionProvider, TranscriptionRequest, TranscriptionResponse,
+    TranscriptionWord,
+};
+use crate::features::models::models_registry::ModelProvider;
+use crate::features::transcription::language::to_iso639_1;
+
+/// Longest audio sent in one request: Scribe's 10 hour limit, about
+/// 1.2 GB of 16kHz 16-bit mono WAV (uploads may be up to 3 GB)
+const MAX_AUDIO_SECS: f64 = 10.0 * 60.0 * 60.0;
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ElevenLabsError {
@@ -17,76 +27,135 @@ struct ElevenLabsErrorDetail {
 
 #[derive(Debug, Serialize, Deserialize)]
 struct ElevenLabsResponse {
+    text: String,
+    /// Detected (or requested) language, e.g. "eng"
+    #[serde(default)]
+    language_code: Option<String>,
+    #[serde(default)]
+    words: Vec<ElevenLabsWord>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ElevenLabsWord {
     text: String,
     #[serde(default)]
-    language: Option<String>,
+    start: f64,
+    #[serde(default)]
+    end: f64,
+    /// "word", "spacing" or "audio_event"
+    #[serde(rename = "type")]
+    word_type: String,
+    /// e.g. "speaker_0", present when diarizing
+    #[serde(default)]
+    speaker_id: Option<String>,
 }
 
-/// Transcribe audio using ElevenLabs Speech-to-Text API
+/// ElevenLabs Speech-to-Text provider
 ///
 /// API Reference: https://elevenlabs.io/docs/api-reference/speech-to-text
-#[command]
-pub async fn transcribe_with_elevenlabs(
-    audio_data: Vec<u8>,
-    api_key: String,
-    model: Option<String>,
-    language: Option<String>,
-) -> Result<TranscriptionResponse, String> {
-    // ElevenLabs uses "scribe_v1" model for speech-to-text
-    let model_id = model.unwrap_or_else(|| "scribe_v1".to_string());
-
-    // Create multipart form - field must be named "file" per API docs
-    let audio_part = Part::bytes(audio_data)
-        .file_name("audio.wav")
-        .mime_str("audio/wav")
-        .map_err(|e| format!("Failed to create audio part: {}", e))?;
-
-    let mut form = Form::new()
-        .part("file", audio_part)
-        .text("model_id", model_id);
-
-    // Add language if provided
-    if let Some(lang) = language {
-        form = form.text("language", lang);
+pub struct ElevenLabsProvider;
+
+#[async_trait]
+impl TranscriptionProvider for ElevenLabsProvider {
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::ElevenLabs
+    }
+
+    fn display_name(&self) -> &'static str {
+        "ElevenLabs"
+    }
+
+    /// Recordings are sent whole, so one person keeps the same speaker
+    /// label throughout instead of being renumbered in every chunk
+    fn max_chunk_secs(&self) -> f64 {
+        MAX_AUDIO_SECS
     }
 
-    // Make request to ElevenLabs API
-    let client = reqwest::Client::new();
-    let response = client
-        .post("https://api.elevenlabs.io/v1/speech-to-text")
-        .header("xi-api-key", api_key)
-        .multipart(form)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to send request to ElevenLabs: {}", e))?;
-
-    let status = response.status();
-    let response_text = response
-        .text()
-        .await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
-
-    if !status.is_success() {
-        // Try to parse error response
-        if let Ok(error) = serde_json::from_str::<ElevenLabsError>(&response_text) {
+    async fn transcribe(
+        &self,
+        _app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String> {
+        let api_key = request.api_key.ok_or("ElevenLabs API key not provided")?;
+
+        // Create multipart form - field must be named "file" per API docs
+        let audio_part = Part::bytes(request.audio.to_wav()?)
+            .file_name("audio.wav")
+            .mime_str("audio/wav")
+            .map_err(|e| format!("Failed to create audio part: {}", e))?;
+
+        let mut form = Form::new()
+            .part("file", audio_part)
+            .text("model_id", request.model_id);
+
+        // Note: Scribe has no vocabulary biasing field, so request.vocabulary
+        // is only applied through AI post-processing for this provider
+
+        // Omitting the language code lets ElevenLabs detect it
+        if let Some(language) = request.language.clone() {
+            form = form.text("language_code", language);
+        }
+
+        if request.options.diarize {
+            form = form.text("diarize", "true");
+        }
+
+        // Make request to ElevenLabs API
+        let client = reqwest::Client::new();
+        let response = client
+            .post("https://api.elevenlabs.io/v1/speech-to-text")
+            .header("xi-api-key", api_key)
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to send request to ElevenLabs: {}", e))?;
+
+        let status = response.status();
+        let response_text = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+
+        if !status.is_success() {
+            // Try to parse error response
+            if let Ok(error) = serde_json::from_str::<ElevenLabsError>(&response_text) {
+                return Err(format!(
+                    "ElevenLabs API error ({}): {}",
+                    status, error.detail.message
+                ));
+            }
             return Err(format!(
-                "ElevenLabs API error ({}): {}",
-                status, error.detail.message
+                "ElevenLabs API request failed with status {}: {}",
+                status, response_text
             ));
         }
-        return Err(format!(
-            "ElevenLabs API request failed with status {}: {}",
-            status, response_text
-        ));
-    }
 
-    // Parse successful response
-    let elevenlabs_response: ElevenLabsResponse = serde_json::from_str(&response_text)
-        .map_err(|e| format!("Failed to parse ElevenLabs response: {}", e))?;
+        // Parse successful response
+        let elevenlabs_response: ElevenLabsResponse = serde_json::from_str(&response_text)
+            .map_err(|e| format!("Failed to parse ElevenLabs response: {}", e))?;
+
+        // Words are interleaved with spacing and audio events (e.g., "(laughter)")
+        let words: Vec<TranscriptionWord> = elevenlabs_response
+            .words
+            .into_iter()
+            .filter(|w| w.word_type == "word")
+            .map(|w| TranscriptionWord {
+                start: w.start,
+                end: w.end,
+                word: w.text,
+                speaker: w.speaker_id,
+            })
+            .collect();
 
-    Ok(TranscriptionResponse {
-        text: elevenlabs_response.text,
-        language: elevenlabs_response.language,
-        segments: None, // ElevenLabs doesn't provide segments in basic response
-    })
+        Ok(TranscriptionResponse {
+            text: elevenlabs_response.text,
+            // Scribe reports ISO 639-3 ("eng"); the rest of the app uses 639-1
+            language: elevenlabs_response
+                .language_code
+                .map(|code| to_iso639_1(&code))
+                .or(request.language),
+            segments: Some(segments_from_words(&words)),
+            words: Some(words),
+        })
+    }
 }
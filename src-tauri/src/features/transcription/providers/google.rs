@@ -1,7 +1,14 @@
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use tauri::command;
+use tauri::AppHandle;
 
-use super::TranscriptionResponse;
+use super::{
+    TranscriptionProvider, TranscriptionRequest, TranscriptionResponse, TranscriptionSegment,
+    TranscriptionWord,
+};
+use crate::features::audio::normalize::TARGET_SAMPLE_RATE;
+use crate::features::models::models_registry::ModelProvider;
+use crate::features::transcription::language::{resolve_language, to_bcp47};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct GoogleSpeechRequest {
@@ -18,6 +25,20 @@ struct GoogleSpeechConfig {
     language_code: String,
     #[serde(rename = "enableAutomaticPunctuation")]
     enable_automatic_punctuation: bool,
+    #[serde(rename = "enableWordTimeOffsets")]
+    enable_word_time_offsets: bool,
+    #[serde(
+        rename = "speechContexts",
+        skip_serializing_if = "Vec::is_empty",
+        default
+    )]
+    speech_contexts: Vec<GoogleSpeechContext>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GoogleSpeechContext {
+    phrases: Vec<String>,
+    boost: f32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -33,12 +54,27 @@ struct GoogleSpeechResponse {
 #[derive(Debug, Serialize, Deserialize)]
 struct GoogleRecognitionResult {
     alternatives: Vec<GoogleSpeechAlternative>,
+    /// BCP-47 tag of the recognized language (e.g., "es-es")
+    #[serde(rename = "languageCode", default)]
+    language_code: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct GoogleSpeechAlternative {
     transcript: String,
     confidence: Option<f64>,
+    #[serde(default)]
+    words: Vec<GoogleWordInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GoogleWordInfo {
+    /// Duration string (e.g., "1.300s")
+    #[serde(rename = "startTime")]
+    start_time: String,
+    #[serde(rename = "endTime")]
+    end_time: String,
+    word: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -52,73 +88,195 @@ struct GoogleErrorDetail {
     code: i32,
 }
 
-#[command]
-pub async fn transcribe_with_google(
-    audio_data: Vec<u8>,
-    api_key: String,
-    language: Option<String>,
-) -> Result<TranscriptionResponse, String> {
-    let language_code = language.unwrap_or_else(|| "en-US".to_string());
-
-    // Encode audio to base64
-    use base64::{engine::general_purpose::STANDARD, Engine};
-    let audio_base64 = STANDARD.encode(&audio_data);
-
-    let request_body = GoogleSpeechRequest {
-        config: GoogleSpeechConfig {
-            encoding: "LINEAR16".to_string(),
-            sample_rate_hertz: 16000,
-            language_code,
-            enable_automatic_punctuation: true,
-        },
-        audio: GoogleAudioContent {
-            content: audio_base64,
-        },
-    };
-
-    let client = reqwest::Client::new();
-    let response = client
-        .post(format!(
-            "https://speech.googleapis.com/v1/speech:recognize?key={}",
-            api_key
-        ))
-        .json(&request_body)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to send request: {}", e))?;
-
-    let status = response.status();
-    let response_text = response
-        .text()
-        .await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
-
-    if !status.is_success() {
-        if let Ok(error) = serde_json::from_str::<GoogleError>(&response_text) {
+/// Boost for custom vocabulary phrases (Google accepts 0-20)
+const VOCABULARY_BOOST: f32 = 10.0;
+
+/// Google rejects phrases longer than this
+const MAX_PHRASE_CHARS: usize = 100;
+
+/// Google Cloud Speech-to-Text provider
+///
+/// API Reference: https://cloud.google.com/speech-to-text/docs/reference/rest/v1/speech/recognize
+pub struct GoogleProvider;
+
+#[async_trait]
+impl TranscriptionProvider for GoogleProvider {
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::Google
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Google"
+    }
+
+    // Synchronous recognize accepts at most one minute of audio
+    fn max_chunk_secs(&self) -> f64 {
+        55.0
+    }
+
+    async fn transcribe(
+        &self,
+        _app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String> {
+        let api_key = request.api_key.ok_or("Google API key not provided")?;
+
+        let language_code = language_code(request.language.as_deref())?;
+
+        // Encode audio to base64
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        let audio_base64 = STANDARD.encode(request.audio.to_wav()?);
+
+        let request_body = GoogleSpeechRequest {
+            config: GoogleSpeechConfig {
+                encoding: "LINEAR16".to_string(),
+                sample_rate_hertz: TARGET_SAMPLE_RATE as i32,
+                language_code,
+                enable_automatic_punctuation: true,
+                enable_word_time_offsets: true,
+                speech_contexts: build_speech_contexts(&request.vocabulary),
+            },
+            audio: GoogleAudioContent {
+                content: audio_base64,
+            },
+        };
+
+        let client = reqwest::Client::new();
+        let response = client
+            .post(format!(
+                "https://speech.googleapis.com/v1/speech:recognize?key={}",
+                api_key
+            ))
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to send request: {}", e))?;
+
+        let status = response.status();
+        let response_text = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+
+        if !status.is_success() {
+            if let Ok(error) = serde_json::from_str::<GoogleError>(&response_text) {
+                return Err(format!(
+                    "Google API error ({}): {}",
+                    error.error.code, error.error.message
+                ));
+            }
             return Err(format!(
-                "Google API error ({}): {}",
-                error.error.code, error.error.message
+                "Google API request failed with status {}: {}",
+                status, response_text
             ));
         }
-        return Err(format!(
-            "Google API request failed with status {}: {}",
-            status, response_text
-        ));
-    }
 
-    let speech_response: GoogleSpeechResponse = serde_json::from_str(&response_text)
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+        let speech_response: GoogleSpeechResponse = serde_json::from_str(&response_text)
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        // Each result covers a consecutive portion of the audio
+        let mut texts = Vec::new();
+        let mut segments = Vec::new();
+        let mut words = Vec::new();
+        let mut detected_language = None;
+
+        for result in speech_response.results.unwrap_or_default() {
+            if detected_language.is_none() {
+                detected_language = result.language_code.as_deref().and_then(resolve_language);
+            }
 
-    let text = speech_response
-        .results
-        .and_then(|results| results.into_iter().next())
-        .and_then(|result| result.alternatives.into_iter().next())
-        .map(|alt| alt.transcript)
-        .unwrap_or_default();
+            let Some(alternative) = result.alternatives.into_iter().next() else {
+                continue;
+            };
 
-    Ok(TranscriptionResponse {
-        text,
-        language: None,
-        segments: None,
+            let text = alternative.transcript.trim().to_string();
+
+            let result_words: Vec<TranscriptionWord> = alternative
+                .words
+                .iter()
+                .map(|w| TranscriptionWord {
+                    start: parse_duration(&w.start_time),
+                    end: parse_duration(&w.end_time),
+                    word: w.word.clone(),
+                    speaker: None,
+                })
+                .collect();
+
+            if let (Some(first), Some(last)) = (result_words.first(), result_words.last()) {
+                segments.push(TranscriptionSegment {
+                    start: first.start,
+                    end: last.end,
+                    text: text.clone(),
+                    speaker: None,
+                });
+            }
+
+            words.extend(result_words);
+            texts.push(text);
+        }
+
+        Ok(TranscriptionResponse {
+            text: texts.join(" "),
+            language: detected_language.or(request.language),
+            segments: Some(segments),
+            words: Some(words),
+        })
+    }
+}
+
+/// Convert an ISO 639-1 code to Google's format (e.g., "es" -> "es-ES")
+///
+/// The v1 API can't detect the language, and forcing one would turn other
+/// speech into nonsense in that language, so auto-detect is rejected.
+fn language_code(language: Option<&str>) -> Result<String, String> {
+    language.map(to_bcp47).ok_or_else(|| {
+        "Google can't detect the spoken language. Choose a transcription language in Settings instead of Auto-detect.".to_string()
     })
 }
+
+/// Bias recognition towards custom vocabulary
+fn build_speech_contexts(vocabulary: &[String]) -> Vec<GoogleSpeechContext> {
+    let phrases: Vec<String> = vocabulary
+        .iter()
+        .map(|w| w.trim())
+        .filter(|w| !w.is_empty() && w.chars().count() <= MAX_PHRASE_CHARS)
+        .map(String::from)
+        .collect();
+
+    if phrases.is_empty() {
+        return Vec::new();
+    }
+
+    vec![GoogleSpeechContext {
+        phrases,
+        boost: VOCABULARY_BOOST,
+    }]
+}
+
+/// Parse a protobuf Duration string (e.g., "1.300s") into seconds
+fn parse_duration(value: &str) -> f64 {
+    value.trim_end_matches('s').parse().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speech_contexts_from_vocabulary() {
+        let vocabulary = vec!["Dicta".to_string(), " ".to_string(), "x".repeat(101)];
+        let contexts = build_speech_contexts(&vocabulary);
+
+        assert_eq!(contexts.len(), 1);
+        assert_eq!(contexts[0].phrases, vec!["Dicta".to_string()]);
+        assert_eq!(contexts[0].boost, VOCABULARY_BOOST);
+
+        assert!(build_speech_contexts(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_auto_language_is_rejected() {
+        assert_eq!(language_code(Some("es")).as_deref(), Ok("es-ES"));
+        assert!(language_code(None).unwrap_err().contains("Auto-detect"));
+    }
+}
@@ -1,76 +1,91 @@
+use async_trait::async_trait;
 use std::sync::Arc;
-use tauri::{command, State};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
 use tokio::sync::Mutex;
 
-use super::TranscriptionResponse;
+use super::{
+    vocabulary_prompt, TranscriptionProvider, TranscriptionRequest, TranscriptionResponse,
+};
+use crate::features::models::engines::{whisper::WhisperDecodeOptions, EngineKind};
+use crate::features::models::models_registry::ModelProvider;
 use crate::features::models::LocalModelManager;
+use crate::features::transcription::local_model::ensure_loaded;
+use crate::features::transcription::orchestrator::find_model;
 
-// TODO: This is not used anywhere, but we keep it here for reference
-/// Converts WAV audio bytes to f32 samples for whisper-rs
+/// Local Whisper provider
 ///
-/// This function parses the WAV header and converts 16-bit PCM samples
-/// to normalized f32 values (-1.0 to 1.0)
-fn _convert_audio_to_samples(audio_data: Vec<u8>) -> Result<Vec<f32>, String> {
-    use hound::WavReader;
-    use std::io::Cursor;
+/// Uses the LocalModelManager to transcribe audio with the requested model,
+/// loading it first if a different model is loaded.
+pub struct LocalWhisperProvider;
 
-    // Create a cursor from the audio bytes
-    let cursor = Cursor::new(audio_data);
+#[async_trait]
+impl TranscriptionProvider for LocalWhisperProvider {
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::LocalWhisper
+    }
 
-    // Parse WAV file
-    let reader = WavReader::new(cursor).map_err(|e| format!("Failed to parse WAV audio: {}", e))?;
+    fn display_name(&self) -> &'static str {
+        "Local Whisper"
+    }
 
-    let spec = reader.spec();
+    fn requires_api_key(&self) -> bool {
+        false
+    }
 
-    // Verify it's 16-bit PCM (most common format)
-    if spec.bits_per_sample != 16 {
-        return Err(format!(
-            "Unsupported audio format: expected 16-bit, got {}-bit",
-            spec.bits_per_sample
-        ));
+    fn requires_download(&self) -> bool {
+        true
     }
 
-    // Convert samples to f32
-    let samples: Result<Vec<f32>, _> = reader
-        .into_samples::<i16>()
-        .map(|s| s.map(|sample| sample as f32 / 32768.0))
-        .collect();
+    fn supports_translation(&self) -> bool {
+        true
+    }
 
-    samples.map_err(|e| format!("Failed to read audio samples: {}", e))
-}
+    /// Local models can legitimately take longer than a remote request
+    fn request_timeout(&self, _audio_secs: f64) -> Option<Duration> {
+        None
+    }
 
-/// Transcribes audio using the loaded local model
-///
-/// This command uses the LocalModelManager to transcribe audio with the
-/// currently loaded model. The model must be started first using
-/// `start_local_model`.
-///
-/// # Arguments
-/// * `audio_data` - Raw audio bytes in WAV format
-/// * `model` - Model name (currently ignored, uses loaded model)
-/// * `language` - Optional language code (e.g., "en", "es")
-/// * `state` - Shared LocalModelManager state
-#[command]
-pub async fn transcribe_with_local_whisper(
-    audio_data: Vec<u8>,
-    model: Option<String>,
-    language: Option<String>,
-    state: State<'_, Arc<Mutex<LocalModelManager>>>,
-) -> Result<TranscriptionResponse, String> {
-    // Note: The model parameter is kept for API compatibility but not used
-    // The loaded model from LocalModelManager is used instead
-    let _ = model;
+    async fn transcribe(
+        &self,
+        app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String> {
+        let state = app
+            .try_state::<Arc<Mutex<LocalModelManager>>>()
+            .ok_or("Local model manager not available")?;
+
+        let model = find_model(app, &request.model_id)?;
+        let audio_data = request.audio.to_wav()?;
 
-    // Transcribe using the loaded model
-    // The LocalModelManager handles audio conversion internally
-    let mut manager = state.lock().await;
-    let text = manager
-        .transcribe(audio_data, language.clone())
-        .map_err(|e| e.to_string())?;
+        // Swap in the requested model if another one is loaded, so the
+        // transcript is never credited to a model that didn't produce it
+        let mut manager = state.lock().await;
+        ensure_loaded(app, &mut manager, &model)?;
 
-    Ok(TranscriptionResponse {
-        text,
-        language,
-        segments: None, // Can be enhanced later to return segment information
-    })
+        // Append the vocabulary to the model's own initial prompt
+        let vocabulary = vocabulary_prompt(&request.vocabulary);
+        let translate = request.options.translate;
+        let options = match manager.get_loaded_model_info(EngineKind::SpeechToText) {
+            Some(info) if vocabulary.is_some() || translate => {
+                let mut options = WhisperDecodeOptions::from_value(info.decode_options)?;
+                if let Some(vocabulary) = vocabulary {
+                    options.initial_prompt = Some(match options.initial_prompt.as_deref() {
+                        Some(prompt) if !prompt.trim().is_empty() => {
+                            format!("{} {}", prompt.trim(), vocabulary)
+                        }
+                        _ => vocabulary,
+                    });
+                }
+                options.translate = translate;
+                Some(
+                    serde_json::to_value(&options)
+                        .map_err(|e| format!("Failed to serialize decode options: {}", e))?,
+                )
+            }
+            _ => None,
+        };
+
+        manager.transcribe(audio_data, request.language, options)
+    }
 }
@@ -1,21 +1,500 @@
+pub mod assemblyai;
+pub mod deepgram;
 pub mod elevenlabs;
 pub mod google;
 pub mod local_whisper;
 pub mod openai;
+pub mod openai_compatible;
 
-// Common transcription response type
+#[cfg(test)]
+pub(crate) mod test_server;
+
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tauri::AppHandle;
+
+use super::chunking::DEFAULT_MAX_CHUNK_SECS;
+use crate::features::audio::NormalizedAudio;
+use crate::features::models::models_registry::ModelProvider;
+
+/// Pause between words (seconds) that starts a new segment
+const SEGMENT_GAP_SECS: f64 = 1.0;
+
+/// Allowance for connecting and the response on top of the audio length
+const REQUEST_TIMEOUT_BASE: Duration = Duration::from_secs(60);
 
-#[derive(Debug, Serialize, Deserialize)]
+// Common transcription response type
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptionResponse {
     pub text: String,
     pub language: Option<String>,
     pub segments: Option<Vec<TranscriptionSegment>>,
+    /// Word-level timings, for providers that report them
+    #[serde(default)]
+    pub words: Option<Vec<TranscriptionWord>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A phrase with start/end times in seconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptionSegment {
     pub start: f64,
     pub end: f64,
     pub text: String,
+    /// Provider's speaker id, when diarization was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+}
+
+/// A single word with start/end times in seconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptionWord {
+    pub start: f64,
+    pub end: f64,
+    pub word: String,
+    /// Provider's speaker id, when diarization was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+}
+
+/// Group word timings into phrase segments
+///
+/// For providers that only return words: a segment ends at sentence
+/// punctuation, a noticeable pause or a change of speaker.
+pub fn segments_from_words(words: &[TranscriptionWord]) -> Vec<TranscriptionSegment> {
+    let mut segments: Vec<TranscriptionSegment> = Vec::new();
+    let mut current: Option<TranscriptionSegment> = None;
+
+    for word in words {
+        let text = word.word.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        current = match current.take() {
+            Some(mut segment)
+                if word.start - segment.end <= SEGMENT_GAP_SECS
+                    && segment.speaker == word.speaker =>
+            {
+                segment.text.push(' ');
+                segment.text.push_str(text);
+                segment.end = word.end;
+                Some(segment)
+            }
+            previous => {
+                segments.extend(previous);
+                Some(TranscriptionSegment {
+                    start: word.start,
+                    end: word.end,
+                    text: text.to_string(),
+                    speaker: word.speaker.clone(),
+                })
+            }
+        };
+
+        if text.ends_with(['.', '?', '!']) {
+            segments.extend(current.take());
+        }
+    }
+
+    segments.extend(current);
+    segments
+}
+
+impl TranscriptionResponse {
+    /// Guarantee segments are present
+    ///
+    /// Providers that don't report timings get a single segment spanning
+    /// the whole recording.
+    pub fn with_fallback_segment(mut self, duration: f64) -> Self {
+        let has_segments = self
+            .segments
+            .as_ref()
+            .map(|segments| !segments.is_empty())
+            .unwrap_or(false);
+
+        if !has_segments {
+            self.segments = Some(vec![TranscriptionSegment {
+                start: 0.0,
+                end: duration,
+                text: self.text.trim().to_string(),
+                speaker: None,
+            }]);
+        }
+
+        self
+    }
+
+    /// Transcript as "Speaker 1: …" lines, one per change of speaker
+    ///
+    /// Speakers are numbered in order of first appearance. Returns `None`
+    /// when the provider didn't label any segment.
+    pub fn speaker_labelled_text(&self) -> Option<String> {
+        let segments = self.segments.as_deref().unwrap_or_default();
+        if segments.iter().all(|segment| segment.speaker.is_none()) {
+            return None;
+        }
+
+        let mut speakers: Vec<&str> = Vec::new();
+        let mut lines: Vec<(usize, String)> = Vec::new();
+
+        for segment in segments {
+            let text = segment.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+
+            let id = segment.speaker.as_deref().unwrap_or_default();
+            let number = match speakers.iter().position(|speaker| *speaker == id) {
+                Some(index) => index + 1,
+                None => {
+                    speakers.push(id);
+                    speakers.len()
+                }
+            };
+
+            match lines.last_mut() {
+                Some((speaker, line)) if *speaker == number => {
+                    line.push(' ');
+                    line.push_str(text);
+                }
+                _ => lines.push((number, text.to_string())),
+            }
+        }
+
+        Some(
+            lines
+                .into_iter()
+                .map(|(speaker, line)| format!("Speaker {}: {}", speaker, line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+}
+
+/// Rough character budget for a Whisper prompt (it keeps the last 224 tokens)
+const MAX_VOCABULARY_PROMPT_CHARS: usize = 800;
+
+/// Vocabulary as a Whisper-style prompt ("Dicta, Kubernetes, Tauri.")
+///
+/// Used for OpenAI's `prompt` field and local Whisper's initial prompt,
+/// which bias decoding towards the spellings that appear in it. Words past
+/// the prompt budget are dropped.
+pub fn vocabulary_prompt(vocabulary: &[String]) -> Option<String> {
+    let mut prompt = String::new();
+
+    for word in vocabulary
+        .iter()
+        .map(|w| w.trim())
+        .filter(|w| !w.is_empty())
+    {
+        if prompt.len() + word.len() + 2 > MAX_VOCABULARY_PROMPT_CHARS {
+            break;
+        }
+        if !prompt.is_empty() {
+            prompt.push_str(", ");
+        }
+        prompt.push_str(word);
+    }
+
+    if prompt.is_empty() {
+        None
+    } else {
+        prompt.push('.');
+        Some(prompt)
+    }
+}
+
+/// Provider-independent knobs for a single transcription call
+#[derive(Debug, Clone, Default)]
+pub struct TranscriptionOptions {
+    /// Sampling temperature (providers that support it)
+    pub temperature: Option<f64>,
+    /// Translate the speech into English (providers that support it)
+    pub translate: bool,
+    /// Label segments by speaker (providers that support it)
+    pub diarize: bool,
+}
+
+/// Everything a provider needs to transcribe one recording
+pub struct TranscriptionRequest {
+    /// 16kHz mono audio
+    pub audio: NormalizedAudio,
+    /// Model ID from models.json (e.g., "whisper-1", "whisper-base")
+    pub model_id: String,
+    /// ISO 639-1 language code (e.g., "en", "es")
+    pub language: Option<String>,
+    /// Custom vocabulary words to bias recognition towards
+    pub vocabulary: Vec<String>,
+    /// Decrypted API key, present when the provider requires one
+    pub api_key: Option<String>,
+    pub options: TranscriptionOptions,
+}
+
+/// Common interface for all speech-to-text providers
+///
+/// Cloud APIs and local engines implement this trait and register in
+/// `TranscriptionProviderRegistry`, which the orchestrator uses to route
+/// transcription requests by the model's provider.
+#[async_trait]
+pub trait TranscriptionProvider: Send + Sync {
+    /// The provider this implementation handles
+    fn provider(&self) -> ModelProvider;
+
+    /// Human-readable name used in error messages
+    fn display_name(&self) -> &'static str;
+
+    /// Whether an API key must be stored for the model before use
+    fn requires_api_key(&self) -> bool {
+        true
+    }
+
+    /// Whether the model must be downloaded locally before use
+    fn requires_download(&self) -> bool {
+        false
+    }
+
+    /// Whether `TranscriptionOptions::translate` is honoured
+    fn supports_translation(&self) -> bool {
+        false
+    }
+
+    /// Longest audio (seconds) sent in one request; longer recordings are
+    /// split on silence and transcribed in chunks
+    fn max_chunk_secs(&self) -> f64 {
+        DEFAULT_MAX_CHUNK_SECS
+    }
+
+    /// Longest one request may take before it counts as hung and falls back
+    ///
+    /// Scales with the audio so long chunks have time to upload and be
+    /// processed. `None` means no limit is applied: the provider runs
+    /// locally or enforces its own deadline.
+    fn request_timeout(&self, audio_secs: f64) -> Option<Duration> {
+        Some(REQUEST_TIMEOUT_BASE + Duration::from_secs_f64(audio_secs.max(0.0)))
+    }
+
+    /// Transcribe normalized audio
+    async fn transcribe(
+        &self,
+        app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String>;
+}
+
+/// Registry of all available transcription providers, keyed by provider
+pub struct TranscriptionProviderRegistry {
+    providers: HashMap<ModelProvider, Box<dyn TranscriptionProvider>>,
+}
+
+impl TranscriptionProviderRegistry {
+    /// Creates a registry with all built-in providers registered
+    pub fn new() -> Self {
+        let mut registry = Self {
+            providers: HashMap::new(),
+        };
+
+        registry.register(Box::new(openai::OpenAIProvider));
+        registry.register(Box::new(google::GoogleProvider));
+        registry.register(Box::new(elevenlabs::ElevenLabsProvider));
+        registry.register(Box::new(assemblyai::AssemblyAIProvider::new()));
+        registry.register(Box::new(deepgram::DeepgramProvider::new()));
+        registry.register(Box::new(local_whisper::LocalWhisperProvider));
+        registry.register(Box::new(openai_compatible::OpenAICompatibleProvider));
+
+        registry
+    }
+
+    /// Register a provider, replacing any existing one for the same key
+    pub fn register(&mut self, provider: Box<dyn TranscriptionProvider>) {
+        self.providers.insert(provider.provider(), provider);
+    }
+
+    /// Look up the provider for a provider identifier from models.json
+    pub fn get(&self, provider_id: &str) -> Result<&dyn TranscriptionProvider, String> {
+        let provider: ModelProvider = provider_id.parse()?;
+
+        self.providers
+            .get(&provider)
+            .map(|p| p.as_ref())
+            .ok_or_else(|| format!("Unsupported provider: {}", provider_id))
+    }
+}
+
+impl Default for TranscriptionProviderRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_resolves_built_in_providers() {
+        let registry = TranscriptionProviderRegistry::new();
+
+        for id in [
+            "openai",
+            "google",
+            "elevenlabs",
+            "assemblyai",
+            "deepgram",
+            "local-whisper",
+            "openai-compatible",
+        ] {
+            let provider = registry.get(id).unwrap();
+            assert_eq!(provider.provider().as_str(), id);
+        }
+
+        assert!(registry.get("local-whisper").unwrap().requires_download());
+        assert_eq!(
+            registry
+                .get("local-whisper")
+                .unwrap()
+                .request_timeout(600.0),
+            None
+        );
+        assert!(!registry
+            .get("openai-compatible")
+            .unwrap()
+            .requires_api_key());
+        assert!(registry.get("anthropic").is_err());
+        assert!(registry.get("unknown").is_err());
+    }
+
+    #[test]
+    fn test_request_timeout() {
+        let openai = openai::OpenAIProvider;
+        assert_eq!(openai.request_timeout(0.0), Some(REQUEST_TIMEOUT_BASE));
+        assert_eq!(
+            openai.request_timeout(600.0),
+            Some(Duration::from_secs(660))
+        );
+
+        // AssemblyAI polls for the result with its own deadline
+        assert_eq!(
+            assemblyai::AssemblyAIProvider::new().request_timeout(600.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_diarizing_providers_take_long_audio_whole() {
+        // Speaker labels only line up within one request
+        let two_hours = 2.0 * 60.0 * 60.0;
+        assert!(assemblyai::AssemblyAIProvider::new().max_chunk_secs() > two_hours);
+        assert!(deepgram::DeepgramProvider::new().max_chunk_secs() > two_hours);
+        assert!(elevenlabs::ElevenLabsProvider.max_chunk_secs() > two_hours);
+        assert_eq!(
+            openai::OpenAIProvider.max_chunk_secs(),
+            DEFAULT_MAX_CHUNK_SECS
+        );
+    }
+
+    #[test]
+    fn test_vocabulary_prompt() {
+        let vocabulary = vec![
+            "Dicta".to_string(),
+            "  ".to_string(),
+            " Kubernetes ".to_string(),
+        ];
+        assert_eq!(
+            vocabulary_prompt(&vocabulary).as_deref(),
+            Some("Dicta, Kubernetes.")
+        );
+        assert_eq!(vocabulary_prompt(&[]), None);
+
+        let long: Vec<String> = (0..500).map(|i| format!("word{}", i)).collect();
+        let prompt = vocabulary_prompt(&long).unwrap();
+        assert!(prompt.len() <= MAX_VOCABULARY_PROMPT_CHARS);
+        assert!(prompt.starts_with("word0, word1,"));
+    }
+
+    #[test]
+    fn test_segments_from_words() {
+        let word = |start: f64, end: f64, word: &str| TranscriptionWord {
+            start,
+            end,
+            word: word.to_string(),
+            speaker: None,
+        };
+
+        let segments = segments_from_words(&[
+            word(0.0, 0.3, "Hello"),
+            word(0.4, 0.8, "there."),
+            word(0.9, 1.2, "Next"),
+            word(1.3, 1.6, "phrase"),
+            word(3.0, 3.4, "after"),
+            word(3.5, 3.9, "pause"),
+        ]);
+
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].text, "Hello there.");
+        assert_eq!(segments[0].end, 0.8);
+        assert_eq!(segments[1].text, "Next phrase");
+        assert_eq!(segments[2].start, 3.0);
+        assert_eq!(segments[2].text, "after pause");
+    }
+
+    #[test]
+    fn test_speaker_labelled_text() {
+        let word = |start: f64, word: &str, speaker: &str| TranscriptionWord {
+            start,
+            end: start + 0.3,
+            word: word.to_string(),
+            speaker: Some(speaker.to_string()),
+        };
+
+        let words = [
+            word(0.0, "Hi.", "speaker_1"),
+            word(0.4, "Shall", "speaker_1"),
+            word(0.8, "we", "speaker_1"),
+            word(1.2, "Yes", "speaker_0"),
+            word(1.6, "please.", "speaker_0"),
+            word(2.0, "Great.", "speaker_1"),
+        ];
+        let segments = segments_from_words(&words);
+        assert_eq!(segments.len(), 4);
+        assert_eq!(segments[1].text, "Shall we");
+        assert_eq!(segments[2].speaker.as_deref(), Some("speaker_0"));
+
+        let response = TranscriptionResponse {
+            text: "Hi. Shall we Yes please. Great.".to_string(),
+            language: None,
+            segments: Some(segments),
+            words: None,
+        };
+        assert_eq!(
+            response.speaker_labelled_text().unwrap(),
+            "Speaker 1: Hi. Shall we\nSpeaker 2: Yes please.\nSpeaker 1: Great."
+        );
+
+        let plain = TranscriptionResponse {
+            text: "hello".to_string(),
+            language: None,
+            segments: None,
+            words: None,
+        }
+        .with_fallback_segment(1.0);
+        assert_eq!(plain.speaker_labelled_text(), None);
+    }
+
+    #[test]
+    fn test_fallback_segment_spans_audio() {
+        let response = TranscriptionResponse {
+            text: " hello world ".to_string(),
+            language: None,
+            segments: None,
+            words: None,
+        }
+        .with_fallback_segment(2.5);
+
+        let segments = response.segments.unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].end, 2.5);
+        assert_eq!(segments[0].text, "hello world");
+    }
 }
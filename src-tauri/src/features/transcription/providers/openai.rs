@@ -1,8 +1,13 @@
+use async_trait::async_trait;
 use reqwest::multipart::{Form, Part};
 use serde::{Deserialize, Serialize};
-use tauri::command;
+use tauri::AppHandle;
 
-use super::TranscriptionResponse;
+use super::{
+    vocabulary_prompt, TranscriptionProvider, TranscriptionRequest, TranscriptionResponse,
+};
+use crate::features::models::models_registry::ModelProvider;
+use crate::features::transcription::language::from_language_name;
 
 #[derive(Debug, Serialize, Deserialize)]
 struct OpenAIError {
@@ -16,44 +21,112 @@ struct OpenAIErrorDetail {
     error_type: String,
 }
 
-#[command]
-pub async fn transcribe_with_openai(
-    audio_data: Vec<u8>,
-    api_key: String,
-    model: Option<String>,
-    language: Option<String>,
-    temperature: Option<f64>,
-) -> Result<TranscriptionResponse, String> {
-    let model = model.unwrap_or_else(|| "whisper-1".to_string());
+const OPENAI_API_URL: &str = "https://api.openai.com/v1";
+
+/// OpenAI Whisper API provider
+///
+/// API Reference: https://platform.openai.com/docs/api-reference/audio/createTranscription
+pub struct OpenAIProvider;
+
+#[async_trait]
+impl TranscriptionProvider for OpenAIProvider {
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::OpenAI
+    }
+
+    fn display_name(&self) -> &'static str {
+        "OpenAI"
+    }
+
+    fn supports_translation(&self) -> bool {
+        true
+    }
+
+    async fn transcribe(
+        &self,
+        _app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String> {
+        let api_key = request
+            .api_key
+            .clone()
+            .ok_or("OpenAI API key not provided")?;
+        let model = request.model_id.clone();
+
+        transcribe_multipart(
+            OPENAI_API_URL,
+            Some(&api_key),
+            &model,
+            request,
+            self.display_name(),
+        )
+        .await
+    }
+}
 
+/// Post audio to an OpenAI-style `/audio/transcriptions` endpoint
+///
+/// Shared by the OpenAI provider and user-defined OpenAI-compatible
+/// endpoints (faster-whisper, whisper.cpp server, Groq, ...). With
+/// `options.translate` the audio goes to `/audio/translations` instead,
+/// which always returns English and takes no language or timestamp options.
+pub async fn transcribe_multipart(
+    base_url: &str,
+    api_key: Option<&str>,
+    model: &str,
+    request: TranscriptionRequest,
+    provider_name: &str,
+) -> Result<TranscriptionResponse, String> {
     // Create multipart form
-    let audio_part = Part::bytes(audio_data)
+    let audio_part = Part::bytes(request.audio.to_wav()?)
         .file_name("audio.wav")
         .mime_str("audio/wav")
         .map_err(|e| format!("Failed to create audio part: {}", e))?;
 
+    let translate = request.options.translate;
+
     let mut form = Form::new()
         .part("file", audio_part)
-        .text("model", model)
+        .text("model", model.to_string())
         .text("response_format", "verbose_json");
 
-    if let Some(lang) = language {
-        form = form.text("language", lang);
+    if !translate {
+        form = form
+            .text("timestamp_granularities[]", "segment")
+            .text("timestamp_granularities[]", "word");
+
+        if let Some(lang) = request.language {
+            form = form.text("language", lang);
+        }
+    }
+
+    // Whisper follows the spelling of words that appear in the prompt
+    if let Some(prompt) = vocabulary_prompt(&request.vocabulary) {
+        form = form.text("prompt", prompt);
     }
 
-    if let Some(temp) = temperature {
+    if let Some(temp) = request.options.temperature {
         form = form.text("temperature", temp.to_string());
     }
 
-    // Make request to OpenAI API
     let client = reqwest::Client::new();
-    let response = client
-        .post("https://api.openai.com/v1/audio/transcriptions")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .multipart(form)
+    let route = if translate {
+        "translations"
+    } else {
+        "transcriptions"
+    };
+    let mut http_request = client
+        .post(format!("{}/audio/{}", base_url, route))
+        .multipart(form);
+
+    if let Some(key) = api_key {
+        http_request = http_request.header("Authorization", format!("Bearer {}", key));
+    }
+
+    let response = http_request
         .send()
         .await
-        .map_err(|e| format!("Failed to send request: {}", e))?;
+        .map_err(|e| format!("Failed to send request to {}: {}", provider_name, e))?;
 
     let status = response.status();
     let response_text = response
@@ -65,19 +138,142 @@ pub async fn transcribe_with_openai(
         // Try to parse error response
         if let Ok(error) = serde_json::from_str::<OpenAIError>(&response_text) {
             return Err(format!(
-                "OpenAI API error ({}): {}",
-                status, error.error.message
+                "{} API error ({}): {}",
+                provider_name, status, error.error.message
             ));
         }
         return Err(format!(
-            "OpenAI API request failed with status {}: {}",
-            status, response_text
+            "{} API request failed with status {}: {}",
+            provider_name, status, response_text
         ));
     }
 
-    // Parse successful response
-    let transcription: TranscriptionResponse = serde_json::from_str(&response_text)
+    // Parse successful response (verbose_json includes segments and words)
+    let mut transcription: TranscriptionResponse = serde_json::from_str(&response_text)
         .map_err(|e| format!("Failed to parse transcription response: {}", e))?;
 
+    // The reported language is the output language (English), not the spoken one
+    if translate {
+        transcription.language = None;
+    }
+
+    // verbose_json names the language ("german"); the pipeline stores codes
+    transcription.language = transcription.language.as_deref().map(from_language_name);
+
     Ok(transcription)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_server::TestServer;
+    use super::super::TranscriptionOptions;
+    use super::*;
+    use crate::features::audio::NormalizedAudio;
+
+    fn request() -> TranscriptionRequest {
+        TranscriptionRequest {
+            audio: NormalizedAudio {
+                samples: vec![0.0; 1600],
+            },
+            model_id: "openai-compatible-1".to_string(),
+            language: Some("de".to_string()),
+            vocabulary: vec!["Dicta".to_string(), "Kubernetes".to_string()],
+            api_key: None,
+            options: TranscriptionOptions::default(),
+        }
+    }
+
+    #[test]
+    fn test_custom_endpoint_without_key() {
+        let server = TestServer::start(|_| {
+            (
+                200,
+                r#"{"text":"Hallo","language":"german","segments":[{"id":0,"start":0.0,"end":0.1,"text":"Hallo"}],"words":[{"word":"Hallo","start":0.0,"end":0.1}]}"#
+                    .to_string(),
+            )
+        });
+
+        let response = tauri::async_runtime::block_on(transcribe_multipart(
+            &format!("{}/v1", server.base_url),
+            None,
+            "whisper-large-v3",
+            request(),
+            "Local server",
+        ))
+        .unwrap();
+
+        assert_eq!(response.text, "Hallo");
+        assert_eq!(response.language.as_deref(), Some("de"));
+        assert_eq!(response.segments.unwrap().len(), 1);
+        assert_eq!(response.words.unwrap()[0].word, "Hallo");
+
+        let recorded = &server.requests()[0];
+        assert_eq!(recorded.route(), "/v1/audio/transcriptions");
+        assert!(recorded.header("authorization").is_none());
+
+        let body = String::from_utf8_lossy(&recorded.body);
+        assert!(body.contains("whisper-large-v3"));
+        assert!(body.contains("name=\"language\""));
+        assert!(body.contains("name=\"timestamp_granularities[]\""));
+        assert!(body.contains("name=\"prompt\""));
+        assert!(body.contains("Dicta, Kubernetes."));
+    }
+
+    #[test]
+    fn test_translate_uses_translations_endpoint() {
+        let server = TestServer::start(|_| {
+            (
+                200,
+                r#"{"text":"Hello","language":"english","segments":[]}"#.to_string(),
+            )
+        });
+
+        let mut request = request();
+        request.options.translate = true;
+
+        let response = tauri::async_runtime::block_on(transcribe_multipart(
+            &format!("{}/v1", server.base_url),
+            None,
+            "whisper-1",
+            request,
+            "OpenAI",
+        ))
+        .unwrap();
+
+        assert_eq!(response.text, "Hello");
+        assert_eq!(response.language, None);
+
+        let recorded = &server.requests()[0];
+        assert_eq!(recorded.route(), "/v1/audio/translations");
+
+        let body = String::from_utf8_lossy(&recorded.body);
+        assert!(!body.contains("name=\"language\""));
+        assert!(!body.contains("name=\"timestamp_granularities[]\""));
+    }
+
+    #[test]
+    fn test_error_names_provider() {
+        let server = TestServer::start(|_| {
+            (
+                401,
+                r#"{"error":{"message":"Invalid API Key","type":"invalid_request_error"}}"#
+                    .to_string(),
+            )
+        });
+
+        let error = tauri::async_runtime::block_on(transcribe_multipart(
+            &server.base_url,
+            Some("bad-key"),
+            "whisper-large-v3",
+            request(),
+            "Groq",
+        ))
+        .unwrap_err();
+
+        assert!(error.starts_with("Groq API error (401"));
+        assert_eq!(
+            server.requests()[0].header("authorization"),
+            Some("Bearer bad-key")
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use tauri::AppHandle;
+
+use super::openai::transcribe_multipart;
+use super::{TranscriptionProvider, TranscriptionRequest, TranscriptionResponse};
+use crate::features::models::custom_endpoints::get_custom_endpoint;
+use crate::features::models::models_registry::ModelProvider;
+use crate::features::security::get_api_key_internal;
+
+/// User-defined OpenAI-compatible speech-to-text endpoints
+///
+/// Each endpoint's base URL and model name come from models.json; the API
+/// key is optional since self-hosted servers usually don't need one.
+pub struct OpenAICompatibleProvider;
+
+#[async_trait]
+impl TranscriptionProvider for OpenAICompatibleProvider {
+    fn provider(&self) -> ModelProvider {
+        ModelProvider::OpenAICompatible
+    }
+
+    fn display_name(&self) -> &'static str {
+        "OpenAI-compatible endpoint"
+    }
+
+    fn requires_api_key(&self) -> bool {
+        false
+    }
+
+    // Assumes the server also implements `/audio/translations`
+    fn supports_translation(&self) -> bool {
+        true
+    }
+
+    async fn transcribe(
+        &self,
+        app: &AppHandle,
+        request: TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, String> {
+        let endpoint = get_custom_endpoint(app, &request.model_id)?;
+        let api_key = get_api_key_internal(app, &endpoint.id).await.ok();
+
+        transcribe_multipart(
+            &endpoint.base_url,
+            api_key.as_deref(),
+            &endpoint.model,
+            request,
+            &endpoint.name,
+        )
+        .await
+    }
+}
@@ -0,0 +1,269 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::Instant;
+use tauri::{command, AppHandle, Emitter};
+use ts_rs::TS;
+
+use super::language::{resolve_language, AUTO_LANGUAGE, TRANSLATION_TARGET_LANGUAGE};
+use super::local_model::{loaded_local_model, restore_local_model};
+use super::orchestrator::{
+    find_model, get_settings, speaker_diarization_enabled, transcribe_with_provider, SelectedModel,
+};
+use super::orchestrator_helpers::{
+    apply_ai_post_processing, create_empty_prompt_context, get_model_name, get_vocabulary_words,
+};
+use super::providers::TranscriptionOptions;
+use super::retry_queue;
+use crate::features::audio::NormalizedAudio;
+use crate::features::recordings::metadata::{
+    RecordingMetadata, RecordingSegments, RecordingStatus, TranscriptRevision, TranslationInfo,
+};
+use crate::features::recordings::storage::{
+    get_recordings_dir, read_metadata, save_metadata, save_segments,
+};
+use crate::utils::logger;
+
+/// Options for `retranscribe_recording`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(
+    export,
+    export_to = "../../src/features/transcriptions/types/generated/"
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RetranscribeOptions {
+    /// Run AI post-processing on the new transcript
+    #[serde(default)]
+    pub post_process: bool,
+    /// Language code or "auto"; defaults to the recording's language
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Sampling temperature (providers that support it)
+    #[serde(default)]
+    pub temperature: Option<f64>,
+    /// Translate the speech into English instead of transcribing it
+    #[serde(default)]
+    pub translate: bool,
+}
+
+/// Transcribe a saved recording again with another model
+///
+/// The new transcript becomes the recording's current result and is
+/// appended to `revisions` in meta.json. The first time a recording is
+/// revised, its original transcript is stored as the first revision.
+#[command]
+pub async fn retranscribe_recording(
+    app: AppHandle,
+    timestamp: i64,
+    model_id: String,
+    options: Option<RetranscribeOptions>,
+) -> Result<RecordingMetadata, String> {
+    let options = options.unwrap_or_default();
+    let start_time = Instant::now();
+
+    let recording_folder = get_recordings_dir(&app)?.join(timestamp.to_string());
+    if !recording_folder.exists() {
+        return Err(format!("Recording not found: {}", timestamp));
+    }
+
+    let mut metadata = read_metadata(&recording_folder)?;
+
+    let audio_data = fs::read(recording_folder.join("audio.wav"))
+        .map_err(|e| format!("Failed to read audio file: {}", e))?;
+    let audio = NormalizedAudio::from_wav(&audio_data)?;
+
+    let model = find_model(&app, &model_id)?;
+    let language_setting = options
+        .language
+        .clone()
+        .unwrap_or_else(|| metadata.language_selected.clone());
+    let language = resolve_language(&language_setting);
+    let vocabulary = get_vocabulary_words(&app)?.unwrap_or_default();
+
+    logger::info(&format!(
+        "Re-transcribing recording {} with {}",
+        timestamp, model.id
+    ));
+
+    // A local model is loaded by its provider; put the user's model back
+    // once the recording has been transcribed
+    let previous_local_model = loaded_local_model(&app).await;
+    let transcription = transcribe_with_provider(
+        &app,
+        audio,
+        &model,
+        language.clone(),
+        vocabulary.clone(),
+        TranscriptionOptions {
+            temperature: options.temperature,
+            translate: options.translate,
+            diarize: speaker_diarization_enabled(&app)?,
+        },
+    )
+    .await;
+    restore_local_model(&app, previous_local_model).await;
+    let transcription = transcription?;
+
+    let raw_transcription = transcription
+        .speaker_labelled_text()
+        .unwrap_or_else(|| transcription.text.clone());
+    if raw_transcription.trim().is_empty() {
+        return Err(format!("{} returned an empty transcript", model.id));
+    }
+
+    let translation = options.translate.then(|| TranslationInfo {
+        source_language: transcription.language.clone().or(language.clone()),
+        target_language: TRANSLATION_TARGET_LANGUAGE.to_string(),
+    });
+    let effective_language = match &translation {
+        Some(translation) => translation.target_language.clone(),
+        None => transcription
+            .language
+            .clone()
+            .or(language.clone())
+            .unwrap_or_else(|| AUTO_LANGUAGE.to_string()),
+    };
+
+    let post_processing = if options.post_process {
+        let settings = get_settings(&app)?;
+        Some(
+            apply_ai_post_processing(
+                &app,
+                &raw_transcription,
+                &metadata.focused_app_name,
+                &effective_language,
+                &settings,
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
+    // Keep the original transcript before overwriting it
+    if metadata.revisions.is_empty() && metadata.status == RecordingStatus::Completed {
+        let original = TranscriptRevision::from_metadata(&metadata, metadata.datetime.clone());
+        metadata.revisions.push(original);
+    }
+
+    metadata.raw_result = raw_transcription.clone();
+    match post_processing {
+        Some(result) => {
+            metadata.result = result.final_text;
+            metadata.post_processed_result = Some(result.post_processed_text);
+            metadata.post_processing_enabled = true;
+            metadata.post_processing_model_id = Some(result.model_id);
+            metadata.post_processing_model_name = result.model_name;
+            metadata.post_processing_provider = result.provider;
+            metadata.style_applied = result.style_applied;
+            metadata.style_category = result.style_category;
+            metadata.prompt_context = result.prompt_context;
+        }
+        None => {
+            metadata.result = raw_transcription;
+            metadata.post_processed_result = None;
+            metadata.post_processing_enabled = false;
+            metadata.post_processing_model_id = None;
+            metadata.post_processing_model_name = None;
+            metadata.post_processing_provider = None;
+            metadata.style_applied = None;
+            metadata.style_category = None;
+            metadata.prompt_context = create_empty_prompt_context(&effective_language);
+            metadata.prompt_context.vocabulary_used = vocabulary;
+        }
+    }
+
+    metadata.transcription_attempts = 1;
+    metadata.language_selected = language.unwrap_or_else(|| AUTO_LANGUAGE.to_string());
+    metadata.detected_language = transcription.language.clone();
+    metadata.translation = translation;
+    metadata.processing_time = start_time.elapsed().as_millis() as u64;
+    metadata.status = RecordingStatus::Completed;
+    metadata.error = None;
+
+    let created_at = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S").to_string();
+    record_revision(&mut metadata, &model, created_at);
+
+    save_metadata(&recording_folder, &metadata)?;
+
+    if let Err(e) = save_segments(&recording_folder, &RecordingSegments::from(&transcription)) {
+        logger::warn(&format!("Failed to save segments: {}", e));
+    }
+
+    // A failed recording no longer needs automatic retries
+    if let Err(e) = retry_queue::dequeue(&app, timestamp) {
+        logger::warn(&format!("Failed to update retry queue: {}", e));
+    }
+
+    let _ = app.emit("transcriptions-changed", ());
+
+    Ok(metadata)
+}
+
+/// Credit the current transcript to `model` and append it as a revision
+fn record_revision(metadata: &mut RecordingMetadata, model: &SelectedModel, created_at: String) {
+    metadata.model_key = model.id.clone();
+    metadata.model_name = get_model_name(model);
+    metadata.provider = model.provider.clone();
+
+    let revision = TranscriptRevision::from_metadata(metadata, created_at);
+    metadata.revisions.push(revision);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(model_key: &str) -> RecordingMetadata {
+        RecordingMetadata::new(
+            "hello world".to_string(),
+            "Hello world.".to_string(),
+            None,
+            1_700_000_000_000,
+            2_000.0,
+            120,
+            model_key.to_string(),
+            "Whisper Tiny".to_string(),
+            "local-whisper".to_string(),
+            None,
+            None,
+            None,
+            "en".to_string(),
+            "default".to_string(),
+            "Notes".to_string(),
+            "personal".to_string(),
+            false,
+            None,
+            None,
+            create_empty_prompt_context("en"),
+        )
+    }
+
+    #[test]
+    fn test_revision_names_model_that_ran() {
+        let mut metadata = recording("whisper-tiny");
+        let original = TranscriptRevision::from_metadata(&metadata, metadata.datetime.clone());
+        metadata.revisions.push(original);
+
+        let medium = SelectedModel {
+            id: "whisper-medium".to_string(),
+            provider: "local-whisper".to_string(),
+            path: Some("/models/whisper/ggml-medium.bin".to_string()),
+        };
+        metadata.raw_result = "hello, world".to_string();
+        record_revision(&mut metadata, &medium, "2026-01-01T00:00:00".to_string());
+
+        assert_eq!(metadata.model_key, "whisper-medium");
+        assert_eq!(metadata.model_name, "Whisper Medium");
+
+        let revisions: Vec<&str> = metadata
+            .revisions
+            .iter()
+            .map(|revision| revision.model_key.as_str())
+            .collect();
+        assert_eq!(revisions, ["whisper-tiny", "whisper-medium"]);
+
+        let latest = metadata.revisions.last().unwrap();
+        assert_eq!(latest.raw_result, "hello, world");
+        assert_eq!(latest.model_name, "Whisper Medium");
+    }
+}
@@ -0,0 +1,505 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, Manager};
+use tauri_plugin_store::StoreExt;
+
+use super::language::{AUTO_LANGUAGE, TRANSLATION_TARGET_LANGUAGE};
+use super::orchestrator::{transcribe_and_process, TranscribeRequest, TranscriptionRecord};
+use super::orchestrator_helpers::create_empty_prompt_context;
+use crate::features::recordings::metadata::{RecordingMetadata, RecordingStatus, TranslationInfo};
+use crate::features::recordings::storage::{get_recordings_dir, read_metadata, save_metadata};
+use crate::utils::logger;
+
+/// Store holding recordings waiting to be re-transcribed
+const RETRY_QUEUE_STORE: &str = "retry_queue.json";
+const QUEUE_KEY: &str = "pending";
+
+/// How often the worker looks for due retries
+const WORKER_INTERVAL: Duration = Duration::from_secs(30);
+
+/// First automatic retry delay; doubled after every failed retry
+const BASE_RETRY_DELAY_MS: i64 = 30_000;
+
+/// Longest wait between automatic retries
+const MAX_RETRY_DELAY_MS: i64 = 30 * 60 * 1000;
+
+/// Automatic retries before a recording is left for a manual retry
+const MAX_AUTOMATIC_RETRIES: u32 = 20;
+
+/// A failed recording waiting for another transcription attempt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedRecording {
+    pub timestamp: i64,
+    /// Automatic retries made so far
+    pub attempts: u32,
+    /// Unix time in ms when the next automatic retry is due
+    pub next_attempt_at: i64,
+}
+
+/// Failed recordings waiting for a retry, and the retries running now
+///
+/// Held as Tauri state. The queue is only read and written with `pending`
+/// locked, so the worker and a manual retry can't overwrite each other's
+/// changes, and a recording is retried by one caller at a time.
+pub struct RetryQueue {
+    /// Loaded from the store on first use; every change is saved back
+    pending: Mutex<Option<Vec<QueuedRecording>>>,
+    in_flight: Mutex<HashSet<i64>>,
+}
+
+impl RetryQueue {
+    pub fn new() -> Self {
+        Self {
+            pending: Mutex::new(None),
+            in_flight: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Claim a recording for retrying, or `None` if it is already running
+    fn start(&self, timestamp: i64) -> Option<RetryGuard<'_>> {
+        let claimed = self.in_flight.lock().unwrap().insert(timestamp);
+        claimed.then(|| RetryGuard {
+            queue: self,
+            timestamp,
+        })
+    }
+}
+
+impl Default for RetryQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Releases a recording claimed with `RetryQueue::start` when dropped
+struct RetryGuard<'a> {
+    queue: &'a RetryQueue,
+    timestamp: i64,
+}
+
+impl Drop for RetryGuard<'_> {
+    fn drop(&mut self) {
+        self.queue.in_flight.lock().unwrap().remove(&self.timestamp);
+    }
+}
+
+/// Delay before automatic retry number `attempts + 1`: 30s, 1m, 2m, ... 30m
+fn retry_delay_ms(attempts: u32) -> i64 {
+    BASE_RETRY_DELAY_MS
+        .saturating_mul(2i64.saturating_pow(attempts))
+        .min(MAX_RETRY_DELAY_MS)
+}
+
+/// Add a recording to the queue unless it is already waiting
+fn enqueue(queue: &mut Vec<QueuedRecording>, timestamp: i64, now: i64) {
+    if queue.iter().any(|entry| entry.timestamp == timestamp) {
+        return;
+    }
+
+    queue.push(QueuedRecording {
+        timestamp,
+        attempts: 0,
+        next_attempt_at: now + retry_delay_ms(0),
+    });
+}
+
+/// Push a recording's next retry back after a failure
+///
+/// Returns false (and drops the entry) once automatic retries are used up.
+fn reschedule(queue: &mut Vec<QueuedRecording>, timestamp: i64, now: i64) -> bool {
+    let Some(index) = queue.iter().position(|entry| entry.timestamp == timestamp) else {
+        enqueue(queue, timestamp, now);
+        return true;
+    };
+
+    let entry = &mut queue[index];
+    entry.attempts += 1;
+
+    if entry.attempts >= MAX_AUTOMATIC_RETRIES {
+        queue.remove(index);
+        return false;
+    }
+
+    entry.next_attempt_at = now + retry_delay_ms(entry.attempts);
+    true
+}
+
+fn load_queue(app: &AppHandle) -> Result<Vec<QueuedRecording>, String> {
+    let store = app
+        .store(RETRY_QUEUE_STORE)
+        .map_err(|e| format!("Failed to access retry queue: {}", e))?;
+
+    match store.get(QUEUE_KEY) {
+        Some(value) => {
+            serde_json::from_value(value).map_err(|e| format!("Failed to parse retry queue: {}", e))
+        }
+        None => Ok(Vec::new()),
+    }
+}
+
+fn save_queue(app: &AppHandle, queue: &[QueuedRecording]) -> Result<(), String> {
+    let store = app
+        .store(RETRY_QUEUE_STORE)
+        .map_err(|e| format!("Failed to access retry queue: {}", e))?;
+
+    let value = serde_json::to_value(queue)
+        .map_err(|e| format!("Failed to serialize retry queue: {}", e))?;
+
+    store.set(QUEUE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save retry queue: {}", e))
+}
+
+/// Run `f` on the queue while it is locked, loading it on first use
+fn with_queue<R>(
+    app: &AppHandle,
+    f: impl FnOnce(&mut Vec<QueuedRecording>) -> R,
+) -> Result<R, String> {
+    let state = app.state::<RetryQueue>();
+    let mut pending = state.pending.lock().unwrap();
+
+    if pending.is_none() {
+        *pending = Some(load_queue(app)?);
+    }
+
+    Ok(f(pending.get_or_insert_with(Vec::new)))
+}
+
+/// Change the queue and save it, all under the lock
+fn update_queue<R>(
+    app: &AppHandle,
+    update: impl FnOnce(&mut Vec<QueuedRecording>) -> R,
+) -> Result<R, String> {
+    with_queue(app, |queue| {
+        let result = update(queue);
+        save_queue(app, queue).map(|()| result)
+    })?
+}
+
+/// Keep a recording whose transcription failed and queue it for retry
+///
+/// Writes meta.json with `status: failed` and the error. Recordings that
+/// already have metadata (a failed retry) only get their error updated.
+pub fn record_failed_transcription(
+    app: &AppHandle,
+    timestamp: i64,
+    duration: Option<f64>,
+    language: Option<String>,
+    recording_device: Option<String>,
+    translate: bool,
+    error: &str,
+) -> Result<(), String> {
+    if !mark_failed(
+        app,
+        timestamp,
+        duration,
+        language,
+        recording_device,
+        translate,
+        error,
+    )? {
+        return Ok(());
+    }
+
+    let now = chrono::Utc::now().timestamp_millis();
+    update_queue(app, |queue| enqueue(queue, timestamp, now))?;
+
+    let _ = app.emit("transcriptions-changed", ());
+    Ok(())
+}
+
+/// Write `status: failed` and the error to meta.json
+///
+/// Returns false when the transcript was already saved, i.e. the failure
+/// came from a later step such as pasting.
+fn mark_failed(
+    app: &AppHandle,
+    timestamp: i64,
+    duration: Option<f64>,
+    language: Option<String>,
+    recording_device: Option<String>,
+    translate: bool,
+    error: &str,
+) -> Result<bool, String> {
+    let recording_folder = get_recordings_dir(app)?.join(timestamp.to_string());
+    if !recording_folder.exists() {
+        return Err(format!("Recording folder not found: {}", timestamp));
+    }
+
+    let mut metadata = match read_metadata(&recording_folder) {
+        Ok(existing)
+            if existing.status == RecordingStatus::Completed && !existing.raw_result.is_empty() =>
+        {
+            return Ok(false)
+        }
+        Ok(existing) => existing,
+        // meta.json is still the empty placeholder from start_recording
+        Err(_) => RecordingMetadata::new(
+            String::new(),
+            String::new(),
+            None,
+            timestamp,
+            duration.unwrap_or(0.0) * 1000.0, // Convert to ms
+            0,
+            selected_model_id(app).unwrap_or_default(),
+            String::new(),
+            String::new(),
+            None,
+            None,
+            None,
+            language.unwrap_or_else(|| AUTO_LANGUAGE.to_string()),
+            recording_device.unwrap_or_else(|| "Unknown".to_string()),
+            "Unknown".to_string(),
+            String::new(),
+            false,
+            None,
+            None,
+            create_empty_prompt_context(AUTO_LANGUAGE),
+        ),
+    };
+
+    // Remembered so a retry translates again
+    if translate && metadata.translation.is_none() {
+        metadata.translation = Some(TranslationInfo {
+            source_language: None,
+            target_language: TRANSLATION_TARGET_LANGUAGE.to_string(),
+        });
+    }
+
+    metadata.status = RecordingStatus::Failed;
+    metadata.error = Some(error.to_string());
+    save_metadata(&recording_folder, &metadata)?;
+
+    Ok(true)
+}
+
+fn selected_model_id(app: &AppHandle) -> Option<String> {
+    app.store("settings")
+        .ok()?
+        .get("settings")?
+        .get("transcription")?
+        .get("speechToTextModelId")?
+        .as_str()
+        .map(String::from)
+}
+
+/// Make every queued recording due on the worker's next pass
+///
+/// Called after a successful transcription, since the provider or network
+/// that caused earlier failures is evidently back.
+pub fn retry_queued_soon(app: &AppHandle) {
+    let now = chrono::Utc::now().timestamp_millis();
+    let result = update_queue(app, |queue| {
+        for entry in queue.iter_mut() {
+            entry.next_attempt_at = entry.next_attempt_at.min(now);
+        }
+    });
+
+    if let Err(e) = result {
+        logger::warn(&format!("Failed to update retry queue: {}", e));
+    }
+}
+
+/// Re-transcribe a failed recording from its saved audio
+async fn retry_now(app: &AppHandle, timestamp: i64) -> Result<Option<TranscriptionRecord>, String> {
+    let state = app.state::<RetryQueue>();
+    let Some(_retrying) = state.start(timestamp) else {
+        return Err(format!("Recording {} is already being retried", timestamp));
+    };
+
+    let recording_folder = get_recordings_dir(app)?.join(timestamp.to_string());
+
+    // Deleted, or transcribed by an earlier retry: nothing left to do
+    let metadata = match read_metadata(&recording_folder) {
+        Ok(metadata) if metadata.status == RecordingStatus::Failed => metadata,
+        Ok(_) => {
+            dequeue(app, timestamp)?;
+            return Err(format!(
+                "Recording {} has already been transcribed",
+                timestamp
+            ));
+        }
+        Err(e) => {
+            dequeue(app, timestamp)?;
+            return Err(format!("Recording {} can't be retried: {}", timestamp, e));
+        }
+    };
+
+    let audio_data = match std::fs::read(recording_folder.join("audio.wav")) {
+        Ok(audio_data) => audio_data,
+        Err(e) => return retry_failed(app, timestamp, format!("Failed to read audio file: {}", e)),
+    };
+
+    let request = TranscribeRequest {
+        audio_data,
+        timestamp,
+        duration: Some(metadata.duration / 1000.0), // Stored in ms
+        language: Some(metadata.language_selected.clone()),
+        recording_device: Some(metadata.recording_device.clone()),
+        background: true,
+        translate: metadata.translation.is_some(),
+        model_id: None,
+    };
+
+    match transcribe_and_process(request, app.clone()).await {
+        Ok(Some(record)) => {
+            dequeue(app, timestamp)?;
+            logger::info(&format!("Retried recording {} successfully", timestamp));
+            Ok(Some(record))
+        }
+        Ok(None) => {
+            // Retrying won't make speech appear, so stop retrying. Silent
+            // recordings are already deleted by transcribe_and_process.
+            dequeue(app, timestamp)?;
+            if recording_folder.exists() {
+                mark_failed(
+                    app,
+                    timestamp,
+                    None,
+                    None,
+                    None,
+                    false,
+                    "No speech detected",
+                )?;
+                let _ = app.emit("transcriptions-changed", ());
+            }
+            Ok(None)
+        }
+        Err(e) => retry_failed(app, timestamp, e),
+    }
+}
+
+/// Record a failed retry and schedule the next one
+fn retry_failed(
+    app: &AppHandle,
+    timestamp: i64,
+    error: String,
+) -> Result<Option<TranscriptionRecord>, String> {
+    record_failed_transcription(app, timestamp, None, None, None, false, &error)?;
+
+    let now = chrono::Utc::now().timestamp_millis();
+    let still_queued = update_queue(app, |queue| reschedule(queue, timestamp, now))?;
+
+    if !still_queued {
+        logger::warn(&format!(
+            "Giving up automatic retries for recording {}",
+            timestamp
+        ));
+    }
+
+    Err(error)
+}
+
+/// Stop retrying a recording (e.g. it was transcribed another way)
+pub fn dequeue(app: &AppHandle, timestamp: i64) -> Result<(), String> {
+    update_queue(app, |queue| {
+        queue.retain(|entry| entry.timestamp != timestamp)
+    })
+}
+
+/// Retry transcribing a failed recording now
+#[command]
+pub async fn retry_recording(
+    app: AppHandle,
+    timestamp: i64,
+) -> Result<Option<TranscriptionRecord>, String> {
+    retry_now(&app, timestamp).await
+}
+
+/// Retry queued recordings in the background as they come due
+pub fn start_retry_worker(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(WORKER_INTERVAL).await;
+
+            let now = chrono::Utc::now().timestamp_millis();
+            let due = with_queue(&app, |queue| {
+                queue
+                    .iter()
+                    .filter(|entry| entry.next_attempt_at <= now)
+                    .map(|entry| entry.timestamp)
+                    .collect::<Vec<i64>>()
+            });
+            let due = match due {
+                Ok(due) => due,
+                Err(e) => {
+                    logger::warn(&format!("Failed to read retry queue: {}", e));
+                    continue;
+                }
+            };
+
+            for timestamp in due {
+                if let Err(e) = retry_now(&app, timestamp).await {
+                    logger::warn(&format!(
+                        "Automatic retry of recording {} failed: {}",
+                        timestamp, e
+                    ));
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_backs_off_to_limit() {
+        assert_eq!(retry_delay_ms(0), 30_000);
+        assert_eq!(retry_delay_ms(1), 60_000);
+        assert_eq!(retry_delay_ms(3), 240_000);
+        assert_eq!(retry_delay_ms(10), MAX_RETRY_DELAY_MS);
+    }
+
+    #[test]
+    fn test_enqueue_and_reschedule() {
+        let mut queue = Vec::new();
+        enqueue(&mut queue, 1, 1_000);
+        enqueue(&mut queue, 1, 5_000);
+
+        assert_eq!(
+            queue,
+            vec![QueuedRecording {
+                timestamp: 1,
+                attempts: 0,
+                next_attempt_at: 31_000,
+            }]
+        );
+
+        assert!(reschedule(&mut queue, 1, 40_000));
+        assert_eq!(queue[0].attempts, 1);
+        assert_eq!(queue[0].next_attempt_at, 100_000);
+    }
+
+    #[test]
+    fn test_reschedule_gives_up_after_max_retries() {
+        let mut queue = vec![QueuedRecording {
+            timestamp: 7,
+            attempts: MAX_AUTOMATIC_RETRIES - 1,
+            next_attempt_at: 0,
+        }];
+
+        assert!(!reschedule(&mut queue, 7, 0));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_recording_is_retried_once_at_a_time() {
+        let queue = RetryQueue::new();
+
+        let retrying = queue.start(3);
+        assert!(retrying.is_some());
+        assert!(queue.start(3).is_none());
+
+        // Other recordings aren't blocked
+        assert!(queue.start(4).is_some());
+
+        drop(retrying);
+        assert!(queue.start(3).is_some());
+    }
+}
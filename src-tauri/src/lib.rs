@@ -18,10 +18,16 @@ use features::audio::{
 };
 use features::data::{export_all_data, import_all_data, import_from_json};
 use features::models::{
-    auto_start_selected_models, delete_local_model, download_local_model, get_all_models,
-    get_local_model_status, start_local_model, stop_local_model, LocalModelManager,
+    add_custom_endpoint_model, auto_start_selected_models, cancel_model_download,
+    delete_local_model, download_local_model, get_all_models, get_local_model_status,
+    import_local_model, list_endpoint_models, pause_model_download, remove_custom_endpoint_model,
+    resume_model_download, set_whisper_decode_options, start_local_model, stop_local_model,
+    DownloadManager, LocalModelManager,
+};
+use features::recordings::{
+    delete_recording, export_recordings, get_all_transcriptions, get_recording_audio_path,
+    import_audio_file, start_folder_watcher,
 };
-use features::recordings::{delete_recording, get_all_transcriptions, get_recording_audio_path};
 use features::security::{get_api_key, has_api_key, remove_api_key, store_api_key};
 use features::shortcuts::{
     disable_global_shortcuts, enable_global_shortcuts, register_escape_shortcut,
@@ -29,7 +35,11 @@ use features::shortcuts::{
     update_paste_shortcut, update_ptt_shortcut, update_voice_input_shortcut,
     RecordingShortcutHandler, ShortcutManager,
 };
-use features::transcription::{get_last_transcript, paste_last_transcript, transcribe_and_process};
+use features::transcription::{
+    compare_models, get_last_transcript, paste_last_transcript, retranscribe_recording,
+    retry_recording, start_retry_worker, transcribe_and_process, RetryQueue,
+    TranscriptionProviderRegistry,
+};
 use utils::logger;
 
 use std::sync::Arc;
@@ -60,6 +70,9 @@ pub fn run() {
     let devtools = tauri_plugin_devtools::init();
 
     let local_model_manager = Arc::new(Mutex::new(LocalModelManager::new()));
+    let download_manager = DownloadManager::new();
+    let transcription_providers = TranscriptionProviderRegistry::new();
+    let retry_queue = RetryQueue::new();
     let shortcut_manager = ShortcutManager::new();
 
     // Audio recording state
@@ -69,6 +82,9 @@ pub fn run() {
 
     let mut builder = tauri::Builder::default()
         .manage(local_model_manager)
+        .manage(download_manager)
+        .manage(transcription_providers)
+        .manage(retry_queue)
         .manage(shortcut_manager)
         .manage(audio_recorder)
         .manage(recording_state_manager)
@@ -248,6 +264,12 @@ pub fn run() {
             }
         });
 
+        // Re-attempt recordings whose transcription failed
+        start_retry_worker(app.app_handle().clone());
+
+        // Transcribe new audio files dropped into watched folders
+        start_folder_watcher(app.app_handle().clone());
+
         logger::info("🚀 LOG_CLEANUP STARTING");
         logger::debug_with("Cleaning up old logs", &[("retention_days", "30")]);
 
@@ -282,12 +304,21 @@ pub fn run() {
             transcribe_and_process,
             post_process_transcript,
             get_all_models,
+            // Custom OpenAI-compatible endpoints
+            add_custom_endpoint_model,
+            remove_custom_endpoint_model,
+            list_endpoint_models,
             // Local model download commands
             download_local_model,
+            pause_model_download,
+            resume_model_download,
+            cancel_model_download,
             delete_local_model,
+            import_local_model,
             // Local model lifecycle commands
             start_local_model,
             stop_local_model,
+            set_whisper_decode_options,
             get_local_model_status,
             // Secure API key storage
             store_api_key,
@@ -320,6 +351,11 @@ pub fn run() {
             get_all_transcriptions,
             delete_recording,
             get_recording_audio_path,
+            export_recordings,
+            import_audio_file,
+            retry_recording,
+            retranscribe_recording,
+            compare_models,
             // System preferences
             set_show_in_dock,
             // Data export/import
@@ -338,12 +374,12 @@ pub fn run() {
 
     app.run(move |_app_handle, event| {
         if let tauri::RunEvent::ExitRequested { .. } = event {
-            // Cleanup local model before app exits
+            // Cleanup local models before app exits
             let runtime = tokio::runtime::Runtime::new().unwrap();
             runtime.block_on(async {
                 let mut manager = local_model_manager_cleanup.lock().await;
-                manager.unload_model();
-                logger::info("Local model stopped on app exit");
+                manager.unload_all();
+                logger::info("Local models stopped on app exit");
             });
         }
     });
@@ -409,12 +409,12 @@ fn handle_tray_event(
             logger::info("General feedback clicked");
         }
         "quit" => {
-            // Cleanup local model before exit
+            // Cleanup local models before exit
             let runtime = tokio::runtime::Runtime::new().unwrap();
             runtime.block_on(async {
                 let mut manager = model_manager_cleanup.lock().await;
-                manager.unload_model();
-                logger::info("Local model stopped on app exit");
+                manager.unload_all();
+                logger::info("Local models stopped on app exit");
             });
             app.exit(0);
         }
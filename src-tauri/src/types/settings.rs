@@ -29,6 +29,62 @@ pub struct TranscriptionSettings {
     pub auto_paste: bool,
     pub auto_copy_to_clipboard: bool,
     pub speech_to_text_model_id: Option<String>,
+    /// Models tried in order when the selected one fails
+    #[serde(default)]
+    pub fallback_model_ids: Vec<String>,
+    /// Label who said what (providers that support diarization)
+    #[serde(default)]
+    pub speaker_diarization: bool,
+    /// Folders whose new audio files are transcribed automatically
+    #[serde(default)]
+    pub watched_folders: Vec<WatchedFolder>,
+}
+
+/// A directory watched for new audio files
+///
+/// Files already in the directory when it is added are left alone; only
+/// files that appear afterwards are imported.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/features/settings/types/generated/")]
+#[serde(rename_all = "camelCase")]
+pub struct WatchedFolder {
+    pub path: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// File name patterns to import (`*` and `?` wildcards, case-insensitive)
+    #[serde(default = "default_watched_globs")]
+    pub globs: Vec<String>,
+    /// Speech-to-text model to use; `None` uses the selected model
+    #[serde(default)]
+    pub model_id: Option<String>,
+    /// What to do with a source file once it is transcribed
+    #[serde(default)]
+    pub after_import: WatchedFileAction,
+    /// Destination for `move`; defaults to a "Transcribed" subfolder
+    #[serde(default)]
+    pub move_to: Option<String>,
+}
+
+/// What happens to a watched file after a successful transcription
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "../../src/features/settings/types/generated/")]
+#[serde(rename_all = "lowercase")]
+pub enum WatchedFileAction {
+    #[default]
+    Keep,
+    Move,
+    Delete,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_watched_globs() -> Vec<String> {
+    ["*.wav", "*.mp3", "*.m4a", "*.flac", "*.ogg"]
+        .iter()
+        .map(|glob| glob.to_string())
+        .collect()
 }
 
 /// Shortcuts settings
@@ -97,6 +153,9 @@ impl Default for Settings {
                 auto_paste: false,
                 auto_copy_to_clipboard: false,
                 speech_to_text_model_id: None,
+                fallback_model_ids: Vec::new(),
+                speaker_diarization: false,
+                watched_folders: Vec::new(),
             },
             shortcuts: ShortcutsSettings {
                 paste_last_transcript: "CmdOrCtrl+Shift+V".to_string(),
@@ -13,8 +13,13 @@ import { TranscriptionGroup } from './transcription-group'
 import { WelcomeBanner } from './welcome-banner'
 
 export function HomePageContent() {
-  const { transcriptions, initialized, getStats, deleteTranscription } =
-    useTranscriptionsStore()
+  const {
+    transcriptions,
+    initialized,
+    getStats,
+    deleteTranscription,
+    retryTranscription,
+  } = useTranscriptionsStore()
 
   const stats = getStats()
 
@@ -62,6 +67,7 @@ export function HomePageContent() {
               key={group.date}
               group={group}
               onDeleteTranscription={handleDeleteTranscription}
+              onRetryTranscription={retryTranscription}
             />
           ))
         )}
@@ -1,4 +1,4 @@
-import { ChevronDown, ChevronUp, Trash2 } from 'lucide-react'
+import { ChevronDown, ChevronUp, RotateCw, Trash2 } from 'lucide-react'
 import { useState } from 'react'
 
 import { AudioPlayer } from '@/components/ui/audio-player'
@@ -14,15 +14,19 @@ import type { Transcription } from '@/features/transcriptions'
 interface TranscriptionCardProps {
   transcription: Transcription
   onDelete: (id: string) => void
+  onRetry: (id: string) => Promise<void>
   isLast: boolean
 }
 
 export function TranscriptionCard({
   transcription,
   onDelete,
+  onRetry,
   isLast,
 }: TranscriptionCardProps) {
   const [isExpanded, setIsExpanded] = useState(false)
+  const [isRetrying, setIsRetrying] = useState(false)
+  const isFailed = transcription.status === 'failed'
   const timestamp = parseInt(transcription.id.split('-')[0])
   const { audioPath } = useAudioPath(timestamp)
 
@@ -39,9 +43,16 @@ export function TranscriptionCard({
       <div className="p-4">
         <div className="flex items-start justify-between gap-4">
           <div className="flex-1 min-w-0">
-            <p className="text-sm text-foreground line-clamp-2 mb-2">
-              {transcription.text}
-            </p>
+            {isFailed ? (
+              <p className="text-sm text-destructive line-clamp-2 mb-2">
+                Transcription failed
+                {transcription.error ? `: ${transcription.error}` : ''}
+              </p>
+            ) : (
+              <p className="text-sm text-foreground line-clamp-2 mb-2">
+                {transcription.text}
+              </p>
+            )}
             <div className="flex items-center gap-4 text-xs text-muted-foreground">
               <span>{formatTime(transcription.timestamp)}</span>
               <span>{transcription.wordCount} words</span>
@@ -67,11 +78,35 @@ export function TranscriptionCard({
                 )}
               </Button>
             )}
-            <CopyButton
-              content={transcription.text}
-              size="icon"
-              variant="ghost"
-            />
+            {isFailed ? (
+              <Button
+                size="icon"
+                variant="ghost"
+                className="h-8 w-8"
+                disabled={isRetrying}
+                onClick={async () => {
+                  setIsRetrying(true)
+                  try {
+                    await onRetry(transcription.id)
+                  } catch (error) {
+                    console.error('Retry failed:', error)
+                  } finally {
+                    setIsRetrying(false)
+                  }
+                }}
+                aria-label="Retry transcription"
+              >
+                <RotateCw
+                  className={cn('h-4 w-4', { 'animate-spin': isRetrying })}
+                />
+              </Button>
+            ) : (
+              <CopyButton
+                content={transcription.text}
+                size="icon"
+                variant="ghost"
+              />
+            )}
             <Button
               size="icon"
               variant="ghost"
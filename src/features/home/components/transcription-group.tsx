@@ -5,11 +5,13 @@ import type { GroupedTranscriptions } from '../utils'
 interface TranscriptionGroupProps {
   group: GroupedTranscriptions
   onDeleteTranscription: (id: string) => void
+  onRetryTranscription: (id: string) => Promise<void>
 }
 
 export function TranscriptionGroup({
   group,
   onDeleteTranscription,
+  onRetryTranscription,
 }: TranscriptionGroupProps) {
   return (
     <div>
@@ -23,6 +25,7 @@ export function TranscriptionGroup({
             key={transcription.id}
             transcription={transcription}
             onDelete={onDeleteTranscription}
+            onRetry={onRetryTranscription}
             isLast={index === group.transcriptions.length - 1}
           />
         ))}
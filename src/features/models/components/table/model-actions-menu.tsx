@@ -16,10 +16,13 @@ import type { TranscriptionModel } from '../../types'
 interface ModelActionsMenuProps {
   model: TranscriptionModel
   downloading: string | null
+  pausedDownloads: string[]
   onSelectModel: (id: string) => void
   onSetApiKey: (model: TranscriptionModel) => void
   onRemoveApiKey: (id: string) => void
   onDownloadModel: (model: TranscriptionModel) => void
+  onPauseDownload: (model: TranscriptionModel) => void
+  onCancelDownload: (model: TranscriptionModel) => void
   onDeleteModel: (model: TranscriptionModel) => void
   onStartModel?: (id: string) => Promise<void>
   onStopModel?: (id: string) => Promise<void>
@@ -28,16 +31,19 @@ interface ModelActionsMenuProps {
 export function ModelActionsMenu({
   model,
   downloading,
+  pausedDownloads,
   onSelectModel,
   onSetApiKey,
   onRemoveApiKey,
   onDownloadModel,
+  onPauseDownload,
+  onCancelDownload,
   onDeleteModel,
   onStartModel,
   onStopModel,
 }: ModelActionsMenuProps) {
-  const modelName = model.id.replace('whisper-', '')
-  const isDownloading = downloading === modelName
+  const isDownloading = downloading === model.id
+  const isPaused = pausedDownloads.includes(model.id)
   const isLocalModel = model.type === 'local'
   const isRunning = model.status === 'ready'
   const isStopped = model.status === 'stopped' || !model.status
@@ -130,20 +136,66 @@ export function ModelActionsMenu({
         {/* DOWNLOAD SECTION (for local models not yet downloaded) */}
         {showDownloadSection && (
           <>
-            <DropdownMenuItem
-              onClick={() => onDownloadModel(model)}
-              disabled={downloading !== null}
-              className="rounded-sm"
-            >
-              <div className="flex flex-col gap-0.5">
-                <span className="font-medium text-sm">
-                  {isDownloading ? 'Downloading...' : 'Download model'}
-                </span>
-                <span className="text-xs text-muted-foreground">
-                  {model.size}
-                </span>
-              </div>
-            </DropdownMenuItem>
+            {isDownloading ? (
+              <>
+                <DropdownMenuItem
+                  onClick={() => onPauseDownload(model)}
+                  className="rounded-sm"
+                >
+                  <div className="flex flex-col gap-0.5">
+                    <span className="font-medium text-sm">Pause download</span>
+                    <span className="text-xs text-muted-foreground">
+                      Resume later from where it stopped
+                    </span>
+                  </div>
+                </DropdownMenuItem>
+                <DropdownMenuItem
+                  onClick={() => onCancelDownload(model)}
+                  className="rounded-sm"
+                >
+                  <div className="flex flex-col gap-0.5">
+                    <span className="font-medium text-sm">
+                      Cancel download
+                    </span>
+                    <span className="text-xs text-muted-foreground">
+                      Discard the partial file
+                    </span>
+                  </div>
+                </DropdownMenuItem>
+              </>
+            ) : (
+              <>
+                <DropdownMenuItem
+                  onClick={() => onDownloadModel(model)}
+                  disabled={downloading !== null}
+                  className="rounded-sm"
+                >
+                  <div className="flex flex-col gap-0.5">
+                    <span className="font-medium text-sm">
+                      {isPaused ? 'Resume download' : 'Download model'}
+                    </span>
+                    <span className="text-xs text-muted-foreground">
+                      {model.size}
+                    </span>
+                  </div>
+                </DropdownMenuItem>
+                {isPaused && (
+                  <DropdownMenuItem
+                    onClick={() => onCancelDownload(model)}
+                    className="rounded-sm"
+                  >
+                    <div className="flex flex-col gap-0.5">
+                      <span className="font-medium text-sm">
+                        Cancel download
+                      </span>
+                      <span className="text-xs text-muted-foreground">
+                        Discard the partial file
+                      </span>
+                    </div>
+                  </DropdownMenuItem>
+                )}
+              </>
+            )}
             {hasContentAfterDownload && <DropdownMenuSeparator />}
           </>
         )}
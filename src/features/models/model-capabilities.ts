@@ -136,6 +136,8 @@ export function getProviderCapabilitySummary(provider: ModelProvider): string {
       return 'Enterprise-grade with speaker detection'
     case 'assemblyai':
       return 'Advanced features including sentiment analysis'
+    case 'deepgram':
+      return 'Low-latency transcription with smart formatting'
     case 'elevenlabs':
       return 'High-quality multilingual transcription'
     case 'local-whisper':
@@ -12,6 +12,9 @@ import {
 import { ModelsTable } from '../components/table'
 import {
   downloadModel,
+  resumeModelDownload,
+  pauseModelDownload,
+  cancelModelDownload,
   deleteModel,
   syncModels,
   createModelColumns,
@@ -39,6 +42,7 @@ export function ModelsPage() {
   const [apiKeyModalModel, setApiKeyModalModel] =
     useState<TranscriptionModel | null>(null)
   const [downloading, setDownloading] = useState<string | null>(null)
+  const [pausedDownloads, setPausedDownloads] = useState<string[]>([])
   const [activeTab, setActiveTab] = useState<
     'speech-to-text' | 'post-processing'
   >('speech-to-text')
@@ -51,14 +55,36 @@ export function ModelsPage() {
 
   const handleDownloadModel = useCallback(
     async (model: TranscriptionModel) => {
+      const isPaused = pausedDownloads.includes(model.id)
       setDownloading(model.id)
+      setPausedDownloads(ids => ids.filter(id => id !== model.id))
       try {
-        await downloadModel(model, selectModel)
+        if (isPaused) {
+          await resumeModelDownload(model, selectModel)
+        } else {
+          await downloadModel(model, selectModel)
+        }
       } finally {
         setDownloading(null)
       }
     },
-    [selectModel]
+    [selectModel, pausedDownloads]
+  )
+
+  const handlePauseDownload = useCallback(
+    async (model: TranscriptionModel) => {
+      setPausedDownloads(ids => [...ids, model.id])
+      await pauseModelDownload(model)
+    },
+    []
+  )
+
+  const handleCancelDownload = useCallback(
+    async (model: TranscriptionModel) => {
+      setPausedDownloads(ids => ids.filter(id => id !== model.id))
+      await cancelModelDownload(model)
+    },
+    []
   )
 
   const handleDeleteModel = useCallback(async (model: TranscriptionModel) => {
@@ -73,10 +99,13 @@ export function ModelsPage() {
     () =>
       createModelColumns({
         downloading,
+        pausedDownloads,
         onSelectModel: id => void selectModel(id),
         onSetApiKey: setApiKeyModalModel,
         onRemoveApiKey: id => void removeApiKey(id),
         onDownloadModel: handleDownloadModel,
+        onPauseDownload: handlePauseDownload,
+        onCancelDownload: handleCancelDownload,
         onDeleteModel: handleDeleteModel,
         onRefreshStatus: async id => void refreshModelStatus(id),
         onStartModel: async id => void startLocalModel(id),
@@ -84,12 +113,15 @@ export function ModelsPage() {
       }),
     [
       downloading,
+      pausedDownloads,
       selectModel,
       removeApiKey,
       refreshModelStatus,
       startLocalModel,
       stopLocalModel,
       handleDownloadModel,
+      handlePauseDownload,
+      handleCancelDownload,
       handleDeleteModel,
     ]
   )
@@ -62,6 +62,12 @@ export const getProviderInfo = (provider: ModelProvider): ProviderInfo => {
         icon: <ProviderLogo provider="assemblyai" className="dark:invert" />,
         color: 'text-indigo-600',
       }
+    case 'deepgram':
+      return {
+        name: 'Deepgram',
+        icon: <ProviderLogo provider="deepgram" className="dark:invert" />,
+        color: 'text-emerald-600',
+      }
     case 'elevenlabs':
       return {
         name: 'ElevenLabs',
@@ -80,6 +86,12 @@ export const getProviderInfo = (provider: ModelProvider): ProviderInfo => {
         icon: <ProviderLogo provider="lmstudio" />,
         color: 'text-gray-700',
       }
+    case 'openai-compatible':
+      return {
+        name: 'Custom Endpoint',
+        icon: <ProviderLogo provider="openai" />,
+        color: 'text-sky-600',
+      }
     case 'local-whisper':
       return {
         name: 'Whisper Local',
@@ -95,6 +95,9 @@ export const useModelsStore = create<ModelsState>((set, get) => ({
                 isSelected,
                 hasApiKey,
                 ...(storedModel.apiKey ? { apiKey: storedModel.apiKey } : {}),
+                ...(storedModel.decodeOptions
+                  ? { decodeOptions: storedModel.decodeOptions }
+                  : {}),
               }
             }
 
@@ -333,6 +336,17 @@ export const useModelsStore = create<ModelsState>((set, get) => ({
     }
   },
 
+  setDecodeOptions: async (id, options) => {
+    // Saved to models.json by the backend and applied to the loaded model
+    await invoke('set_whisper_decode_options', { modelId: id, options })
+
+    set({
+      models: get().models.map(model =>
+        model.id === id ? { ...model, decodeOptions: options } : model
+      ),
+    })
+  },
+
   startLocalModel: async id => {
     const model = get().models.find(m => m.id === id)
     if (!model || model.type !== 'local' || !model.path || !model.engine) {
@@ -365,8 +379,10 @@ export const useModelsStore = create<ModelsState>((set, get) => ({
   },
 
   stopLocalModel: async id => {
+    const model = get().models.find(m => m.id === id)
+
     try {
-      await stopLocalModelCommand()
+      await stopLocalModelCommand(model?.engine ?? undefined)
 
       toast.success('Model stopped', {
         description: 'Model has been unloaded from memory.',
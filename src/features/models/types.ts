@@ -1,8 +1,17 @@
 // Import generated types from Rust
-import type { ModelDefinition as GeneratedModelDefinition } from './types/generated'
+import type {
+  ModelDefinition as GeneratedModelDefinition,
+  WhisperDecodeOptions,
+} from './types/generated'
 
 // Re-export generated types
-export type { ModelType, ModelPurpose, ModelProvider } from './types/generated'
+export type {
+  ModelType,
+  ModelPurpose,
+  ModelProvider,
+  ModelFeatures,
+  WhisperDecodeOptions,
+} from './types/generated'
 
 // Re-export ModelDefinition for direct use
 export type { ModelDefinition } from './types/generated'
@@ -24,6 +33,7 @@ export interface TranscriptionModel extends GeneratedModelDefinition {
   apiKey?: string
   status?: ModelStatus
   capabilities?: ModelCapabilities
+  decodeOptions?: WhisperDecodeOptions
 }
 
 export interface ModelStatusInfo {
@@ -45,6 +55,10 @@ export interface ModelsState {
   removeApiKey: (id: string) => Promise<void>
   selectModel: (id: string) => Promise<void>
   syncDefaultModels: () => Promise<void>
+  setDecodeOptions: (
+    id: string,
+    options: WhisperDecodeOptions
+  ) => Promise<void>
   startLocalModel: (id: string) => Promise<void>
   stopLocalModel: (id: string) => Promise<void>
   refreshModelStatus: (id: string) => Promise<ModelStatus>
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModelProvider } from "./ModelProvider";
+import type { ModelPurpose } from "./ModelPurpose";
+
+/**
+ * A user-defined OpenAI-compatible endpoint
+ *
+ * Speech-to-text endpoints are servers exposing `/audio/transcriptions`
+ * (faster-whisper, whisper.cpp server, Groq, ...). Post-processing
+ * endpoints expose `/chat/completions` (Ollama, LM Studio, OpenRouter,
+ * vLLM, ...). Stored in models.json under `customEndpoints` and surfaced
+ * as a regular model by `get_all_models`.
+ */
+export type CustomEndpoint = { id: string, 
+/**
+ * Display name shown in the models list
+ */
+name: string, 
+/**
+ * Base URL including the API version (e.g., "http://localhost:8000/v1")
+ */
+baseUrl: string, 
+/**
+ * Model name sent to the endpoint (e.g., "whisper-large-v3", "llama3.2")
+ */
+model: string, 
+/**
+ * "openai-compatible", or "ollama"/"lmstudio" for those local servers
+ */
+provider: ModelProvider, purpose: ModelPurpose, };
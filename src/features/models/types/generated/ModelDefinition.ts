@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModelFeatures } from "./ModelFeatures";
 import type { ModelProvider } from "./ModelProvider";
 import type { ModelPurpose } from "./ModelPurpose";
 import type { ModelType } from "./ModelType";
@@ -16,4 +17,16 @@ downloadUrl: string | null,
 /**
  * Filename to save the model as (for local models)
  */
-filename: string | null, };
+filename: string | null, 
+/**
+ * Base URL for user-defined OpenAI-compatible endpoints
+ */
+baseUrl: string | null, 
+/**
+ * ISO 639-1 codes the model understands; None means any language
+ */
+languages: Array<string> | null, 
+/**
+ * Memory needed to run a local model comfortably
+ */
+recommendedRamMb: number | null, features: ModelFeatures | null, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a model can do beyond plain transcription
+ */
+export type ModelFeatures = { 
+/**
+ * Returns segment or word timings
+ */
+timestamps: boolean, 
+/**
+ * Can translate speech into English
+ */
+translate: boolean, 
+/**
+ * Can label who is speaking
+ */
+diarization: boolean, };
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type ModelProvider = "openai" | "anthropic" | "google" | "assemblyai" | "elevenlabs" | "local-whisper" | "ollama" | "lmstudio";
+export type ModelProvider = "openai" | "anthropic" | "google" | "assemblyai" | "deepgram" | "elevenlabs" | "local-whisper" | "ollama" | "lmstudio" | "openai-compatible";
@@ -0,0 +1,42 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Decoding parameters for a local Whisper model
+ *
+ * Stored per model in models.json (`decodeOptions`). Defaults match
+ * whisper.cpp's own defaults, except that decoding is greedy with a
+ * single candidate unless `beamSize` is set.
+ */
+export type WhisperDecodeOptions = { 
+/**
+ * Beam search width; `None` (or 1) uses greedy decoding
+ */
+beamSize: number | null, 
+/**
+ * Candidates sampled per temperature step when decoding greedily
+ */
+bestOf: number, 
+/**
+ * Initial sampling temperature
+ */
+temperature: number, 
+/**
+ * Temperature added on each fallback retry; 0 disables fallback
+ */
+temperatureIncrement: number, 
+/**
+ * Segments above this no-speech probability are treated as silence
+ */
+noSpeechThreshold: number, 
+/**
+ * Retry with a higher temperature below this average log probability
+ */
+logprobThreshold: number, 
+/**
+ * CPU threads to use; `None` lets whisper.cpp decide
+ */
+threads: number | null, suppressBlank: boolean, suppressNonSpeechTokens: boolean, 
+/**
+ * Text fed to the decoder as previous context (names, jargon, style)
+ */
+initialPrompt: string | null, };
@@ -1,7 +1,10 @@
 // This file re-exports all generated types from ts-rs
 // Run `cargo test --lib` to regenerate these types
 
+export * from './CustomEndpoint'
 export * from './ModelDefinition'
+export * from './ModelFeatures'
 export * from './ModelProvider'
 export * from './ModelPurpose'
 export * from './ModelType'
+export * from './WhisperDecodeOptions'
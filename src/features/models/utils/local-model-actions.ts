@@ -76,10 +76,12 @@ export async function startLocalModel(
 }
 
 /**
- * Stops (unloads) the current local model from memory
+ * Stops (unloads) a local model from memory
+ *
+ * @param engineType - Engine of the model to stop; omit to stop all models
  */
-export async function stopLocalModel(): Promise<void> {
-  await invoke('stop_local_model')
+export async function stopLocalModel(engineType?: string): Promise<void> {
+  await invoke('stop_local_model', { engineType: engineType ?? null })
 }
 
 /**
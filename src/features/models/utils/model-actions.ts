@@ -24,19 +24,77 @@ export async function downloadModel(
     engineType: model.engine,
   })
 
-  toast.promise(downloadPromise, {
-    loading: `Downloading ${model.name} model...`,
-    success: async () => {
-      // Reload models to update state
-      await initializeModels()
-
-      // Auto-select and start the downloaded model
-      await selectModel(model.id)
-
-      return `${model.name} model ready to use!`
-    },
-    error: error => `Failed to download ${model.name}: ${error}`,
+  await trackDownload(model, downloadPromise, selectModel)
+}
+
+/**
+ * Continues a paused download from where it stopped
+ */
+export async function resumeModelDownload(
+  model: TranscriptionModel,
+  selectModel: (id: string) => Promise<void>
+): Promise<void> {
+  const downloadPromise = invoke<string>('resume_model_download', {
+    modelId: model.id,
   })
+
+  await trackDownload(model, downloadPromise, selectModel)
+}
+
+/**
+ * Pauses a running download, keeping what was downloaded so far
+ */
+export async function pauseModelDownload(
+  model: TranscriptionModel
+): Promise<void> {
+  try {
+    await invoke('pause_model_download', { modelId: model.id })
+  } catch (error) {
+    toast.error(`Failed to pause ${model.name}: ${error}`)
+  }
+}
+
+/**
+ * Cancels a running or paused download and discards the partial file
+ */
+export async function cancelModelDownload(
+  model: TranscriptionModel
+): Promise<void> {
+  try {
+    await invoke('cancel_model_download', { modelId: model.id })
+  } catch (error) {
+    toast.error(`Failed to cancel ${model.name}: ${error}`)
+  }
+}
+
+async function trackDownload(
+  model: TranscriptionModel,
+  downloadPromise: Promise<string>,
+  selectModel: (id: string) => Promise<void>
+): Promise<void> {
+  const toastId = toast.loading(`Downloading ${model.name} model...`)
+
+  try {
+    await downloadPromise
+
+    // Reload models to update state
+    await initializeModels()
+
+    // Auto-select and start the downloaded model
+    await selectModel(model.id)
+
+    toast.success(`${model.name} model ready to use!`, { id: toastId })
+  } catch (error) {
+    if (error === 'Download paused') {
+      toast.info(`${model.name} download paused`, { id: toastId })
+    } else if (error === 'Download cancelled') {
+      toast.info(`${model.name} download cancelled`, { id: toastId })
+    } else {
+      toast.error(`Failed to download ${model.name}: ${error}`, {
+        id: toastId,
+      })
+    }
+  }
 }
 
 /**
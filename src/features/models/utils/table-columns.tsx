@@ -10,10 +10,13 @@ import type { ColumnDef } from '@tanstack/react-table'
 
 export interface ColumnActions {
   downloading: string | null
+  pausedDownloads: string[]
   onSelectModel: (id: string) => void
   onSetApiKey: (model: TranscriptionModel) => void
   onRemoveApiKey: (id: string) => void
   onDownloadModel: (model: TranscriptionModel) => void
+  onPauseDownload: (model: TranscriptionModel) => void
+  onCancelDownload: (model: TranscriptionModel) => void
   onDeleteModel: (model: TranscriptionModel) => void
   onRefreshStatus: (id: string) => Promise<void>
   onStartModel: (id: string) => Promise<void>
@@ -66,10 +69,13 @@ export function createModelColumns(
         <ModelActionsMenu
           model={row.original}
           downloading={actions.downloading}
+          pausedDownloads={actions.pausedDownloads}
           onSelectModel={actions.onSelectModel}
           onSetApiKey={actions.onSetApiKey}
           onRemoveApiKey={actions.onRemoveApiKey}
           onDownloadModel={actions.onDownloadModel}
+          onPauseDownload={actions.onPauseDownload}
+          onCancelDownload={actions.onCancelDownload}
           onDeleteModel={actions.onDeleteModel}
           onStartModel={actions.onStartModel}
           onStopModel={actions.onStopModel}
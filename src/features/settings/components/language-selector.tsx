@@ -12,6 +12,7 @@ import {
 } from '@/components/ui/dropdown-menu'
 
 import {
+  AUTO_DETECT_LANGUAGE,
   getLanguageByCode,
   getPopularLanguages,
   SUPPORTED_LANGUAGES,
@@ -59,7 +60,12 @@ export function LanguageSelector({ disabled }: { disabled?: boolean }) {
     <DropdownMenu>
       <DropdownMenuTrigger asChild>
         <Button variant="outline" className="gap-2" disabled={disabled}>
-          {selectedLanguage ? (
+          {selectedLanguageCode === AUTO_DETECT_LANGUAGE ? (
+            <>
+              <Globe className="h-4 w-4" />
+              Auto-detect
+            </>
+          ) : selectedLanguage ? (
             <>
               <FlagIcon countryCode={selectedLanguage.countryCode} />
               {selectedLanguage.name}
@@ -77,6 +83,15 @@ export function LanguageSelector({ disabled }: { disabled?: boolean }) {
           value={selectedLanguageCode}
           onValueChange={handleSelectLanguage}
         >
+          <DropdownMenuRadioItem value={AUTO_DETECT_LANGUAGE}>
+            <div className="flex items-center gap-2">
+              <Globe className="h-3 w-5" />
+              <span>Auto-detect</span>
+            </div>
+          </DropdownMenuRadioItem>
+
+          <DropdownMenuSeparator />
+
           {popularLanguages.map(language => (
             <DropdownMenuRadioItem key={language.code} value={language.code}>
               <LanguageItem language={language} />
@@ -1,7 +1,7 @@
 import { Switch } from '@/components/ui/switch'
 import { useAudioDevices } from '@/hooks/use-audio-devices'
 
-import { getLanguageByCode } from '../../data/languages'
+import { AUTO_DETECT_LANGUAGE, getLanguageByCode } from '../../data/languages'
 import { useSettingsStore } from '../../store'
 import { LanguageSelector } from '../language-selector'
 import { MicrophoneSelector } from '../microphone-selector'
@@ -13,9 +13,12 @@ export function GeneralPanel() {
   const { devices } = useAudioDevices()
 
   const selectedLanguage = getLanguageByCode(settings.transcription.language)
-  const languageDescription = selectedLanguage
-    ? `${selectedLanguage.name} (${selectedLanguage.nativeName})`
-    : 'English'
+  const languageDescription =
+    settings.transcription.language === AUTO_DETECT_LANGUAGE
+      ? 'Detected automatically from your speech (Google models need a specific language)'
+      : selectedLanguage
+        ? `${selectedLanguage.name} (${selectedLanguage.nativeName})`
+        : 'English'
 
   // Get the currently selected microphone name for description
   const selectedDeviceId = settings.voiceInput.microphoneDeviceId
@@ -58,9 +61,8 @@ export function GeneralPanel() {
 
         <SettingItem
           title="Transcription language"
-          description={`${languageDescription} - Currently we only support English`}
-          action={<LanguageSelector disabled={true} />}
-          disabled={true}
+          description={languageDescription}
+          action={<LanguageSelector />}
         />
 
         <SettingItem
@@ -34,7 +34,7 @@ export function ShortcutsPanel() {
           title="Voice input activation (Toggle)"
           description={
             settings.shortcuts.globalShortcutsEnabled
-              ? 'Click once to start recording, click again to stop'
+              ? 'Click once to start recording, click again to stop. Add Shift to translate into English'
               : 'Global shortcuts are disabled'
           }
           action={
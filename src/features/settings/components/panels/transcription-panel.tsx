@@ -1,9 +1,11 @@
 import { useState } from 'react'
 
 import { Switch } from '@/components/ui/switch'
+import { useModelsStore } from '@/features/models/store'
 
 import { SettingsPanel, SettingItem, SettingsSection } from './settings-panel'
 import { useSettingsStore } from '../../store'
+import { WatchedFolders } from '../watched-folders'
 
 export function TranscriptionPanel() {
   const {
@@ -11,7 +13,10 @@ export function TranscriptionPanel() {
     setAutoPaste,
     setAutoCopyToClipboard,
     setAiProcessingEnabled,
+    setFallbackModels,
+    setSpeakerDiarization,
   } = useSettingsStore()
+  const { models } = useModelsStore()
   const [autoPasteLoading, setAutoPasteLoading] = useState(false)
   const [autoCopyLoading, setAutoCopyLoading] = useState(false)
   const [aiProcessingLoading, setAiProcessingLoading] = useState(false)
@@ -49,6 +54,25 @@ export function TranscriptionPanel() {
     }
   }
 
+  const fallbackModelIds = settings.transcription.fallbackModelIds
+  // Only models that can run right now are offered as fallbacks
+  const fallbackCandidates = models.filter(
+    model =>
+      model.purpose === 'speech-to-text' &&
+      model.id !== settings.transcription.speechToTextModelId &&
+      (model.type === 'local'
+        ? model.isDownloaded
+        : !model.requiresApiKey || model.hasApiKey)
+  )
+
+  const handleFallbackToggle = async (modelId: string, checked: boolean) => {
+    // Newly enabled models go to the end of the chain
+    const next = checked
+      ? [...fallbackModelIds.filter(id => id !== modelId), modelId]
+      : fallbackModelIds.filter(id => id !== modelId)
+    await setFallbackModels(next)
+  }
+
   return (
     <SettingsPanel
       title="Transcription"
@@ -78,6 +102,48 @@ export function TranscriptionPanel() {
             />
           }
         />
+
+        <SettingItem
+          title="Label speakers"
+          description="Prefix each speaker's lines with Speaker 1, Speaker 2, … (ElevenLabs, AssemblyAI and Deepgram)"
+          action={
+            <Switch
+              checked={settings.transcription.speakerDiarization}
+              onCheckedChange={setSpeakerDiarization}
+            />
+          }
+        />
+      </SettingsSection>
+
+      {fallbackCandidates.length > 0 && (
+        <SettingsSection title="Fallback Models">
+          {fallbackCandidates.map(model => {
+            const position = fallbackModelIds.indexOf(model.id)
+            return (
+              <SettingItem
+                key={model.id}
+                title={model.name}
+                description={
+                  position >= 0
+                    ? `Fallback #${position + 1} if the selected model fails`
+                    : 'Not used as a fallback'
+                }
+                action={
+                  <Switch
+                    checked={position >= 0}
+                    onCheckedChange={checked =>
+                      handleFallbackToggle(model.id, checked)
+                    }
+                  />
+                }
+              />
+            )
+          })}
+        </SettingsSection>
+      )}
+
+      <SettingsSection title="Watched Folders">
+        <WatchedFolders />
       </SettingsSection>
 
       <SettingsSection title="AI Post-Processing">
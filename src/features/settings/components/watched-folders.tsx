@@ -0,0 +1,222 @@
+import { Plus, Trash2 } from 'lucide-react'
+import { useState } from 'react'
+
+import { Button } from '@/components/ui/button'
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuRadioGroup,
+  DropdownMenuRadioItem,
+  DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu'
+import { Input } from '@/components/ui/input'
+import { Switch } from '@/components/ui/switch'
+import { useModelsStore } from '@/features/models/store'
+
+import { useSettingsStore } from '../store'
+import { SettingItem } from './panels/settings-panel'
+
+import type { WatchedFileAction, WatchedFolder } from '../types/generated'
+
+// Same defaults as the backend's `default_watched_globs`
+const DEFAULT_GLOBS = ['*.wav', '*.mp3', '*.m4a', '*.flac', '*.ogg']
+
+const AFTER_IMPORT_LABELS: Record<WatchedFileAction, string> = {
+  keep: 'Keep file',
+  move: 'Move file',
+  delete: 'Delete file',
+}
+
+const SELECTED_MODEL = 'selected'
+
+function parseGlobs(value: string) {
+  return value
+    .split(',')
+    .map(glob => glob.trim())
+    .filter(Boolean)
+}
+
+export function WatchedFolders() {
+  const { settings, setWatchedFolders } = useSettingsStore()
+  const { models } = useModelsStore()
+  const [newPath, setNewPath] = useState('')
+
+  const folders = settings.transcription.watchedFolders
+  // Only models that can run right now can transcribe imports
+  const modelOptions = models.filter(
+    model =>
+      model.purpose === 'speech-to-text' &&
+      (model.type === 'local'
+        ? model.isDownloaded
+        : !model.requiresApiKey || model.hasApiKey)
+  )
+
+  const updateFolder = async (
+    path: string,
+    updates: Partial<WatchedFolder>
+  ) => {
+    await setWatchedFolders(
+      folders.map(folder =>
+        folder.path === path ? { ...folder, ...updates } : folder
+      )
+    )
+  }
+
+  const removeFolder = async (path: string) => {
+    await setWatchedFolders(folders.filter(folder => folder.path !== path))
+  }
+
+  const addFolder = async () => {
+    const path = newPath.trim()
+    if (!path || folders.some(folder => folder.path === path)) {
+      return
+    }
+
+    await setWatchedFolders([
+      ...folders,
+      {
+        path,
+        enabled: true,
+        globs: DEFAULT_GLOBS,
+        modelId: null,
+        afterImport: 'keep',
+        moveTo: null,
+      },
+    ])
+    setNewPath('')
+  }
+
+  return (
+    <>
+      {folders.map(folder => {
+        const model = models.find(m => m.id === folder.modelId)
+        return (
+          <SettingItem
+            key={folder.path}
+            title={folder.path}
+            description="Only files added after the folder is watched are imported"
+            action={
+              <div className="flex items-center gap-2">
+                <Switch
+                  checked={folder.enabled}
+                  onCheckedChange={enabled =>
+                    updateFolder(folder.path, { enabled })
+                  }
+                />
+                <Button
+                  variant="ghost"
+                  size="icon-sm"
+                  onClick={() => removeFolder(folder.path)}
+                >
+                  <Trash2 className="h-4 w-4" />
+                </Button>
+              </div>
+            }
+          >
+            <div className="grid gap-2">
+              <Input
+                defaultValue={folder.globs.join(', ')}
+                placeholder={DEFAULT_GLOBS.join(', ')}
+                onBlur={event =>
+                  updateFolder(folder.path, {
+                    globs: parseGlobs(event.target.value),
+                  })
+                }
+              />
+              <div className="flex gap-2">
+                <DropdownMenu>
+                  <DropdownMenuTrigger asChild>
+                    <Button variant="outline" size="sm">
+                      {model?.name ?? 'Selected model'}
+                    </Button>
+                  </DropdownMenuTrigger>
+                  <DropdownMenuContent align="start">
+                    <DropdownMenuRadioGroup
+                      value={folder.modelId ?? SELECTED_MODEL}
+                      onValueChange={value =>
+                        updateFolder(folder.path, {
+                          modelId: value === SELECTED_MODEL ? null : value,
+                        })
+                      }
+                    >
+                      <DropdownMenuRadioItem value={SELECTED_MODEL}>
+                        Selected model
+                      </DropdownMenuRadioItem>
+                      {modelOptions.map(option => (
+                        <DropdownMenuRadioItem
+                          key={option.id}
+                          value={option.id}
+                        >
+                          {option.name}
+                        </DropdownMenuRadioItem>
+                      ))}
+                    </DropdownMenuRadioGroup>
+                  </DropdownMenuContent>
+                </DropdownMenu>
+
+                <DropdownMenu>
+                  <DropdownMenuTrigger asChild>
+                    <Button variant="outline" size="sm">
+                      {AFTER_IMPORT_LABELS[folder.afterImport]}
+                    </Button>
+                  </DropdownMenuTrigger>
+                  <DropdownMenuContent align="start">
+                    <DropdownMenuRadioGroup
+                      value={folder.afterImport}
+                      onValueChange={value =>
+                        updateFolder(folder.path, {
+                          afterImport: value as WatchedFileAction,
+                        })
+                      }
+                    >
+                      {Object.entries(AFTER_IMPORT_LABELS).map(
+                        ([action, label]) => (
+                          <DropdownMenuRadioItem key={action} value={action}>
+                            {label}
+                          </DropdownMenuRadioItem>
+                        )
+                      )}
+                    </DropdownMenuRadioGroup>
+                  </DropdownMenuContent>
+                </DropdownMenu>
+              </div>
+              {folder.afterImport === 'move' && (
+                <Input
+                  defaultValue={folder.moveTo ?? ''}
+                  placeholder={`${folder.path}/Transcribed`}
+                  onBlur={event =>
+                    updateFolder(folder.path, {
+                      moveTo: event.target.value.trim() || null,
+                    })
+                  }
+                />
+              )}
+            </div>
+          </SettingItem>
+        )
+      })}
+
+      <SettingItem
+        title="Add folder"
+        description="New audio files in this folder are transcribed automatically"
+      >
+        <div className="flex gap-2">
+          <Input
+            value={newPath}
+            placeholder="/Users/me/Recordings"
+            onChange={event => setNewPath(event.target.value)}
+            onKeyDown={event => {
+              if (event.key === 'Enter') {
+                void addFolder()
+              }
+            }}
+          />
+          <Button variant="outline" onClick={addFolder}>
+            <Plus className="h-4 w-4" />
+            Add
+          </Button>
+        </div>
+      </SettingItem>
+    </>
+  )
+}
@@ -6,6 +6,9 @@ export interface Language {
   popularity?: number // Optional: for sorting (1-10 for popular languages)
 }
 
+// Lets the speech-to-text model detect the spoken language
+export const AUTO_DETECT_LANGUAGE = 'auto'
+
 export const SUPPORTED_LANGUAGES: Language[] = [
   // Popular languages first (top 10)
   {
@@ -27,6 +27,9 @@ export const defaultSettings: Settings = {
     autoPaste: false,
     autoCopyToClipboard: false,
     speechToTextModelId: null,
+    fallbackModelIds: [],
+    speakerDiarization: false,
+    watchedFolders: [],
   },
   shortcuts: {
     pasteLastTranscript: 'CmdOrCtrl+Shift+V',
@@ -5,6 +5,7 @@ import { create } from 'zustand'
 import { defaultSettings, type Settings } from './schema'
 
 import type { SettingsStore } from './types'
+import type { WatchedFolder } from './types/generated'
 
 let tauriStore: Store | null = null
 
@@ -44,6 +45,11 @@ export const useSettingsStore = create<SettingsStore>((set, get) => ({
             storedSettings?.transcription?.autoCopyToClipboard ?? false,
           speechToTextModelId:
             storedSettings?.transcription?.speechToTextModelId ?? null,
+          fallbackModelIds:
+            storedSettings?.transcription?.fallbackModelIds ?? [],
+          speakerDiarization:
+            storedSettings?.transcription?.speakerDiarization ?? false,
+          watchedFolders: storedSettings?.transcription?.watchedFolders ?? [],
         },
         shortcuts: {
           pasteLastTranscript:
@@ -353,6 +359,60 @@ export const useSettingsStore = create<SettingsStore>((set, get) => ({
     }
   },
 
+  setFallbackModels: async (modelIds: string[]) => {
+    try {
+      const store = await getTauriStore()
+      const newSettings = {
+        ...get().settings,
+        transcription: {
+          ...get().settings.transcription,
+          fallbackModelIds: modelIds,
+        },
+      }
+      await store.set('settings', newSettings)
+      await store.save()
+      set({ settings: newSettings })
+    } catch (error) {
+      console.error('Error setting fallback models:', error)
+    }
+  },
+
+  setSpeakerDiarization: async (enabled: boolean) => {
+    try {
+      const store = await getTauriStore()
+      const newSettings = {
+        ...get().settings,
+        transcription: {
+          ...get().settings.transcription,
+          speakerDiarization: enabled,
+        },
+      }
+      await store.set('settings', newSettings)
+      await store.save()
+      set({ settings: newSettings })
+    } catch (error) {
+      console.error('Error setting speaker diarization:', error)
+    }
+  },
+
+  setWatchedFolders: async (folders: WatchedFolder[]) => {
+    try {
+      const store = await getTauriStore()
+      const newSettings = {
+        ...get().settings,
+        transcription: {
+          ...get().settings.transcription,
+          watchedFolders: folders,
+        },
+      }
+      await store.set('settings', newSettings)
+      await store.save()
+      set({ settings: newSettings })
+    } catch (error) {
+      console.error('Error setting watched folders:', error)
+    }
+  },
+
   setPostProcessingModel: async (modelId: string | null) => {
     try {
       const store = await getTauriStore()
@@ -1,4 +1,5 @@
 import { type Settings } from './schema'
+import type { WatchedFolder } from './types/generated'
 
 export interface SettingsStore {
   settings: Settings
@@ -19,6 +20,9 @@ export interface SettingsStore {
   resetSettings: () => Promise<void>
   setAiProcessingEnabled: (enabled: boolean) => Promise<void>
   setSpeechToTextModel: (modelId: string | null) => Promise<void>
+  setFallbackModels: (modelIds: string[]) => Promise<void>
+  setSpeakerDiarization: (enabled: boolean) => Promise<void>
+  setWatchedFolders: (folders: WatchedFolder[]) => Promise<void>
   setPostProcessingModel: (modelId: string | null) => Promise<void>
   setEnablePushToTalk: (enabled: boolean) => Promise<void>
   setPushToTalkShortcut: (shortcut: string) => Promise<void>
@@ -1,6 +1,19 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WatchedFolder } from "./WatchedFolder";
 
 /**
  * Transcription settings
  */
-export type TranscriptionSettings = { language: string, autoPaste: boolean, autoCopyToClipboard: boolean, speechToTextModelId: string | null, };
+export type TranscriptionSettings = { language: string, autoPaste: boolean, autoCopyToClipboard: boolean, speechToTextModelId: string | null, 
+/**
+ * Models tried in order when the selected one fails
+ */
+fallbackModelIds: Array<string>, 
+/**
+ * Label who said what (providers that support diarization)
+ */
+speakerDiarization: boolean, 
+/**
+ * Folders whose new audio files are transcribed automatically
+ */
+watchedFolders: Array<WatchedFolder>, };
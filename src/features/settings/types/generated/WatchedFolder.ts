@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WatchedFileAction } from "./WatchedFileAction";
+
+/**
+ * A directory watched for new audio files
+ *
+ * Files already in the directory when it is added are left alone; only
+ * files that appear afterwards are imported.
+ */
+export type WatchedFolder = { path: string, enabled: boolean, 
+/**
+ * File name patterns to import (`*` and `?` wildcards, case-insensitive)
+ */
+globs: Array<string>, 
+/**
+ * Speech-to-text model to use; `None` uses the selected model
+ */
+modelId: string | null, 
+/**
+ * What to do with a source file once it is transcribed
+ */
+afterImport: WatchedFileAction, 
+/**
+ * Destination for `move`; defaults to a "Transcribed" subfolder
+ */
+moveTo: string | null, };
@@ -5,6 +5,8 @@ export * from './Settings'
 export * from './OnboardingSettings'
 export * from './VoiceInputSettings'
 export * from './TranscriptionSettings'
+export * from './WatchedFolder'
+export * from './WatchedFileAction'
 export * from './ShortcutsSettings'
 export * from './SystemSettings'
 export * from './PrivacySettings'
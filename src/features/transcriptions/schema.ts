@@ -8,6 +8,8 @@ export const transcriptionRecordSchema = z.object({
   wordCount: z.number(),
   modelId: z.string(),
   provider: z.string(),
+  status: z.enum(['completed', 'failed']).default('completed'),
+  error: z.string().optional().nullable(),
 })
 
 export const transcriptionsStoreSchema = z.object({
@@ -64,6 +64,35 @@ export const useTranscriptionsStore = create<TranscriptionsStore>(
       }
     },
 
+    retryTranscription: async id => {
+      const timestamp = parseInt(id.split('-')[0])
+
+      // Refresh either way so the latest error is shown on failure
+      try {
+        await invoke('retry_recording', { timestamp })
+      } finally {
+        await get().initialize()
+      }
+    },
+
+    retranscribeTranscription: async (id, modelId, options) => {
+      const timestamp = parseInt(id.split('-')[0])
+
+      try {
+        await invoke('retranscribe_recording', { timestamp, modelId, options })
+      } finally {
+        await get().initialize()
+      }
+    },
+
+    importAudioFile: async path => {
+      try {
+        await invoke('import_audio_file', { path })
+      } finally {
+        await get().initialize()
+      }
+    },
+
     clearAll: async () => {
       try {
         // Delete all recordings
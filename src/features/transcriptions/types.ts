@@ -1,4 +1,5 @@
 import type { Transcription } from './schema'
+import type { RetranscribeOptions } from './types/generated'
 
 export type { Transcription }
 
@@ -10,6 +11,13 @@ export interface TranscriptionsStore {
     transcription: Omit<Transcription, 'id' | 'wordCount'>
   ) => Promise<Transcription>
   deleteTranscription: (id: string) => Promise<void>
+  retryTranscription: (id: string) => Promise<void>
+  retranscribeTranscription: (
+    id: string,
+    modelId: string,
+    options?: Partial<RetranscribeOptions>
+  ) => Promise<void>
+  importAudioFile: (path: string) => Promise<void>
   clearAll: () => Promise<void>
   getStats: () => {
     totalTranscriptions: number
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One model's transcript of one sample
+ */
+export type ComparisonSampleResult = { sample: string, modelId: string, recorded: boolean, text: string | null, error: string | null, audioDuration: number, latencyMs: bigint | null, wer: number | null, cer: number | null, };
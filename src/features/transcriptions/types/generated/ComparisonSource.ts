@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Audio to run through each model in `compare_models`
+ */
+export type ComparisonSource = { "type": "recording", timestamp: number, reference: string | null, } | { "type": "folder", path: string, };
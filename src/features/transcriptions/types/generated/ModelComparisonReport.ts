@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComparisonSampleResult } from "./ComparisonSampleResult";
+import type { ModelComparisonSummary } from "./ModelComparisonSummary";
+
+/**
+ * Results of running the same audio through several models
+ */
+export type ModelComparisonReport = { createdAt: string, language: string | null, samples: Array<string>, models: Array<ModelComparisonSummary>, results: Array<ComparisonSampleResult>, };
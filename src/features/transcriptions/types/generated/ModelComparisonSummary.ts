@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Aggregate scores for one model across all samples
+ */
+export type ModelComparisonSummary = { modelId: string, modelName: string, provider: string, recorded: boolean, samples: number, failures: number, wer: number | null, cer: number | null, avgLatencyMs: number | null, realTimeFactor: number | null, };
@@ -1,7 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { PromptContext } from "./PromptContext";
+import type { RecordingStatus } from "./RecordingStatus";
+import type { TranscriptRevision } from "./TranscriptRevision";
+import type { TranslationInfo } from "./TranslationInfo";
 
 /**
  * Comprehensive metadata for each recording
  */
-export type RecordingMetadata = { result: string, rawResult: string, postProcessedResult: string | null, datetime: string, duration: number, processingTime: bigint, modelKey: string, modelName: string, provider: string, postProcessingModelId: string | null, postProcessingModelName: string | null, postProcessingProvider: string | null, languageSelected: string, recordingDevice: string, postProcessingEnabled: boolean, styleApplied: string | null, styleCategory: string | null, focusedAppName: string, focusedAppCategory: string, promptContext: PromptContext, appVersion: string, };
+export type RecordingMetadata = { status: RecordingStatus, error: string | null, result: string, rawResult: string, postProcessedResult: string | null, datetime: string, duration: number, processingTime: bigint, modelKey: string, modelName: string, provider: string, transcriptionAttempts: number, postProcessingModelId: string | null, postProcessingModelName: string | null, postProcessingProvider: string | null, languageSelected: string, detectedLanguage: string | null, translation: TranslationInfo | null, recordingDevice: string, postProcessingEnabled: boolean, styleApplied: string | null, styleCategory: string | null, focusedAppName: string, focusedAppCategory: string, promptContext: PromptContext, revisions: Array<TranscriptRevision>, appVersion: string, };
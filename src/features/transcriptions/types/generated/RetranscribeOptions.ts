@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Options for `retranscribe_recording`
+ */
+export type RetranscribeOptions = { 
+/**
+ * Run AI post-processing on the new transcript
+ */
+postProcess: boolean, 
+/**
+ * Language code or "auto"; defaults to the recording's language
+ */
+language: string | null, 
+/**
+ * Sampling temperature (providers that support it)
+ */
+temperature: number | null, 
+/**
+ * Translate the speech into English instead of transcribing it
+ */
+translate: boolean, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One transcript of a recording's audio
+ */
+export type TranscriptRevision = { createdAt: string, result: string, rawResult: string, postProcessedResult: string | null, modelKey: string, modelName: string, provider: string, postProcessingModelId: string | null, detectedLanguage: string | null, processingTime: bigint, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Progress of a recording transcribed in several chunks
+ *
+ * Emitted as `transcription-progress` after each chunk finishes.
+ */
+export type TranscriptionProgress = { modelId: string, completedChunks: number, totalChunks: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Languages of a translated dictation
+ */
+export type TranslationInfo = { sourceLanguage: string | null, targetLanguage: string, };
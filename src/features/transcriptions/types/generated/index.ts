@@ -2,8 +2,19 @@
 // Run `cargo test --lib` to regenerate these types
 
 export * from './RecordingMetadata'
+export * from './RecordingStatus'
+export * from './TranscriptRevision'
+export * from './TranslationInfo'
+export * from './RetranscribeOptions'
+export * from './TranscriptionProgress'
 export * from './PromptContext'
 export * from './SnippetInfo'
 export * from './SystemContext'
 export * from './ApplicationContext'
 export * from './FocusedApp'
+export * from './ExportFormat'
+export * from './ComparisonSource'
+export * from './ComparisonFormat'
+export * from './ModelComparisonReport'
+export * from './ModelComparisonSummary'
+export * from './ComparisonSampleResult'
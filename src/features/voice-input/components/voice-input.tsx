@@ -1,9 +1,11 @@
-import { useState } from 'react'
+import { useEffect, useState } from 'react'
 
 import { LiveWaveform } from '@/components/ui/live-waveform'
 import { useAudioRecording } from '@/hooks/use-audio-recording'
 import { useTauriEvent } from '@/hooks/use-tauri-event'
 
+import type { PartialTranscript } from '../types/generated/PartialTranscript'
+
 import { CancelButton } from './cancel-button'
 import { StopButton } from './stop-button'
 import { VoiceInputContainer } from './voice-input-container'
@@ -11,6 +13,7 @@ import { VoiceInputContainer } from './voice-input-container'
 export const VoiceInput = () => {
   const recording = useAudioRecording()
   const [audioLevel, setAudioLevel] = useState<number>(0)
+  const [partial, setPartial] = useState<PartialTranscript | null>(null)
 
   const isTranscribing = recording.state === 'transcribing'
   const isProcessing = recording.state === 'stopping' || isTranscribing
@@ -19,6 +22,17 @@ export const VoiceInput = () => {
     setAudioLevel(event.payload)
   })
 
+  useTauriEvent<PartialTranscript>('partial-transcript', event => {
+    setPartial(event.payload)
+  })
+
+  // Partial text belongs to a single recording
+  useEffect(() => {
+    if (!recording.isRecording) {
+      setPartial(null)
+    }
+  }, [recording.isRecording])
+
   return (
     <VoiceInputContainer>
       <CancelButton
@@ -29,6 +43,8 @@ export const VoiceInput = () => {
       <div className="flex-1 flex items-center justify-center h-full">
         {isProcessing ? (
           <TranscriberProcessing />
+        ) : partial && (partial.stable || partial.unstable) ? (
+          <PartialText partial={partial} />
         ) : (
           <LiveWaveform
             active={recording.isRecording}
@@ -56,6 +72,21 @@ export const VoiceInput = () => {
   )
 }
 
+const PartialText = ({ partial }: { partial: PartialTranscript }) => {
+  // Right-to-left overflow keeps the newest words visible
+  return (
+    <p
+      className="w-full truncate text-xs text-white"
+      style={{ direction: 'rtl', textAlign: 'left' }}
+    >
+      <bdi>
+        {partial.stable}{' '}
+        <span className="text-white/50">{partial.unstable}</span>
+      </bdi>
+    </p>
+  )
+}
+
 const TranscriberProcessing = () => {
   return (
     <LiveWaveform
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Text recognized so far in a recording that is still in progress
+ *
+ * Emitted as `partial-transcript` about once a second while recording with
+ * a local Whisper model. `stable` only ever grows; `unstable` is the tail
+ * the model may still revise. The transcript produced when the recording
+ * stops replaces both.
+ */
+export type PartialTranscript = { stable: string, unstable: string, };
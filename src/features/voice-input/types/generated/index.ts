@@ -2,3 +2,5 @@
 // Run `cargo test --lib` to regenerate these types
 
 export * from './AudioDevice'
+export * from './DictationMode'
+export * from './PartialTranscript'
@@ -2,6 +2,7 @@ import { invoke } from '@tauri-apps/api/core'
 import { listen, UnlistenFn } from '@tauri-apps/api/event'
 import { useCallback, useEffect, useState } from 'react'
 
+import type { DictationMode } from '@/features/voice-input/types/generated/DictationMode'
 import type { RecordingResponse } from '@/features/voice-input/types/generated/RecordingResponse'
 import type { RecordingState } from '@/features/voice-input/types/generated/RecordingState'
 
@@ -11,7 +12,7 @@ interface UseAudioRecordingReturn {
   isActive: boolean
   error: string | null
   filePath: string | null
-  startRecording: () => Promise<void>
+  startRecording: (mode?: DictationMode) => Promise<void>
   stopRecording: () => Promise<void>
   cancelRecording: () => Promise<void>
 }
@@ -63,9 +64,11 @@ export function useAudioRecording(): UseAudioRecordingReturn {
     loadInitialState()
   }, [])
 
-  const startRecording = useCallback(async () => {
+  const startRecording = useCallback(async (mode?: DictationMode) => {
     try {
-      const response = await invoke<RecordingResponse>('start_recording')
+      const response = await invoke<RecordingResponse>('start_recording', {
+        mode: mode ?? null,
+      })
       if (response.success) {
         setState(response.state)
         setFilePath(response.filePath || null)